@@ -29,6 +29,9 @@ pub enum Action {
     Refresh,
     ApplySelectedAudioDevice,
     ApplySelectedBrowser,
+    SwitchAccount,
+    CycleRegionSetting,
+    ToggleAutoplayRadio,
     SettingsFocusNext,
     SettingsFocusPrev,
     ClearCache,
@@ -37,6 +40,38 @@ pub enum Action {
     VolumeDown,
     SeekForward,
     SeekBack,
+    /// Shift the current track's lyrics by this many ms; positive shows them later
+    NudgeLyrics(i64),
+
+    // Lyrics sync mode
+    ToggleLyricsSync,
+    SyncStamp,
+    SyncUndo,
+    SyncAdjust(i64),
+    SaveLyricsSync,
+    PublishLyricsSync,
+
+    ToggleLyricsSecondary,
+    ToggleLyricsRomanize,
+
+    // Lyrics screen
+    FollowLyrics,
+    SeekToLyricsLine,
+    LyricsClick(u16), // Mouse click on a terminal row
+    StartLyricsSearch,
+    LyricsSearchInput(char),
+    LyricsSearchBackspace,
+    LyricsSearchSubmit,
+    LyricsSearchCancel,
+    LyricsSearchNext,
+    LyricsSearchPrev,
+
+    // LRCLIB candidate picker
+    OpenLyricsPicker,
+    CloseLyricsPicker,
+    LyricsPickerUp,
+    LyricsPickerDown,
+    PinLyricsCandidate,
 
     Resize,
 
@@ -49,11 +84,44 @@ pub enum Action {
     QueueShuffle,
     QueueMoveUp,
     QueueMoveDown,
+    QueueUndo,
+    QueueRedo,
     QueuePlayIndex(usize),
     PlayNext,
     PlayPrev,
     AddSelectedToQueue,    // Add currently selected track to queue
+    PlaySelectedNext,      // Queue the selected track to play after the current one
     AddAllToQueue,         // Add all tracks (from playlist view) to queue
+    StartRadio,            // Start a radio from the selected track/artist/playlist
+
+    // Multi-select in track lists; bulk actions use the marked rows
+    ToggleSelectMode,
+    ToggleSelected,
+    SelectRange,
+    ClearSelection,
+    LikeSelected,
+
+    // Picker for the playlist to add the selected tracks to
+    OpenPlaylistPicker,
+    ClosePlaylistPicker,
+    PlaylistPickerUp,
+    PlaylistPickerDown,
+    AddToPickedPlaylist,
+
+    // Local playlists, kept in void's own database
+    NewLocalPlaylist,
+    RenameLocalPlaylist,
+    DeleteLocalPlaylist,
+    SaveQueueAsPlaylist,
+    LocalPlaylistMoveUp,
+    LocalPlaylistMoveDown,
+    LocalPlaylistRemove,
+
+    // Prompt for a local playlist's name
+    NamePromptInput(char),
+    NamePromptBackspace,
+    NamePromptSubmit,
+    NamePromptCancel,
 
     // Library tab actions
     LibraryTabNext,
@@ -62,6 +130,15 @@ pub enum Action {
     OpenPlaylist(Playlist),
     ClosePlaylist,
 
+    // Shelf navigation (within a card shelf on Home/Explore)
+    ShelfLeft,
+    ShelfRight,
+
+    // Explore tab actions
+    ExploreTabNext,
+    ExploreTabPrev,
+    ExploreNextCountry,
+
     // Track ended - for auto-advance
     TrackEnded,
 }
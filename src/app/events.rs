@@ -31,10 +31,43 @@ pub enum NetworkEvent {
     HistoryAdded { track: crate::ytm::models::Track },
     LibraryResults { tracks: Vec<crate::ytm::models::Track> },
     PlaylistsLoaded { playlists: Vec<crate::ytm::models::Playlist> },
-    PlaylistTracksLoaded { _playlist_id: String, tracks: Vec<crate::ytm::models::Track> },
+    PlaylistTracksLoaded { playlist_id: String, tracks: Vec<crate::ytm::models::Track> },
+    HomeLoaded { shelves: Vec<crate::ytm::models::Shelf>, continuation: Option<String> },
+    HomeMoreLoaded { shelves: Vec<crate::ytm::models::Shelf>, continuation: Option<String> },
+    ExploreLoaded { key: String, shelves: Vec<crate::ytm::models::Shelf>, continuation: Option<String> },
+    ExploreMoreLoaded { key: String, shelves: Vec<crate::ytm::models::Shelf>, continuation: Option<String> },
+    /// A radio batch; `seed_title` is set when this starts a new station
+    RadioLoaded { page: crate::ytm::api::RadioPage, seed_title: Option<String> },
+    ArtLoaded { url: String, image: std::sync::Arc<image::RgbaImage> },
+    ArtFailed { url: String },
+    MoodsLoaded { categories: Vec<crate::ytm::models::MoodCategory> },
+    /// Result of the account check; `None` means YouTube saw us as logged out
+    AccountLoaded { account: Option<crate::ytm::models::AccountInfo> },
+    AccountCheckFailed { error: String },
+    ChannelsLoaded { channels: Vec<crate::ytm::models::Channel> },
     ResolvedStream { track: crate::ytm::models::Track, url: String },
     AudioDevices { devices: Vec<crate::app::state::AudioDevice> },
-    LyricsLoaded { video_id: String, lyrics: crate::lyrics::ParsedLyrics },
+    /// `offset_ms` is the user's saved nudge for the track
+    LyricsLoaded { video_id: String, lyrics: crate::lyrics::ParsedLyrics, offset_ms: i64 },
     LyricsNotFound { video_id: String },
+    LyricsPublished,
+    LyricsCandidatesLoaded {
+        video_id: String,
+        candidates: Vec<(crate::lyrics::lrclib::LrclibResponse, Option<i32>)>,
+    },
+    LyricsCandidatesFailed { error: String },
+    LyricsPublishFailed { error: String },
+    /// `what` names the tracks, e.g. "3 tracks"
+    TracksLiked { what: String },
+    LikeFailed { error: String },
+    PlaylistItemsAdded { what: String, playlist: String },
+    PlaylistEditFailed { error: String },
+    /// Local playlists after a change; `select` puts the Library cursor on one
+    LocalPlaylistsLoaded {
+        playlists: Vec<crate::ytm::models::Playlist>,
+        select: Option<i64>,
+        message: Option<String>,
+    },
+    LocalPlaylistFailed { error: String },
 }
 
@@ -4,45 +4,78 @@ pub mod state;
 
 use crate::config::Config;
 use crate::input;
-use crate::storage::Storage;
+use crate::storage::{local_playlist_id, Storage};
 use crate::tui::{self, TuiTerminal};
 use crate::player::mpv::MpvHandle;
 use crate::ytm::{self, api::YtmClient};
 use actions::Action;
 use events::Event;
-use state::{AppState, LibraryTab, RepeatMode, Screen, SearchFocus, SettingsFocus, Toast};
+use state::{AppState, AuthStatus, ExploreTab, LibraryTab, RepeatMode, Screen, SearchFocus, SettingsFocus, Toast};
 use tokio::sync::mpsc;
 
 pub struct App {
     cfg: Config,
     config_path: std::path::PathBuf,
+    /// Command-line request settings, applied on every client rebuild but never saved
+    overrides: crate::config::ClientOverrides,
     state: AppState,
     ytm: YtmClient,
+    lyrics_providers: std::sync::Arc<[Box<dyn crate::lyrics::LyricsProvider>]>,
+    /// For pinned lyrics, the candidate picker and publishing, whatever the provider order
     lrclib: crate::lyrics::LrclibClient,
+    art: crate::art::ArtCache,
     mpv: Option<MpvHandle>,
+    /// Track left playing last session and where, loaded paused once mpv is up
+    resume: Option<(crate::ytm::models::Track, f64)>,
+    /// Local playlist changes, applied one at a time in the order they were made
+    local_playlist_jobs: mpsc::UnboundedSender<LocalPlaylistJob>,
 }
 
 impl App {
-    pub fn new(cfg: Config, config_path: std::path::PathBuf) -> anyhow::Result<Self> {
-        let auth = match cfg.ytm.cookies.as_deref() {
-            Some(p) if p.exists() => Some(ytm::auth::load_netscape_cookies(p)?),
-            _ => None,
+    pub fn new(
+        cfg: Config,
+        config_path: std::path::PathBuf,
+        overrides: crate::config::ClientOverrides,
+    ) -> anyhow::Result<Self> {
+        // Browser cookies are best effort: fall back to anonymous access
+        let (cookies, browser) = cfg.ytm.cookie_sources();
+        let (auth, auth_error) = match ytm::auth::load_auth(cookies, browser) {
+            Ok(auth) => (auth, None),
+            Err(e) => (None, Some(format!("Browser cookies: {e:#}"))),
         };
-        let ytm = YtmClient::new(auth)?;
-        let lrclib = crate::lyrics::LrclibClient::new();
-        let _ = Storage::open(&cfg.paths.data_dir.join("cache.sqlite3"))?;
+        let ytm = YtmClient::new(auth, cfg.client_options(&overrides))?;
+        let lyrics_providers = crate::lyrics::build_providers(&cfg.lyrics, &ytm).into();
+        let art = crate::art::ArtCache::new(
+            cfg.paths.data_dir.join("art"),
+            cfg.ui.art_cache_mb * 1024 * 1024,
+        );
+        let storage = Storage::open(&cfg.paths.data_dir.join("cache.sqlite3"))?;
 
         // Create state with config values
         let mut state = AppState::new();
         state.volume = cfg.player.volume;
+        state.lyrics_show_secondary = cfg.lyrics.show_secondary;
+        state.lyrics_romanize = cfg.lyrics.romanize;
+        state.auth_status = AuthStatus::from_auth(ytm.auth());
+        state.art = crate::tui::graphics::ArtState::new(crate::tui::graphics::Protocol::from_config(
+            &cfg.ui.image_protocol,
+        ));
+        if let Some(e) = auth_error {
+            state.toast = Some(Toast::error(e.clone()));
+            state.status = e.clone();
+            state.auth_status.error = Some(e);
+        }
 
         // Restore last screen if available
         if let Some(screen_name) = &cfg.ui.last_screen {
             state.screen = match screen_name.as_str() {
+                "home" => Screen::Home,
+                "explore" => Screen::Explore,
                 "history" => Screen::History,
                 "search" => Screen::Search,
                 "queue" => Screen::Queue,
                 "library" => Screen::Library,
+                "lyrics" => Screen::Lyrics,
                 "settings" => Screen::Settings,
                 "help" => Screen::Help,
                 _ => Screen::History,
@@ -50,13 +83,39 @@ impl App {
             state.sidebar_selected = screen_to_sidebar(state.screen);
         }
 
+        // Bring back the queue from last session
+        let mut resume = None;
+        if let Ok(Some(saved)) = storage.load_playback() {
+            state.queue = crate::queue::Queue::restore(
+                saved.queue,
+                saved.current_index,
+                saved.up_next,
+                saved.shuffle_enabled,
+                saved.shuffle_order,
+            );
+            state.repeat_mode = RepeatMode::from_name(&saved.repeat_mode);
+            state.playing_from_queue = saved.playing_from_queue;
+            if cfg.player.resume_on_start {
+                resume = saved.track.map(|t| (t, saved.position_secs));
+            }
+        }
+        state.queue.set_smart_shuffle(cfg.player.smart_shuffle);
+
+        let (local_playlist_jobs, jobs) = mpsc::unbounded_channel();
+        tokio::spawn(run_local_playlist_jobs(jobs));
+
         Ok(Self {
             cfg,
             config_path,
+            overrides,
             state,
             ytm,
-            lrclib,
+            lyrics_providers,
+            lrclib: crate::lyrics::LrclibClient::new(),
+            art,
             mpv: None,
+            resume,
+            local_playlist_jobs,
         })
     }
 
@@ -85,11 +144,19 @@ impl App {
             }
         }
 
+        // Pick up the track from last session, paused where it was left
+        if let Some((track, position_secs)) = self.resume.take() {
+            self.state.resume_at = Some(position_secs);
+            self.load_track(track, &tx);
+        }
+
         // First draw
+        self.request_art(&tx);
         tui::draw(terminal, &self.cfg, &mut self.state)?;
 
         // Auto-load History on startup
         self.handle_action(Action::LoadHistory, &tx).await;
+        self.check_auth(&tx);
 
         while let Some(ev) = rx.recv().await {
             match ev {
@@ -110,6 +177,7 @@ impl App {
                 break;
             }
 
+            self.request_art(&tx);
             tui::draw(terminal, &self.cfg, &mut self.state)?;
         }
 
@@ -123,6 +191,8 @@ impl App {
         match self.state.screen {
             Screen::Settings => self.spawn_load_audio_devices(tx),
             Screen::Library if !self.state.library_list.loaded => self.spawn_load_library(tx),
+            Screen::Home if !self.state.home.loaded => self.spawn_load_home(tx),
+            Screen::Explore => self.ensure_explore_loaded(tx),
             _ => {}
         }
     }
@@ -133,10 +203,13 @@ impl App {
 
         // Save last screen
         let screen_name = match self.state.screen {
+            Screen::Home => "home",
+            Screen::Explore => "explore",
             Screen::History => "history",
             Screen::Search => "search",
             Screen::Queue => "queue",
             Screen::Library => "library",
+            Screen::Lyrics => "lyrics",
             Screen::Settings => "settings",
             Screen::Help => "help",
         };
@@ -144,6 +217,22 @@ impl App {
 
         // Persist to disk
         let _ = crate::config::save(&self.cfg, Some(&self.config_path));
+
+        // Save the queue and what was playing
+        let queue = &self.state.queue;
+        let saved = crate::storage::SavedPlayback {
+            queue: queue.tracks().to_vec(),
+            current_index: queue.current_index(),
+            up_next: queue.up_next_range().len(),
+            shuffle_enabled: queue.is_shuffle_enabled(),
+            shuffle_order: queue.shuffle_order().to_vec(),
+            repeat_mode: self.state.repeat_mode.as_str().to_string(),
+            playing_from_queue: self.state.playing_from_queue,
+            track: self.state.current_track.clone(),
+            // A track that never got going resumes where it was meant to
+            position_secs: self.state.resume_at.unwrap_or(self.state.position_secs),
+        };
+        let _ = self.storage_cache_handle().save_playback(&saved);
     }
 
     async fn handle_action(&mut self, action: Action, tx: &mpsc::Sender<Event>) {
@@ -157,6 +246,12 @@ impl App {
                 if screen == Screen::Library && !self.state.library_list.loaded {
                     self.spawn_load_library(tx);
                 }
+                if screen == Screen::Home && !self.state.home.loaded {
+                    self.spawn_load_home(tx);
+                }
+                if screen == Screen::Explore {
+                    self.ensure_explore_loaded(tx);
+                }
                 self.reduce(Action::SetScreen(screen));
             }
             Action::NextScreen => {
@@ -185,6 +280,9 @@ impl App {
                     && self.state.search_list.should_load_more(20) {
                         self.spawn_search_more(tx);
                     }
+                if self.state.active_shelves().is_some_and(|s| s.should_load_more()) {
+                    self.spawn_shelves_more(tx);
+                }
             }
             Action::ListUp => {
                 self.reduce(Action::ListUp);
@@ -204,12 +302,28 @@ impl App {
                     && self.state.search_list.should_load_more(20) {
                         self.spawn_search_more(tx);
                     }
+                if self.state.active_shelves().is_some_and(|s| s.should_load_more()) {
+                    self.spawn_shelves_more(tx);
+                }
             }
             Action::LoadHistory => {
                 self.spawn_load_history(tx);
             }
             Action::Refresh => {
                 match self.state.screen {
+                    Screen::Home => {
+                        self.state.home.loaded = false;
+                        self.spawn_load_home(tx);
+                    }
+                    Screen::Explore => {
+                        if self.state.explore.showing_moods() {
+                            self.state.explore.moods_loaded = false;
+                            self.spawn_load_moods(tx);
+                        } else {
+                            self.state.explore.reset_page();
+                            self.spawn_load_explore(tx);
+                        }
+                    }
                     Screen::History => self.spawn_load_history(tx),
                     Screen::Search => {
                         self.spawn_search(tx);
@@ -227,7 +341,9 @@ impl App {
             }
             Action::SettingsFocusNext => {
                 self.state.settings_focus = match self.state.settings_focus {
-                    SettingsFocus::Authentication => SettingsFocus::AudioDevice,
+                    SettingsFocus::Authentication => SettingsFocus::Region,
+                    SettingsFocus::Region => SettingsFocus::Playback,
+                    SettingsFocus::Playback => SettingsFocus::AudioDevice,
                     SettingsFocus::AudioDevice => SettingsFocus::Cache,
                     SettingsFocus::Cache => SettingsFocus::Authentication,
                 };
@@ -235,12 +351,23 @@ impl App {
             Action::SettingsFocusPrev => {
                 self.state.settings_focus = match self.state.settings_focus {
                     SettingsFocus::Authentication => SettingsFocus::Cache,
-                    SettingsFocus::AudioDevice => SettingsFocus::Authentication,
+                    SettingsFocus::Region => SettingsFocus::Authentication,
+                    SettingsFocus::Playback => SettingsFocus::Region,
+                    SettingsFocus::AudioDevice => SettingsFocus::Playback,
                     SettingsFocus::Cache => SettingsFocus::AudioDevice,
                 };
             }
             Action::ApplySelectedBrowser => {
-                self.apply_selected_browser();
+                self.apply_selected_browser(tx);
+            }
+            Action::SwitchAccount => {
+                self.switch_account(tx);
+            }
+            Action::CycleRegionSetting => {
+                self.cycle_region_setting(tx);
+            }
+            Action::ToggleAutoplayRadio => {
+                self.toggle_autoplay_radio();
             }
             Action::ClearCache => {
                 self.clear_cache();
@@ -260,6 +387,46 @@ impl App {
                     return;
                 }
 
+                // On Explore's Moods & genres list, open the selected category
+                if self.state.screen == Screen::Explore && self.state.explore.showing_moods() {
+                    if let Some(category) = self.state.explore.selected_mood().cloned() {
+                        self.state.explore.category = Some(category);
+                        self.state.explore.reset_page();
+                        self.spawn_load_explore(tx);
+                    }
+                    return;
+                }
+
+                // On shelf screens (Home, Explore), play tracks and open collections in place
+                if let Some(shelves) = self.state.active_shelves() {
+                    let target = if shelves.view.is_open() {
+                        shelves
+                            .view
+                            .selected_track()
+                            .cloned()
+                            .map(crate::ytm::models::ShelfItem::Track)
+                    } else {
+                        shelves.selected_item().cloned()
+                    };
+                    match target {
+                        Some(crate::ytm::models::ShelfItem::Track(track)) => {
+                            self.state.playing_from_queue = false;
+                            self.state.status = "Resolving stream...".into();
+                            self.play_track(track, tx).await;
+                        }
+                        Some(item) => {
+                            if let Some(playlist) = item.as_collection().cloned()
+                                && let Some(shelves) = self.state.active_shelves_mut()
+                            {
+                                shelves.view.open(playlist.clone());
+                                self.spawn_load_playlist_tracks(&playlist.id, tx);
+                            }
+                        }
+                        None => {}
+                    }
+                    return;
+                }
+
                 // On Library screen with Playlists tab
                 if self.state.screen == Screen::Library && self.state.library_tab == LibraryTab::Playlists {
                     // If playlist view is open, play the selected track
@@ -282,36 +449,36 @@ impl App {
                 }
 
                 // On Search screen, handle both tracks and playlists
-                if self.state.screen == Screen::Search {
-                    if let Some(item) = self.state.search_list.selected_search_item().cloned() {
-                        use crate::ytm::models::SearchItem;
-                        match item {
-                            SearchItem::Track(track) => {
-                                self.state.playing_from_queue = false;
-                                self.state.now_playing = Some(track.title.clone());
-                                self.state.current_track = Some(track.clone());
-                                self.state.status = "Resolving stream...".into();
-                                self.play_track(track, tx).await;
-                            }
-                            SearchItem::Playlist(playlist) => {
-                                // Open playlist view and switch to Library screen
-                                let playlist_model = crate::ytm::models::Playlist {
-                                    id: playlist.id,
-                                    title: playlist.title,
-                                    author: playlist.author,
-                                    track_count: playlist.track_count,
-                                    thumbnail_url: playlist.thumbnail_url,
-                                };
-                                self.state.playlist_view.open(playlist_model.clone());
-                                self.spawn_load_playlist_tracks(&playlist_model.id, tx);
-                                // Switch to Library with Playlists tab
-                                self.state.screen = Screen::Library;
-                                self.state.sidebar_selected = screen_to_sidebar(Screen::Library);
-                                self.state.library_tab = LibraryTab::Playlists;
-                            }
+                if self.state.screen == Screen::Search
+                    && let Some(item) = self.state.search_list.selected_search_item().cloned()
+                {
+                    use crate::ytm::models::SearchItem;
+                    match item {
+                        SearchItem::Track(track) => {
+                            self.state.playing_from_queue = false;
+                            self.state.now_playing = Some(track.title.clone());
+                            self.state.current_track = Some(track.clone());
+                            self.state.status = "Resolving stream...".into();
+                            self.play_track(track, tx).await;
+                        }
+                        SearchItem::Playlist(playlist) => {
+                            // Open playlist view and switch to Library screen
+                            let playlist_model = crate::ytm::models::Playlist {
+                                id: playlist.id,
+                                title: playlist.title,
+                                author: playlist.author,
+                                track_count: playlist.track_count,
+                                thumbnail_url: playlist.thumbnail_url,
+                            };
+                            self.state.playlist_view.open(playlist_model.clone());
+                            self.spawn_load_playlist_tracks(&playlist_model.id, tx);
+                            // Switch to Library with Playlists tab
+                            self.state.screen = Screen::Library;
+                            self.state.sidebar_selected = screen_to_sidebar(Screen::Library);
+                            self.state.library_tab = LibraryTab::Playlists;
                         }
-                        return;
                     }
+                    return;
                 }
 
                 // "Activate" on a Track plays it (for History, Library Liked Songs)
@@ -350,8 +517,7 @@ impl App {
                     self.spawn_lyrics_fetch(track.clone(), tx.clone());
 
                     let storage = self.storage_cache_handle();
-                    let cookies = self.cfg.ytm.cookies.clone();
-                    let cookies_from_browser = self.cfg.ytm.cookies_from_browser.clone();
+                    let (cookies, cookies_from_browser) = self.cookie_sources();
                     let tx = tx.clone();
 
                     tokio::spawn(async move {
@@ -447,6 +613,19 @@ impl App {
                     let _ = mpv.seek_relative(-10.0).await;
                 }
             }
+            Action::NudgeLyrics(delta) => {
+                let synced = self.state.lyrics.as_ref().is_some_and(|l| l.synced);
+                if let Some(video_id) = self.state.lyrics_video_id.clone()
+                    && synced
+                {
+                    let offset_ms = self.state.lyrics_offset_ms + delta;
+                    self.state.lyrics_offset_ms = offset_ms;
+                    self.state.toast = Some(Toast::success(format!("Lyrics offset {offset_ms:+}ms")));
+
+                    let storage = self.storage_cache_handle();
+                    tokio::task::spawn_blocking(move || storage.set_lyrics_offset(&video_id, offset_ms));
+                }
+            }
             Action::PlayNext => {
                 if !self.state.queue.is_empty() {
                     if let Some(next_track) = self.state.queue.advance() {
@@ -454,6 +633,7 @@ impl App {
                         self.state.playing_from_queue = true;
                         self.state.status = format!("Playing next: {}", track.title);
                         self.play_track(track, tx).await;
+                        self.top_up_radio(tx);
                     } else if self.state.repeat_mode == RepeatMode::All {
                         // At end of queue with Repeat All - go back to start
                         self.state.queue.set_current(0);
@@ -463,6 +643,8 @@ impl App {
                             self.state.status = format!("Restarting queue: {}", track.title);
                             self.play_track(track, tx).await;
                         }
+                    } else if self.state.repeat_mode == RepeatMode::Off && self.cfg.player.autoplay_radio {
+                        self.continue_radio(tx);
                     } else {
                         self.state.toast = Some(Toast::error("End of queue"));
                     }
@@ -501,10 +683,65 @@ impl App {
             Action::LoadPlaylists => {
                 self.spawn_load_playlists(tx);
             }
+            Action::StartRadio => match self.selected_radio_seed() {
+                Some(seed) => self.spawn_start_radio(seed, tx),
+                None => self.state.toast = Some(Toast::error("Nothing to start a radio from")),
+            },
+            Action::ExploreTabNext => {
+                let tab = self.state.explore.tab.next();
+                self.state.explore.set_tab(tab);
+                self.ensure_explore_loaded(tx);
+            }
+            Action::ExploreTabPrev => {
+                let tab = self.state.explore.tab.prev();
+                self.state.explore.set_tab(tab);
+                self.ensure_explore_loaded(tx);
+            }
+            Action::ExploreNextCountry => {
+                if self.state.explore.tab == ExploreTab::Charts {
+                    self.state.explore.next_country();
+                    self.state.explore.reset_page();
+                    self.spawn_load_explore(tx);
+                }
+            }
             Action::OpenPlaylist(playlist) => {
                 self.state.playlist_view.open(playlist.clone());
                 self.spawn_load_playlist_tracks(&playlist.id, tx);
             }
+            Action::SaveLyricsSync => self.save_lyrics_sync(false, tx),
+            Action::PublishLyricsSync => self.save_lyrics_sync(true, tx),
+            Action::OpenLyricsPicker => self.open_lyrics_picker(tx),
+            Action::SeekToLyricsLine => {
+                let line = self.state.lyrics_cursor();
+                self.seek_to_lyrics_line(line).await;
+            }
+            Action::LyricsClick(row) => {
+                let view = &self.state.lyrics_view;
+                let line = row
+                    .checked_sub(view.area.y)
+                    .and_then(|r| view.rows.get(r as usize).copied());
+                if let Some(line) = line {
+                    self.seek_to_lyrics_line(line).await;
+                }
+            }
+            Action::PinLyricsCandidate => self.pin_lyrics_candidate(),
+            Action::LikeSelected => self.like_selected(tx),
+            Action::OpenPlaylistPicker => {
+                let tracks = self.selected_tracks();
+                if tracks.is_empty() {
+                    self.state.toast = Some(Toast::error("No track selected"));
+                } else {
+                    self.state.playlist_picker = Some(crate::app::state::PlaylistPickerState::new(tracks));
+                    self.spawn_load_playlists(tx);
+                }
+            }
+            Action::AddToPickedPlaylist => self.add_to_picked_playlist(tx),
+            Action::RenameLocalPlaylist => self.rename_local_playlist(),
+            Action::DeleteLocalPlaylist => self.delete_local_playlist(tx),
+            Action::LocalPlaylistMoveUp | Action::LocalPlaylistMoveDown | Action::LocalPlaylistRemove => {
+                self.edit_local_playlist_view(&action, tx);
+            }
+            Action::NamePromptSubmit => self.submit_name_prompt(tx),
             _ => self.reduce(action),
         }
     }
@@ -657,6 +894,8 @@ impl App {
     }
 
     fn spawn_load_playlists(&mut self, tx: &mpsc::Sender<Event>) {
+        // Local playlists are always at hand, signed in or not
+        self.reload_local_playlists(tx);
         if self.state.playlist_list.loading || self.state.playlist_list.loaded {
             return;
         }
@@ -685,10 +924,52 @@ impl App {
         });
     }
 
+    /// Start fetching album art the UI is about to show
+    fn request_art(&mut self, tx: &mpsc::Sender<Event>) {
+        let wanted: Vec<String> = [
+            self.state.current_track.as_ref().and_then(|t| t.thumbnail_url(crate::art::ART_MIN_WIDTH)),
+            self.state.playlist_view.playlist.as_ref().and_then(|p| p.thumbnail_url.as_deref()),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|url| self.state.art.wants(url))
+        .map(|url| url.to_string())
+        .collect();
+
+        for url in wanted {
+            self.state.art.pending.insert(url.clone());
+            let art = self.art.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let event = match art.fetch(&url).await {
+                    Ok(image) => crate::app::events::NetworkEvent::ArtLoaded { url, image },
+                    Err(_) => crate::app::events::NetworkEvent::ArtFailed { url },
+                };
+                let _ = tx.send(Event::Network(event)).await;
+            });
+        }
+    }
+
     fn spawn_load_playlist_tracks(&mut self, playlist_id: &str, tx: &mpsc::Sender<Event>) {
-        self.state.playlist_view.loading = true;
         self.state.status = "Loading playlist tracks...".into();
 
+        if let Some(id) = local_playlist_id(playlist_id) {
+            let library = self.library_handle();
+            let tx = tx.clone();
+            let playlist_id = playlist_id.to_string();
+            tokio::spawn(async move {
+                let event = match tokio::task::spawn_blocking(move || library.local_playlist_tracks(id)).await {
+                    Ok(Ok(tracks)) => {
+                        crate::app::events::NetworkEvent::PlaylistTracksLoaded { playlist_id, tracks }
+                    }
+                    Ok(Err(e)) => crate::app::events::NetworkEvent::Error(format!("Playlist tracks: {e:#}")),
+                    Err(e) => crate::app::events::NetworkEvent::Error(format!("spawn error: {e:#}")),
+                };
+                let _ = tx.send(Event::Network(event)).await;
+            });
+            return;
+        }
+
         let ytm = self.ytm.clone();
         let tx = tx.clone();
         let playlist_id = playlist_id.to_string();
@@ -697,7 +978,7 @@ impl App {
                 Ok(tracks) => {
                     let _ = tx
                         .send(Event::Network(crate::app::events::NetworkEvent::PlaylistTracksLoaded {
-                            _playlist_id: playlist_id,
+                            playlist_id,
                             tracks,
                         }))
                         .await;
@@ -713,117 +994,501 @@ impl App {
         });
     }
 
-    fn spawn_load_audio_devices(&mut self, tx: &mpsc::Sender<Event>) {
-        self.state.audio_loaded = false;
-        self.state.status = "Loading audio devices...".into();
+    fn spawn_load_home(&mut self, tx: &mpsc::Sender<Event>) {
+        if self.state.home.loading {
+            return;
+        }
+        self.state.home.loading = true;
+        self.state.home.continuation = None;
+        self.state.status = "Loading home...".into();
 
+        let ytm = self.ytm.clone();
         let tx = tx.clone();
         tokio::spawn(async move {
-            let out = tokio::process::Command::new("mpv")
-                .args(["--audio-device=help", "--no-video", "--idle=no"])
-                .output()
-                .await;
-
-            let out = match out {
-                Ok(o) => o,
+            match ytm.browse_home().await {
+                Ok(result) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::HomeLoaded {
+                            shelves: result.shelves,
+                            continuation: result.continuation,
+                        }))
+                        .await;
+                }
                 Err(e) => {
                     let _ = tx
-                        .send(Event::Network(crate::app::events::NetworkEvent::Error(format!(
-                            "mpv audio devices failed: {e}"
-                        ))))
+                        .send(Event::Network(crate::app::events::NetworkEvent::Error(
+                            format!("Home: {e:#}"),
+                        )))
                         .await;
-                    return;
                 }
-            };
+            }
+        });
+    }
 
-            let text = String::from_utf8_lossy(&out.stdout);
-            let mut devices = Vec::new();
-            for line in text.lines() {
-                let line = line.trim();
-                if !line.starts_with('\'') {
-                    continue;
+    fn spawn_home_more(&mut self, tx: &mpsc::Sender<Event>) {
+        if self.state.home.loading_more {
+            return;
+        }
+        let continuation = match &self.state.home.continuation {
+            Some(c) => c.clone(),
+            None => return,
+        };
+
+        self.state.home.loading_more = true;
+        self.state.status = "Loading more shelves...".into();
+
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            match ytm.browse_shelves_continue(&continuation).await {
+                Ok(result) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::HomeMoreLoaded {
+                            shelves: result.shelves,
+                            continuation: result.continuation,
+                        }))
+                        .await;
                 }
-                // "'name' (desc)"
-                if let Some(end) = line[1..].find('\'') {
-                    let name = line[1..1 + end].to_string();
-                    let rest = line[1 + end + 1..].trim();
-                    let desc = rest
-                        .trim_start_matches('(')
-                        .trim_end_matches(')')
-                        .to_string();
-                    let _ = desc; // unused but parsed
-                    devices.push(crate::app::state::AudioDevice { name });
+                Err(e) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::Error(
+                            format!("Load more failed: {e:#}"),
+                        )))
+                        .await;
                 }
             }
+        });
+    }
 
-            if devices.is_empty() {
-                devices.push(crate::app::state::AudioDevice { name: "auto".into() });
+    fn spawn_shelves_more(&mut self, tx: &mpsc::Sender<Event>) {
+        match self.state.screen {
+            Screen::Home => self.spawn_home_more(tx),
+            Screen::Explore => self.spawn_explore_more(tx),
+            _ => {}
+        }
+    }
+
+    /// Load whatever the current Explore tab shows, unless it is already loaded
+    fn ensure_explore_loaded(&mut self, tx: &mpsc::Sender<Event>) {
+        if self.state.explore.showing_moods() {
+            if !self.state.explore.moods_loaded {
+                self.spawn_load_moods(tx);
             }
+        } else if !self.state.explore.page.loaded {
+            self.spawn_load_explore(tx);
+        }
+    }
 
-            let _ = tx
-                .send(Event::Network(crate::app::events::NetworkEvent::AudioDevices { devices }))
-                .await;
+    fn spawn_load_explore(&mut self, tx: &mpsc::Sender<Event>) {
+        let explore = &mut self.state.explore;
+        if explore.page.loading {
+            return;
+        }
+        let tab = explore.tab;
+        let key = explore.page_key();
+        let country = explore.country_code();
+        let params = explore.category.as_ref().map(|c| c.params.clone());
+        if tab == ExploreTab::MoodsGenres && params.is_none() {
+            return;
+        }
+        explore.page.loading = true;
+        explore.page.continuation = None;
+        self.state.status = format!("Loading {}...", tab.label());
+
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let result = match (tab, params) {
+                (ExploreTab::Explore, _) => ytm.browse_explore().await,
+                (ExploreTab::NewReleases, _) => ytm.browse_new_releases().await,
+                (ExploreTab::Charts, _) => ytm.browse_charts(country).await,
+                (ExploreTab::MoodsGenres, Some(params)) => ytm.browse_mood_category(&params).await,
+                (ExploreTab::MoodsGenres, None) => return,
+            };
+            match result {
+                Ok(page) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::ExploreLoaded {
+                            key,
+                            shelves: page.shelves,
+                            continuation: page.continuation,
+                        }))
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::Error(
+                            format!("{}: {e:#}", tab.label()),
+                        )))
+                        .await;
+                }
+            }
         });
     }
 
-    fn reduce(&mut self, action: Action) {
-        match action {
-            Action::Quit => self.state.should_quit = true,
-            Action::NextScreen => {
-                self.state.screen = self.state.screen.next();
-                self.state.sidebar_selected = screen_to_sidebar(self.state.screen);
-                if self.state.screen == Screen::Search {
-                    self.state.search_focus = SearchFocus::Input;
+    fn spawn_explore_more(&mut self, tx: &mpsc::Sender<Event>) {
+        let explore = &mut self.state.explore;
+        if explore.page.loading_more {
+            return;
+        }
+        let continuation = match &explore.page.continuation {
+            Some(c) => c.clone(),
+            None => return,
+        };
+        let key = explore.page_key();
+
+        explore.page.loading_more = true;
+        self.state.status = "Loading more shelves...".into();
+
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            match ytm.browse_shelves_continue(&continuation).await {
+                Ok(page) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::ExploreMoreLoaded {
+                            key,
+                            shelves: page.shelves,
+                            continuation: page.continuation,
+                        }))
+                        .await;
                 }
-            }
-            Action::PrevScreen => {
-                self.state.screen = self.state.screen.prev();
-                self.state.sidebar_selected = screen_to_sidebar(self.state.screen);
-                if self.state.screen == Screen::Search {
-                    self.state.search_focus = SearchFocus::Input;
+                Err(e) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::Error(
+                            format!("Load more failed: {e:#}"),
+                        )))
+                        .await;
                 }
             }
-            Action::SidebarUp => {
-                self.state.sidebar_selected = self.state.sidebar_selected.saturating_sub(1);
-                self.state.screen = sidebar_to_screen(self.state.sidebar_selected);
-                if self.state.screen == Screen::Search {
-                    self.state.search_focus = SearchFocus::Input;
+        });
+    }
+
+    fn spawn_load_moods(&mut self, tx: &mpsc::Sender<Event>) {
+        if self.state.explore.moods_loading {
+            return;
+        }
+        self.state.explore.moods_loading = true;
+        self.state.status = "Loading moods & genres...".into();
+
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            match ytm.get_mood_categories().await {
+                Ok(categories) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::MoodsLoaded { categories }))
+                        .await;
                 }
-            }
-            Action::SidebarDown => {
-                self.state.sidebar_selected = (self.state.sidebar_selected + 1).min(5);
-                self.state.screen = sidebar_to_screen(self.state.sidebar_selected);
-                if self.state.screen == Screen::Search {
-                    self.state.search_focus = SearchFocus::Input;
+                Err(e) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::Error(
+                            format!("Moods & genres: {e:#}"),
+                        )))
+                        .await;
                 }
             }
-            Action::ListUp => {
-                if self.state.screen == Screen::Settings {
-                    match self.state.settings_focus {
-                        SettingsFocus::Authentication => {
-                            self.state.auth_selected = self.state.auth_selected.saturating_sub(1);
-                        }
-                        SettingsFocus::AudioDevice => {
-                            self.state.audio_selected = self.state.audio_selected.saturating_sub(1);
-                        }
-                        SettingsFocus::Cache => {}
-                    }
-                } else if self.state.screen == Screen::Queue {
-                    self.state.queue_list.select_prev();
-                    self.state.queue_list.update_scroll(20);
-                } else if self.state.screen == Screen::Library && self.state.library_tab == LibraryTab::Playlists {
-                    if self.state.playlist_view.is_open() {
-                        self.state.playlist_view.select_prev();
-                        self.state.playlist_view.update_scroll(20);
-                    } else {
-                        self.state.playlist_list.select_prev();
-                        self.state.playlist_list.update_scroll(20);
-                    }
-                } else {
-                    let list = self.state.active_list_mut();
-                    list.select_prev();
-                    list.update_scroll(20);
-                }
+        });
+    }
+
+    /// Look up the signed-in account and warn when the login cookies are about to expire
+    fn check_auth(&mut self, tx: &mpsc::Sender<Event>) {
+        if self.ytm.auth().is_none() {
+            return;
+        }
+
+        let now = ytm::auth::unix_now();
+        if self.state.auth_status.expiring_soon(now)
+            && let Some(t) = self.state.auth_status.expires_utc
+        {
+            let msg = if t <= now {
+                "Login cookies have expired; sign in again".to_string()
+            } else {
+                format!("Login cookies expire {}", ytm::auth::describe_expiry(t, now))
+            };
+            self.state.toast = Some(Toast::error(msg));
+        }
+
+        self.state.auth_status.checking = true;
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let ev = match ytm.get_account_info().await {
+                Ok(account) => crate::app::events::NetworkEvent::AccountLoaded { account },
+                Err(e) => crate::app::events::NetworkEvent::AccountCheckFailed {
+                    error: format!("{e:#}"),
+                },
+            };
+            let _ = tx.send(Event::Network(ev)).await;
+
+            // Brand accounts to offer when switching; not every login has them
+            if let Ok(channels) = ytm.get_channels().await {
+                let _ = tx
+                    .send(Event::Network(crate::app::events::NetworkEvent::ChannelsLoaded { channels }))
+                    .await;
+            }
+        });
+    }
+
+    /// Owned cookie settings of the active account, for yt-dlp
+    fn cookie_sources(&self) -> (Option<std::path::PathBuf>, Option<String>) {
+        let (cookies, browser) = self.cfg.ytm.cookie_sources();
+        (cookies.map(|p| p.to_path_buf()), browser.map(|b| b.to_string()))
+    }
+
+    /// The track or collection under the cursor, as a radio seed
+    fn selected_radio_seed(&self) -> Option<crate::ytm::models::ShelfItem> {
+        use crate::ytm::models::{SearchItem, ShelfItem};
+
+        if let Some(shelves) = self.state.active_shelves() {
+            return if shelves.view.is_open() {
+                shelves.view.selected_track().cloned().map(ShelfItem::Track)
+            } else {
+                shelves.selected_item().cloned()
+            };
+        }
+
+        match self.state.screen {
+            Screen::Queue => self
+                .state
+                .queue
+                .tracks()
+                .get(self.state.queue_list.selected)
+                .cloned()
+                .map(ShelfItem::Track),
+            Screen::Library if self.state.library_tab == LibraryTab::Playlists => {
+                if self.state.playlist_view.is_open() {
+                    self.state.playlist_view.selected_track().cloned().map(ShelfItem::Track)
+                } else {
+                    // Local playlists have no radio of their own
+                    self.state
+                        .playlist_list
+                        .selected_playlist()
+                        .filter(|p| local_playlist_id(&p.id).is_none())
+                        .cloned()
+                        .map(ShelfItem::Playlist)
+                }
+            }
+            Screen::Search => match self.state.search_list.selected_search_item()? {
+                SearchItem::Track(t) => Some(ShelfItem::Track(t.clone())),
+                SearchItem::Playlist(p) => Some(ShelfItem::Playlist(p.clone())),
+            },
+            Screen::History | Screen::Library => {
+                self.state.active_list().selected_track().cloned().map(ShelfItem::Track)
+            }
+            _ => None,
+        }
+    }
+
+    /// Keep playback going once the queue has run out: follow the current radio,
+    /// or start one seeded from the track that just finished
+    fn continue_radio(&mut self, tx: &mpsc::Sender<Event>) {
+        self.state.radio.pending_play = true;
+        if self.state.radio.loading {
+            return;
+        }
+        let radio = &self.state.radio;
+        if let (Some(playlist_id), Some(continuation)) = (radio.playlist_id.clone(), radio.continuation.clone()) {
+            self.spawn_radio_more(playlist_id, continuation, tx);
+        } else if let Some(track) = self.state.current_track.clone() {
+            self.spawn_start_radio(crate::ytm::models::ShelfItem::Track(track), tx);
+        } else {
+            self.state.radio.pending_play = false;
+            self.state.status = "Playback ended".into();
+        }
+    }
+
+    /// Fetch the next radio batch ahead of time when the queue is about to run out
+    fn top_up_radio(&mut self, tx: &mpsc::Sender<Event>) {
+        let radio = &self.state.radio;
+        if !self.cfg.player.autoplay_radio || radio.loading || self.state.queue.remaining() >= 3 {
+            return;
+        }
+        if let (Some(playlist_id), Some(continuation)) = (radio.playlist_id.clone(), radio.continuation.clone()) {
+            self.spawn_radio_more(playlist_id, continuation, tx);
+        }
+    }
+
+    fn spawn_start_radio(&mut self, seed: crate::ytm::models::ShelfItem, tx: &mpsc::Sender<Event>) {
+        let seed_title = seed.title().to_string();
+        self.state.radio.loading = true;
+        self.state.radio.pending_play = true;
+        self.state.status = format!("Starting radio: {}", seed_title);
+
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            match ytm.start_radio(&seed).await {
+                Ok(page) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::RadioLoaded {
+                            page,
+                            seed_title: Some(seed_title),
+                        }))
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::Error(
+                            format!("Radio: {e:#}"),
+                        )))
+                        .await;
+                }
+            }
+        });
+    }
+
+    fn spawn_radio_more(&mut self, playlist_id: String, continuation: String, tx: &mpsc::Sender<Event>) {
+        self.state.radio.loading = true;
+
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            match ytm.get_radio_continue(&playlist_id, &continuation).await {
+                Ok(page) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::RadioLoaded {
+                            page,
+                            seed_title: None,
+                        }))
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::Error(
+                            format!("Radio: {e:#}"),
+                        )))
+                        .await;
+                }
+            }
+        });
+    }
+
+    fn spawn_load_audio_devices(&mut self, tx: &mpsc::Sender<Event>) {
+        self.state.audio_loaded = false;
+        self.state.status = "Loading audio devices...".into();
+
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let out = tokio::process::Command::new("mpv")
+                .args(["--audio-device=help", "--no-video", "--idle=no"])
+                .output()
+                .await;
+
+            let out = match out {
+                Ok(o) => o,
+                Err(e) => {
+                    let _ = tx
+                        .send(Event::Network(crate::app::events::NetworkEvent::Error(format!(
+                            "mpv audio devices failed: {e}"
+                        ))))
+                        .await;
+                    return;
+                }
+            };
+
+            let text = String::from_utf8_lossy(&out.stdout);
+            let mut devices = Vec::new();
+            for line in text.lines() {
+                let line = line.trim();
+                if !line.starts_with('\'') {
+                    continue;
+                }
+                // "'name' (desc)"
+                if let Some(end) = line[1..].find('\'') {
+                    let name = line[1..1 + end].to_string();
+                    let rest = line[1 + end + 1..].trim();
+                    let desc = rest
+                        .trim_start_matches('(')
+                        .trim_end_matches(')')
+                        .to_string();
+                    let _ = desc; // unused but parsed
+                    devices.push(crate::app::state::AudioDevice { name });
+                }
+            }
+
+            if devices.is_empty() {
+                devices.push(crate::app::state::AudioDevice { name: "auto".into() });
+            }
+
+            let _ = tx
+                .send(Event::Network(crate::app::events::NetworkEvent::AudioDevices { devices }))
+                .await;
+        });
+    }
+
+    fn reduce(&mut self, action: Action) {
+        match action {
+            Action::Quit => self.state.should_quit = true,
+            Action::NextScreen => {
+                self.state.screen = self.state.screen.next();
+                self.state.sidebar_selected = screen_to_sidebar(self.state.screen);
+                if self.state.screen == Screen::Search {
+                    self.state.search_focus = SearchFocus::Input;
+                }
+            }
+            Action::PrevScreen => {
+                self.state.screen = self.state.screen.prev();
+                self.state.sidebar_selected = screen_to_sidebar(self.state.screen);
+                if self.state.screen == Screen::Search {
+                    self.state.search_focus = SearchFocus::Input;
+                }
+            }
+            Action::SidebarUp => {
+                self.state.sidebar_selected = self.state.sidebar_selected.saturating_sub(1);
+                self.state.screen = sidebar_to_screen(self.state.sidebar_selected);
+                if self.state.screen == Screen::Search {
+                    self.state.search_focus = SearchFocus::Input;
+                }
+            }
+            Action::SidebarDown => {
+                self.state.sidebar_selected = (self.state.sidebar_selected + 1).min(8);
+                self.state.screen = sidebar_to_screen(self.state.sidebar_selected);
+                if self.state.screen == Screen::Search {
+                    self.state.search_focus = SearchFocus::Input;
+                }
+            }
+            Action::ListUp => {
+                if self.state.screen == Screen::Settings {
+                    match self.state.settings_focus {
+                        SettingsFocus::Authentication => {
+                            self.state.auth_selected = self.state.auth_selected.saturating_sub(1);
+                        }
+                        SettingsFocus::Region => self.state.region_selected = 0,
+                        SettingsFocus::AudioDevice => {
+                            self.state.audio_selected = self.state.audio_selected.saturating_sub(1);
+                        }
+                        SettingsFocus::Playback | SettingsFocus::Cache => {}
+                    }
+                } else if self.state.screen == Screen::Lyrics {
+                    self.state.move_lyrics_cursor(-1);
+                } else if self.state.screen == Screen::Queue {
+                    self.state.queue_list.select_prev();
+                    self.state.queue_list.update_scroll(20);
+                } else if let Some(shelves) = self.state.active_shelves_mut() {
+                    if shelves.view.is_open() {
+                        shelves.view.select_prev();
+                        shelves.view.update_scroll(20);
+                    } else {
+                        shelves.select_up();
+                    }
+                } else if self.state.screen == Screen::Explore {
+                    let explore = &mut self.state.explore;
+                    explore.select_mood(explore.moods_selected.saturating_sub(1));
+                    explore.update_moods_scroll(20);
+                } else if self.state.screen == Screen::Library && self.state.library_tab == LibraryTab::Playlists {
+                    if self.state.playlist_view.is_open() {
+                        self.state.playlist_view.select_prev();
+                        self.state.playlist_view.update_scroll(20);
+                    } else {
+                        self.state.playlist_list.select_prev();
+                        self.state.playlist_list.update_scroll(20);
+                    }
+                } else {
+                    let list = self.state.active_list_mut();
+                    list.select_prev();
+                    list.update_scroll(20);
+                }
             }
             Action::ListDown => {
                 if self.state.screen == Screen::Settings {
@@ -832,16 +1497,30 @@ impl App {
                             self.state.auth_selected =
                                 (self.state.auth_selected + 1).min(self.state.auth_browsers.len().saturating_sub(1));
                         }
+                        SettingsFocus::Region => self.state.region_selected = 1,
                         SettingsFocus::AudioDevice => {
                             self.state.audio_selected =
                                 (self.state.audio_selected + 1).min(self.state.audio_devices.len().saturating_sub(1));
                         }
-                        SettingsFocus::Cache => {}
+                        SettingsFocus::Playback | SettingsFocus::Cache => {}
                     }
+                } else if self.state.screen == Screen::Lyrics {
+                    self.state.move_lyrics_cursor(1);
                 } else if self.state.screen == Screen::Queue {
                     let len = self.state.queue.len();
                     self.state.queue_list.select_next(len);
                     self.state.queue_list.update_scroll(20);
+                } else if let Some(shelves) = self.state.active_shelves_mut() {
+                    if shelves.view.is_open() {
+                        shelves.view.select_next();
+                        shelves.view.update_scroll(20);
+                    } else {
+                        shelves.select_down();
+                    }
+                } else if self.state.screen == Screen::Explore {
+                    let explore = &mut self.state.explore;
+                    explore.select_mood(explore.moods_selected + 1);
+                    explore.update_moods_scroll(20);
                 } else if self.state.screen == Screen::Library && self.state.library_tab == LibraryTab::Playlists {
                     if self.state.playlist_view.is_open() {
                         self.state.playlist_view.select_next();
@@ -860,12 +1539,25 @@ impl App {
                 if self.state.screen == Screen::Settings {
                     match self.state.settings_focus {
                         SettingsFocus::Authentication => self.state.auth_selected = 0,
+                        SettingsFocus::Region => self.state.region_selected = 0,
                         SettingsFocus::AudioDevice => self.state.audio_selected = 0,
-                        SettingsFocus::Cache => {}
+                        SettingsFocus::Playback | SettingsFocus::Cache => {}
                     }
+                } else if self.state.screen == Screen::Lyrics {
+                    self.state.move_lyrics_cursor(isize::MIN);
                 } else if self.state.screen == Screen::Queue {
                     self.state.queue_list.selected = 0;
                     self.state.queue_list.scroll_offset = 0;
+                } else if let Some(shelves) = self.state.active_shelves_mut() {
+                    if shelves.view.is_open() {
+                        shelves.view.selected = 0;
+                        shelves.view.scroll_offset = 0;
+                    } else {
+                        shelves.select_first_shelf();
+                    }
+                } else if self.state.screen == Screen::Explore {
+                    self.state.explore.moods_selected = 0;
+                    self.state.explore.moods_scroll = 0;
                 } else {
                     let list = self.state.active_list_mut();
                     list.selected = 0;
@@ -878,14 +1570,28 @@ impl App {
                         SettingsFocus::Authentication => {
                             self.state.auth_selected = self.state.auth_browsers.len().saturating_sub(1);
                         }
+                        SettingsFocus::Region => self.state.region_selected = 1,
                         SettingsFocus::AudioDevice => {
                             self.state.audio_selected = self.state.audio_devices.len().saturating_sub(1);
                         }
-                        SettingsFocus::Cache => {}
+                        SettingsFocus::Playback | SettingsFocus::Cache => {}
                     }
+                } else if self.state.screen == Screen::Lyrics {
+                    self.state.move_lyrics_cursor(isize::MAX);
                 } else if self.state.screen == Screen::Queue {
                     self.state.queue_list.selected = self.state.queue.len().saturating_sub(1);
                     self.state.queue_list.update_scroll(20);
+                } else if let Some(shelves) = self.state.active_shelves_mut() {
+                    if shelves.view.is_open() {
+                        shelves.view.selected = shelves.view.tracks.len().saturating_sub(1);
+                        shelves.view.update_scroll(20);
+                    } else {
+                        shelves.select_last_shelf();
+                    }
+                } else if self.state.screen == Screen::Explore {
+                    let explore = &mut self.state.explore;
+                    explore.select_mood(explore.moods.len().saturating_sub(1));
+                    explore.update_moods_scroll(20);
                 } else {
                     let list = self.state.active_list_mut();
                     list.selected = list.items.len().saturating_sub(1);
@@ -898,14 +1604,25 @@ impl App {
                         SettingsFocus::Authentication => {
                             self.state.auth_selected = self.state.auth_selected.saturating_sub(10);
                         }
+                        SettingsFocus::Region => self.state.region_selected = 0,
                         SettingsFocus::AudioDevice => {
                             self.state.audio_selected = self.state.audio_selected.saturating_sub(10);
                         }
-                        SettingsFocus::Cache => {}
+                        SettingsFocus::Playback | SettingsFocus::Cache => {}
                     }
+                } else if self.state.screen == Screen::Lyrics {
+                    self.state.move_lyrics_cursor(-10);
                 } else if self.state.screen == Screen::Queue {
                     self.state.queue_list.selected = self.state.queue_list.selected.saturating_sub(10);
                     self.state.queue_list.update_scroll(20);
+                } else if let Some(shelves) = self.state.active_shelves_mut() {
+                    let view = &mut shelves.view;
+                    view.selected = view.selected.saturating_sub(10);
+                    view.update_scroll(20);
+                } else if self.state.screen == Screen::Explore {
+                    let explore = &mut self.state.explore;
+                    explore.select_mood(explore.moods_selected.saturating_sub(10));
+                    explore.update_moods_scroll(20);
                 } else {
                     let list = self.state.active_list_mut();
                     list.selected = list.selected.saturating_sub(10);
@@ -919,16 +1636,27 @@ impl App {
                             self.state.auth_selected =
                                 (self.state.auth_selected + 10).min(self.state.auth_browsers.len().saturating_sub(1));
                         }
+                        SettingsFocus::Region => self.state.region_selected = 1,
                         SettingsFocus::AudioDevice => {
                             self.state.audio_selected =
                                 (self.state.audio_selected + 10).min(self.state.audio_devices.len().saturating_sub(1));
                         }
-                        SettingsFocus::Cache => {}
+                        SettingsFocus::Playback | SettingsFocus::Cache => {}
                     }
+                } else if self.state.screen == Screen::Lyrics {
+                    self.state.move_lyrics_cursor(10);
                 } else if self.state.screen == Screen::Queue {
                     let len = self.state.queue.len();
                     self.state.queue_list.selected = (self.state.queue_list.selected + 10).min(len.saturating_sub(1));
                     self.state.queue_list.update_scroll(20);
+                } else if let Some(shelves) = self.state.active_shelves_mut() {
+                    let view = &mut shelves.view;
+                    view.selected = (view.selected + 10).min(view.tracks.len().saturating_sub(1));
+                    view.update_scroll(20);
+                } else if self.state.screen == Screen::Explore {
+                    let explore = &mut self.state.explore;
+                    explore.select_mood(explore.moods_selected + 10);
+                    explore.update_moods_scroll(20);
                 } else {
                     let list = self.state.active_list_mut();
                     list.selected = (list.selected + 10).min(list.items.len().saturating_sub(1));
@@ -968,11 +1696,116 @@ impl App {
             Action::Refresh => {}
             Action::ApplySelectedAudioDevice => {}
             Action::ApplySelectedBrowser => {}
+            Action::SwitchAccount => {}
+            Action::CycleRegionSetting => {}
+            Action::ToggleAutoplayRadio => {}
             Action::TogglePause => {}
             Action::VolumeUp => {}
             Action::VolumeDown => {}
             Action::SeekForward => {}
             Action::SeekBack => {}
+            Action::NudgeLyrics(_) => {} // Handled in handle_action
+
+            // Lyrics sync mode
+            Action::ToggleLyricsSync => {
+                if self.state.lyrics_sync.take().is_some() {
+                    self.state.toast = Some(Toast::success("Sync cancelled"));
+                } else {
+                    match (&self.state.lyrics, &self.state.lyrics_video_id) {
+                        (Some(lyrics), Some(video_id)) if !lyrics.synced && !lyrics.lines.is_empty() => {
+                            self.state.lyrics_sync =
+                                Some(crate::lyrics::SyncSession::new(video_id.clone(), lyrics));
+                            self.state.toast = Some(Toast::success(
+                                "Sync mode: Enter stamps a line, Backspace undoes, { } adjust",
+                            ));
+                        }
+                        (Some(lyrics), _) if lyrics.synced => {
+                            self.state.toast = Some(Toast::error("Lyrics are already synced"));
+                        }
+                        _ => self.state.toast = Some(Toast::error("No lyrics to sync")),
+                    }
+                }
+            }
+            Action::SyncStamp => {
+                let position_ms = (self.state.position_secs * 1000.0) as u64;
+                if let Some(session) = &mut self.state.lyrics_sync
+                    && session.stamp(position_ms)
+                    && session.is_complete()
+                {
+                    self.state.toast = Some(Toast::success(
+                        "All lines stamped: w saves, P saves and publishes to LRCLIB",
+                    ));
+                }
+            }
+            Action::SyncUndo => {
+                if let Some(session) = &mut self.state.lyrics_sync {
+                    session.undo();
+                }
+            }
+            Action::SyncAdjust(delta) => {
+                if let Some(session) = &mut self.state.lyrics_sync {
+                    session.adjust(delta);
+                }
+            }
+            Action::SaveLyricsSync => {}    // Handled in handle_action
+            Action::PublishLyricsSync => {} // Handled in handle_action
+
+            // Kept in the config so the choice survives a restart
+            Action::ToggleLyricsSecondary => {
+                let show = !self.state.lyrics_show_secondary;
+                self.state.lyrics_show_secondary = show;
+                self.cfg.lyrics.show_secondary = show;
+                let msg = if show { "Showing translations" } else { "Hiding translations" };
+                self.state.toast = Some(Toast::success(msg));
+            }
+            Action::ToggleLyricsRomanize => {
+                let on = !self.state.lyrics_romanize;
+                self.state.lyrics_romanize = on;
+                self.cfg.lyrics.romanize = on;
+                let msg = if on { "Romanization on" } else { "Romanization off" };
+                self.state.toast = Some(Toast::success(msg));
+            }
+
+            // Lyrics screen
+            Action::FollowLyrics => {
+                self.state.lyrics_view.browsing = false;
+                self.state.lyrics_view.search_query.clear();
+            }
+            Action::SeekToLyricsLine => {} // Handled in handle_action
+            Action::LyricsClick(_) => {}   // Handled in handle_action
+            Action::StartLyricsSearch => {
+                self.state.lyrics_view.searching = true;
+                self.state.lyrics_view.search_query.clear();
+            }
+            Action::LyricsSearchInput(c) => self.state.lyrics_view.search_query.push(c),
+            Action::LyricsSearchBackspace => {
+                self.state.lyrics_view.search_query.pop();
+            }
+            Action::LyricsSearchCancel => {
+                self.state.lyrics_view.searching = false;
+                self.state.lyrics_view.search_query.clear();
+            }
+            Action::LyricsSearchSubmit => {
+                self.state.lyrics_view.searching = false;
+                self.jump_to_lyrics_match(true);
+            }
+            Action::LyricsSearchNext => self.jump_to_lyrics_match(true),
+            Action::LyricsSearchPrev => self.jump_to_lyrics_match(false),
+
+            // LRCLIB candidate picker
+            Action::OpenLyricsPicker => {}   // Handled in handle_action
+            Action::PinLyricsCandidate => {} // Handled in handle_action
+            Action::CloseLyricsPicker => self.state.lyrics_picker = None,
+            Action::LyricsPickerUp => {
+                if let Some(picker) = &mut self.state.lyrics_picker {
+                    picker.select_prev();
+                }
+            }
+            Action::LyricsPickerDown => {
+                if let Some(picker) = &mut self.state.lyrics_picker {
+                    picker.select_next();
+                }
+            }
             Action::SettingsFocusNext => {} // Handled in handle_action
             Action::SettingsFocusPrev => {} // Handled in handle_action
             Action::ClearCache => {} // Handled in handle_action
@@ -987,6 +1820,14 @@ impl App {
             Action::QueueReplace(tracks) => {
                 self.state.queue.replace(tracks);
             }
+            Action::QueueRemove(_) if !self.state.queue_list.selection.is_empty() => {
+                let rows = self.state.queue_list.selection.rows();
+                let removed = self.state.queue.remove_many(&rows);
+                self.state.queue_list.selection.clear();
+                self.state.toast = Some(Toast::success(format!("Removed {removed} tracks")));
+                let len = self.state.queue.len();
+                self.state.queue_list.selected = self.state.queue_list.selected.min(len.saturating_sub(1));
+            }
             Action::QueueRemove(_) => {
                 // Remove the currently selected track in the queue view
                 let selected = self.state.queue_list.selected;
@@ -1000,6 +1841,8 @@ impl App {
             }
             Action::QueueClear => {
                 self.state.queue.clear();
+                self.state.queue_list.selection.clear();
+                self.state.radio.stop();
                 self.state.toast = Some(Toast::success("Queue cleared"));
             }
             Action::QueueShuffle => {
@@ -1011,6 +1854,19 @@ impl App {
                 };
                 self.state.toast = Some(Toast::success(status));
             }
+            Action::QueueMoveUp | Action::QueueMoveDown
+                if self.state.screen == Screen::Queue && !self.state.queue_list.selection.is_empty() =>
+            {
+                // The marked tracks move as a block, and the cursor with them
+                let list = &mut self.state.queue_list;
+                let rows = list.selection.rows();
+                let moved = self.state.queue.move_many(&rows, matches!(action, Action::QueueMoveUp));
+                if let Some(k) = rows.iter().position(|&r| r == list.selected) {
+                    list.selected = moved[k];
+                }
+                list.selection.set_rows(moved);
+                list.update_scroll(20);
+            }
             Action::QueueMoveUp => {
                 if self.state.screen == Screen::Queue {
                     let selected = self.state.queue_list.selected;
@@ -1029,47 +1885,157 @@ impl App {
                     }
                 }
             }
+            Action::QueueUndo | Action::QueueRedo => {
+                let undo = matches!(action, Action::QueueUndo);
+                let done = if undo { self.state.queue.undo() } else { self.state.queue.redo() };
+                self.state.toast = Some(match done {
+                    Some(what) if undo => Toast::success(format!("Undid: {what}")),
+                    Some(what) => Toast::success(format!("Redid: {what}")),
+                    None if undo => Toast::error("Nothing to undo"),
+                    None => Toast::error("Nothing to redo"),
+                });
+                let len = self.state.queue.len();
+                self.state.queue_list.selected = self.state.queue_list.selected.min(len.saturating_sub(1));
+                self.state.queue_list.selection.clear();
+            }
             Action::QueuePlayIndex(index) => {
                 self.state.queue.set_current(index);
             }
             Action::PlayNext => {} // Handled in handle_action
+            Action::StartRadio => {} // Handled in handle_action
             Action::PlayPrev => {} // Handled in handle_action
             Action::AddSelectedToQueue => {
-                // Add currently selected track to queue
-                if self.state.screen == Screen::Library
-                    && self.state.library_tab == LibraryTab::Playlists
-                    && self.state.playlist_view.is_open()
-                {
-                    if let Some(track) = self.state.playlist_view.selected_track().cloned() {
-                        self.state.queue.add(track.clone());
-                        self.state.toast = Some(Toast::success(format!("Added to queue: {}", track.title)));
-                    }
-                } else if let Some(track) = self.state.active_list().selected_track().cloned() {
-                    self.state.queue.add(track.clone());
-                    self.state.toast = Some(Toast::success(format!("Added to queue: {}", track.title)));
+                // Add the marked tracks, or the one under the cursor
+                let tracks = self.selected_tracks();
+                self.state.toast = match tracks.as_slice() {
+                    [] => None,
+                    [track] => Some(Toast::success(format!("Added to queue: {}", track.title))),
+                    many => Some(Toast::success(format!("Added {} tracks to queue", many.len()))),
+                };
+                self.state.queue.add_many(tracks);
+                self.clear_selection();
+            }
+            Action::PlaySelectedNext => {
+                let tracks = self.selected_tracks();
+                self.state.toast = match tracks.as_slice() {
+                    [] => None,
+                    [track] => Some(Toast::success(format!("Playing next: {}", track.title))),
+                    many => Some(Toast::success(format!("Playing {} tracks next", many.len()))),
+                };
+                self.state.queue.play_next_many(tracks);
+                self.clear_selection();
+            }
+            Action::ToggleSelectMode => {
+                if let Some((selection, cursor)) = self.state.active_selection_mut() {
+                    selection.toggle_mode(cursor);
                 }
             }
-            Action::AddAllToQueue => {
-                // Add all tracks from playlist view to queue
-                if self.state.screen == Screen::Library
-                    && self.state.library_tab == LibraryTab::Playlists
-                    && self.state.playlist_view.is_open()
-                {
-                    let tracks = self.state.playlist_view.tracks.clone();
-                    let count = tracks.len();
-                    self.state.queue.add_many(tracks);
-                    self.state.toast = Some(Toast::success(format!("Added {} tracks to queue", count)));
+            Action::ToggleSelected => {
+                if let Some((selection, cursor)) = self.state.active_selection_mut() {
+                    selection.toggle(cursor);
                 }
             }
-
-            // Library tab actions - all handled in handle_action for async loading
-            Action::LibraryTabNext => {}
-            Action::LibraryTabPrev => {}
-            Action::LoadPlaylists => {}
-            Action::OpenPlaylist(_) => {}
-            Action::ClosePlaylist => {
-                self.state.playlist_view.close();
+            Action::SelectRange => {
+                if let Some((selection, cursor)) = self.state.active_selection_mut() {
+                    selection.mark_range(cursor);
+                }
             }
+            Action::ClearSelection => self.clear_selection(),
+            Action::LikeSelected => {} // Handled in handle_action
+            Action::OpenPlaylistPicker => {} // Handled in handle_action
+            Action::AddToPickedPlaylist => {} // Handled in handle_action
+            Action::NewLocalPlaylist => {
+                self.state.name_prompt =
+                    Some(state::NamePromptState::new(state::NamePromptPurpose::NewPlaylist, ""));
+            }
+            Action::SaveQueueAsPlaylist => {
+                if self.state.queue.is_empty() {
+                    self.state.toast = Some(Toast::error("Queue is empty"));
+                } else {
+                    self.state.name_prompt =
+                        Some(state::NamePromptState::new(state::NamePromptPurpose::SaveQueue, ""));
+                }
+            }
+            Action::RenameLocalPlaylist => {} // Handled in handle_action
+            Action::DeleteLocalPlaylist => {} // Handled in handle_action
+            Action::LocalPlaylistMoveUp => {} // Handled in handle_action
+            Action::LocalPlaylistMoveDown => {} // Handled in handle_action
+            Action::LocalPlaylistRemove => {} // Handled in handle_action
+            Action::NamePromptInput(c) => {
+                if let Some(prompt) = &mut self.state.name_prompt {
+                    prompt.input.push(c);
+                }
+            }
+            Action::NamePromptBackspace => {
+                if let Some(prompt) = &mut self.state.name_prompt {
+                    prompt.input.pop();
+                }
+            }
+            Action::NamePromptSubmit => {} // Handled in handle_action
+            Action::NamePromptCancel => self.state.name_prompt = None,
+            Action::ClosePlaylistPicker => self.state.playlist_picker = None,
+            Action::PlaylistPickerUp => {
+                if let Some(picker) = &mut self.state.playlist_picker {
+                    picker.select_prev();
+                }
+            }
+            Action::PlaylistPickerDown => {
+                let len = self.state.playlist_list.len();
+                if let Some(picker) = &mut self.state.playlist_picker {
+                    picker.select_next(len);
+                }
+            }
+            Action::AddAllToQueue => {
+                // Add all tracks from playlist view to queue
+                if let Some(shelves) = self.state.active_shelves()
+                    && shelves.view.is_open()
+                {
+                    let tracks = shelves.view.tracks.clone();
+                    let count = tracks.len();
+                    self.state.queue.add_many(tracks);
+                    self.state.toast = Some(Toast::success(format!("Added {} tracks to queue", count)));
+                } else if self.state.screen == Screen::Library
+                    && self.state.library_tab == LibraryTab::Playlists
+                    && self.state.playlist_view.is_open()
+                {
+                    let tracks = self.state.playlist_view.tracks.clone();
+                    let count = tracks.len();
+                    self.state.queue.add_many(tracks);
+                    self.state.toast = Some(Toast::success(format!("Added {} tracks to queue", count)));
+                }
+            }
+
+            // Library tab actions - all handled in handle_action for async loading
+            Action::LibraryTabNext => {}
+            Action::LibraryTabPrev => {}
+            Action::LoadPlaylists => {}
+            Action::OpenPlaylist(_) => {}
+            Action::ClosePlaylist => {
+                if let Some(shelves) = self.state.active_shelves_mut()
+                    && shelves.view.is_open()
+                {
+                    shelves.view.close();
+                } else if self.state.screen == Screen::Explore {
+                    // Back from a mood category to the category list
+                    self.state.explore.category = None;
+                    self.state.explore.reset_page();
+                } else {
+                    self.state.playlist_view.close();
+                }
+            }
+            Action::ShelfLeft => {
+                if let Some(shelves) = self.state.active_shelves_mut() {
+                    shelves.select_left();
+                }
+            }
+            Action::ShelfRight => {
+                if let Some(shelves) = self.state.active_shelves_mut() {
+                    shelves.select_right();
+                }
+            }
+            Action::ExploreTabNext => {}
+            Action::ExploreTabPrev => {}
+            Action::ExploreNextCountry => {}
 
             Action::TrackEnded => {} // Handled in handle_action
         }
@@ -1082,7 +2048,16 @@ impl App {
             crate::app::events::PlayerEvent::Position { seconds } => {
                 self.state.position_secs = seconds;
             }
-            crate::app::events::PlayerEvent::Duration { seconds } => self.state.duration_secs = seconds,
+            crate::app::events::PlayerEvent::Duration { seconds } => {
+                self.state.duration_secs = seconds;
+                // The resumed track has loaded; jump to where it was left
+                if seconds > 0.0
+                    && let Some(position) = self.state.resume_at.take()
+                    && let Some(mpv) = &self.mpv
+                {
+                    let _ = mpv.seek_absolute(position).await;
+                }
+            }
             crate::app::events::PlayerEvent::Ended => {
                 self.state.position_secs = 0.0;
                 self.state.duration_secs = 0.0;
@@ -1097,12 +2072,15 @@ impl App {
                     }
                 }
 
-                // Only advance the queue if we were playing from it
-                if self.state.playing_from_queue && !self.state.queue.is_empty() {
+                // Only advance the queue if we were playing from it or picked tracks to play next
+                let has_up_next = !self.state.queue.up_next_range().is_empty();
+                if (self.state.playing_from_queue || has_up_next) && !self.state.queue.is_empty() {
                     if let Some(next_track) = self.state.queue.advance() {
                         let track = next_track.clone();
+                        self.state.playing_from_queue = true;
                         self.state.status = format!("Playing next: {}", track.title);
                         self.play_track(track, tx).await;
+                        self.top_up_radio(tx);
                         return;
                     } else if self.state.repeat_mode == RepeatMode::All {
                         // At end of queue with Repeat All - go back to start
@@ -1116,13 +2094,19 @@ impl App {
                     }
                 }
 
+                // Nothing left to play: keep going with a radio
+                if self.state.repeat_mode == RepeatMode::Off && self.cfg.player.autoplay_radio {
+                    self.continue_radio(tx);
+                    return;
+                }
+
                 self.state.status = "Playback ended".into();
             }
             crate::app::events::PlayerEvent::Error(e) => self.state.status = format!("Player error: {e}"),
         }
     }
 
-    async fn handle_network(&mut self, ne: crate::app::events::NetworkEvent, _tx: &mpsc::Sender<Event>) {
+    async fn handle_network(&mut self, ne: crate::app::events::NetworkEvent, tx: &mpsc::Sender<Event>) {
         match ne {
             crate::app::events::NetworkEvent::Error(e) => {
                 // Reset loading state on all lists
@@ -1132,6 +2116,15 @@ impl App {
                 self.state.library_list.loading = false;
                 self.state.playlist_list.loading = false;
                 self.state.playlist_view.loading = false;
+                self.state.home.loading = false;
+                self.state.home.loading_more = false;
+                self.state.home.view.loading = false;
+                self.state.explore.page.loading = false;
+                self.state.explore.page.loading_more = false;
+                self.state.explore.page.view.loading = false;
+                self.state.explore.moods_loading = false;
+                self.state.radio.loading = false;
+                self.state.radio.pending_play = false;
                 self.state.toast = Some(Toast::error(e.clone()));
                 self.state.status = format!("Error: {e} (press r to retry)");
             }
@@ -1175,12 +2168,7 @@ impl App {
                 }
 
                 // Prepend new track to history list for immediate UI update
-                let display = if track.artists.is_empty() {
-                    track.title.clone()
-                } else {
-                    format!("{} - {}", track.title, track.artists.join(", "))
-                };
-                self.state.history_list.items.insert(0, display);
+                self.state.history_list.items.insert(0, track.label());
                 self.state.history_list.tracks.insert(0, track);
                 self.state.history_list.loaded = true;
                 // Update cache sizes to reflect new data
@@ -1198,15 +2186,117 @@ impl App {
                 self.state.playlist_list.set_playlists(playlists);
                 self.state.status = format!("Playlists: {} found", self.state.playlist_list.playlists.len());
             }
-            crate::app::events::NetworkEvent::PlaylistTracksLoaded { _playlist_id: _, tracks } => {
-                self.state.playlist_view.set_tracks(tracks);
-                self.state.status = format!("Playlist: {} tracks", self.state.playlist_view.tracks.len());
+            crate::app::events::NetworkEvent::PlaylistTracksLoaded { playlist_id, tracks } => {
+                let count = tracks.len();
+                // Collections opened from a shelf load into that screen's view
+                let mut opened_from_shelf = false;
+                for view in [&mut self.state.home.view, &mut self.state.explore.page.view] {
+                    if view.playlist.as_ref().is_some_and(|p| p.id == playlist_id) {
+                        view.set_tracks(tracks.clone());
+                        opened_from_shelf = true;
+                    }
+                }
+                if !opened_from_shelf
+                    || self.state.playlist_view.playlist.as_ref().is_some_and(|p| p.id == playlist_id)
+                {
+                    self.state.playlist_view.set_tracks(tracks);
+                }
+                self.state.status = format!("Playlist: {} tracks", count);
+            }
+            crate::app::events::NetworkEvent::HomeLoaded { shelves, continuation } => {
+                self.state.home.set_shelves(shelves);
+                self.state.home.continuation = continuation;
+                self.state.status = format!("Home: {} shelves", self.state.home.shelves.len());
+            }
+            crate::app::events::NetworkEvent::HomeMoreLoaded { shelves, continuation } => {
+                let added = shelves.len();
+                self.state.home.append_shelves(shelves);
+                self.state.home.continuation = continuation;
+                self.state.status = format!("Home: {} shelves (+{})", self.state.home.shelves.len(), added);
+            }
+            crate::app::events::NetworkEvent::ExploreLoaded { key, shelves, continuation } => {
+                // Ignore pages for a tab/country/category that is no longer shown
+                if key == self.state.explore.page_key() {
+                    let page = &mut self.state.explore.page;
+                    page.set_shelves(shelves);
+                    page.continuation = continuation;
+                    self.state.status = format!(
+                        "{}: {} shelves",
+                        self.state.explore.tab.label(),
+                        self.state.explore.page.shelves.len()
+                    );
+                }
+            }
+            crate::app::events::NetworkEvent::ExploreMoreLoaded { key, shelves, continuation } => {
+                if key == self.state.explore.page_key() {
+                    let page = &mut self.state.explore.page;
+                    page.append_shelves(shelves);
+                    page.continuation = continuation;
+                }
+            }
+            crate::app::events::NetworkEvent::ArtLoaded { url, image } => {
+                self.state.art.pending.remove(&url);
+                self.state.art.images.put(url, image);
+            }
+            crate::app::events::NetworkEvent::ArtFailed { url } => {
+                // Art is decoration: leave the placeholder rather than toasting
+                self.state.art.pending.remove(&url);
+                self.state.art.failed.insert(url);
+            }
+            crate::app::events::NetworkEvent::RadioLoaded { page, seed_title } => {
+                // A batch for a station that has since been replaced or stopped
+                if seed_title.is_none() && self.state.radio.playlist_id.as_deref() != Some(page.playlist_id.as_str()) {
+                    return;
+                }
+                let radio = &mut self.state.radio;
+                radio.loading = false;
+                if let Some(title) = seed_title {
+                    radio.playlist_id = Some(page.playlist_id.clone());
+                    radio.seed_title = Some(title);
+                }
+                radio.continuation = page.continuation;
+
+                let batch_len = page.tracks.len();
+                let fresh = radio.filter_new(page.tracks, &self.state.queue);
+                let added = fresh.len();
+                let first_new = self.state.queue.len();
+                self.state.queue.add_many(fresh);
+                self.state.status = format!("Radio: +{} tracks", added);
+
+                if self.state.radio.pending_play {
+                    if added > 0 {
+                        self.state.radio.pending_play = false;
+                        self.state.queue.set_current(first_new);
+                        if let Some(track) = self.state.queue.current_track().cloned() {
+                            self.state.playing_from_queue = true;
+                            self.state.status = format!("Radio: {}", track.title);
+                            self.play_track(track, tx).await;
+                        }
+                    } else if batch_len > 0 && self.state.radio.continuation.is_some() {
+                        // Everything in this batch was played or queued already
+                        self.continue_radio(tx);
+                    } else {
+                        self.state.radio.pending_play = false;
+                        self.state.toast = Some(Toast::error("Radio ran out of tracks"));
+                    }
+                }
+            }
+            crate::app::events::NetworkEvent::MoodsLoaded { categories } => {
+                let explore = &mut self.state.explore;
+                explore.moods = categories;
+                explore.moods_loading = false;
+                explore.moods_loaded = true;
+                explore.select_mood(explore.moods_selected);
+                self.state.status = format!("Moods & genres: {} categories", self.state.explore.moods.len());
             }
             crate::app::events::NetworkEvent::ResolvedStream { track, url } => {
                 self.state.now_playing = Some(track.title.clone());
                 self.state.current_track = Some(track.clone());
                 if let Some(mpv) = &self.mpv {
                     let _ = mpv.set_volume(self.state.volume).await;
+                    if self.state.resume_at.is_some() {
+                        let _ = mpv.set_pause(true).await;
+                    }
                     match mpv.load_url(&url).await {
                         Ok(()) => {
                             self.state.current_url = Some(url);
@@ -1234,24 +2324,104 @@ impl App {
                         .min(self.state.audio_devices.len().saturating_sub(1));
                 }
             }
-            crate::app::events::NetworkEvent::LyricsLoaded { video_id, lyrics } => {
+            crate::app::events::NetworkEvent::LyricsLoaded { video_id, lyrics, offset_ms } => {
                 if self.state.lyrics_video_id.as_deref() == Some(video_id.as_str()) {
                     self.state.lyrics = Some(lyrics);
+                    self.state.lyrics_offset_ms = offset_ms;
                     self.state.lyrics_loading = false;
                 }
             }
+            crate::app::events::NetworkEvent::LyricsCandidatesLoaded { video_id, candidates } => {
+                if let Some(picker) = &mut self.state.lyrics_picker
+                    && picker.video_id == video_id
+                {
+                    picker.candidates = candidates;
+                    picker.loading = false;
+                }
+            }
+            crate::app::events::NetworkEvent::LyricsCandidatesFailed { error } => {
+                if let Some(picker) = &mut self.state.lyrics_picker {
+                    picker.loading = false;
+                }
+                self.state.toast = Some(Toast::error(format!("LRCLIB search failed: {error}")));
+            }
+            crate::app::events::NetworkEvent::LyricsPublished => {
+                self.state.toast = Some(Toast::success("Lyrics published to LRCLIB"));
+            }
+            crate::app::events::NetworkEvent::LyricsPublishFailed { error } => {
+                self.state.toast = Some(Toast::error(format!("LRCLIB publish failed: {error}")));
+            }
+            crate::app::events::NetworkEvent::TracksLiked { what } => {
+                self.state.toast = Some(Toast::success(format!("Liked {what}")));
+            }
+            crate::app::events::NetworkEvent::LikeFailed { error } => {
+                self.state.toast = Some(Toast::error(format!("Like failed: {error}")));
+            }
+            crate::app::events::NetworkEvent::PlaylistItemsAdded { what, playlist } => {
+                self.state.toast = Some(Toast::success(format!("Added {what} to {playlist}")));
+            }
+            crate::app::events::NetworkEvent::PlaylistEditFailed { error } => {
+                self.state.toast = Some(Toast::error(format!("Adding to playlist failed: {error}")));
+            }
+            crate::app::events::NetworkEvent::LocalPlaylistsLoaded { playlists, select, message } => {
+                // Keep an open local playlist's title and count in step
+                if let Some(open) = &mut self.state.playlist_view.playlist
+                    && local_playlist_id(&open.id).is_some()
+                    && let Some(fresh) = playlists.iter().find(|p| p.id == open.id)
+                {
+                    open.title = fresh.title.clone();
+                    open.track_count = fresh.track_count;
+                }
+                self.state.playlist_list.set_local(playlists);
+                // Put the cursor on a new playlist
+                if let Some(id) = select {
+                    let list = &mut self.state.playlist_list;
+                    if let Some(i) = list.local.iter().position(|p| local_playlist_id(&p.id) == Some(id)) {
+                        list.selected = i;
+                    }
+                }
+                if let Some(message) = message {
+                    self.state.toast = Some(Toast::success(message));
+                }
+            }
+            crate::app::events::NetworkEvent::LocalPlaylistFailed { error } => {
+                self.state.toast = Some(Toast::error(error));
+                // An open local playlist may show edits that were not saved
+                if let Some(playlist) = &self.state.playlist_view.playlist
+                    && local_playlist_id(&playlist.id).is_some()
+                {
+                    let playlist_id = playlist.id.clone();
+                    self.spawn_load_playlist_tracks(&playlist_id, tx);
+                }
+            }
             crate::app::events::NetworkEvent::LyricsNotFound { video_id } => {
                 if self.state.lyrics_video_id.as_deref() == Some(video_id.as_str()) {
                     self.state.lyrics = None;
                     self.state.lyrics_loading = false;
                 }
             }
+            crate::app::events::NetworkEvent::AccountLoaded { account } => {
+                let status = &mut self.state.auth_status;
+                status.checking = false;
+                status.error = match account {
+                    Some(_) => None,
+                    None => Some("YouTube did not accept the cookies".into()),
+                };
+                status.account = account;
+            }
+            crate::app::events::NetworkEvent::ChannelsLoaded { channels } => {
+                self.state.auth_status.channels = channels;
+            }
+            crate::app::events::NetworkEvent::AccountCheckFailed { error } => {
+                self.state.auth_status.checking = false;
+                self.state.auth_status.error = Some(error);
+            }
         }
     }
 
     async fn play_track(&mut self, track: crate::ytm::models::Track, tx: &mpsc::Sender<Event>) {
-        self.state.now_playing = Some(track.title.clone());
-        self.state.current_track = Some(track.clone());
+        // Playing something else drops a pending resume
+        self.state.resume_at = None;
 
         // Add to history
         let storage = self.storage_cache_handle();
@@ -1267,13 +2437,21 @@ impl App {
             .await;
         });
 
+        self.load_track(track, tx);
+    }
+
+    /// Fetch lyrics for `track` and hand its stream to mpv, without touching history
+    fn load_track(&mut self, track: crate::ytm::models::Track, tx: &mpsc::Sender<Event>) {
+        self.state.now_playing = Some(track.title.clone());
+        self.state.current_track = Some(track.clone());
+        self.state.radio.played.insert(track.video_id.clone());
+
         // Start lyrics fetch
         self.spawn_lyrics_fetch(track.clone(), tx.clone());
 
         // Resolve and play stream
         let storage = self.storage_cache_handle();
-        let cookies = self.cfg.ytm.cookies.clone();
-        let cookies_from_browser = self.cfg.ytm.cookies_from_browser.clone();
+        let (cookies, cookies_from_browser) = self.cookie_sources();
         let tx2 = tx.clone();
 
         tokio::spawn(async move {
@@ -1342,74 +2520,108 @@ impl App {
 
         self.state.lyrics = None;
         self.state.lyrics_loading = true;
+        self.state.lyrics_offset_ms = 0;
+        self.state.lyrics_sync = None;
+        self.state.lyrics_view.browsing = false;
         self.state.lyrics_video_id = Some(track.video_id.clone());
 
         let storage = self.storage_cache_handle();
+        let providers = self.lyrics_providers.clone();
         let lrclib = self.lrclib.clone();
-        let title = track.title.clone();
-        let artist = track.artists.first().cloned().unwrap_or_default();
-        let album = track.album.clone();
-        let duration = track.duration_seconds;
+        let miss_ttl_secs = self.cfg.lyrics.miss_ttl_hours as i64 * 60 * 60;
         let video_id = track.video_id.clone();
 
         tokio::spawn(async move {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs() as i64;
+            let offset_ms = tokio::task::spawn_blocking({
+                let storage = storage.clone();
+                let vid = video_id.clone();
+                move || storage.get_lyrics_offset(&vid)
+            })
+            .await
+            .ok()
+            .and_then(|r| r.ok())
+            .unwrap_or(0);
+
             // Check cache first
-            if let Ok(Ok(Some((lrc_content, synced)))) = tokio::task::spawn_blocking({
+            if let Ok(Ok(Some((lrc_content, synced, provider)))) = tokio::task::spawn_blocking({
                 let storage = storage.clone();
                 let vid = video_id.clone();
                 move || storage.get_lyrics(&vid)
             })
             .await
             {
-                let lyrics = crate::lyrics::ParsedLyrics::parse(&lrc_content, synced);
+                let mut lyrics = crate::lyrics::ParsedLyrics::parse(&lrc_content, synced);
+                lyrics.source = provider;
                 let _ = tx
                     .send(Event::Network(crate::app::events::NetworkEvent::LyricsLoaded {
                         video_id,
                         lyrics,
+                        offset_ms,
                     }))
                     .await;
                 return;
             }
 
-            // Fetch from LRCLIB
-            match crate::lyrics::fetch_lyrics(
-                &lrclib,
-                &title,
-                &artist,
-                album.as_deref(),
-                duration,
-            )
+            // A pinned LRCLIB entry beats searching
+            if let Ok(Ok(Some(id))) = tokio::task::spawn_blocking({
+                let storage = storage.clone();
+                let vid = video_id.clone();
+                move || storage.get_pinned_lyrics(&vid)
+            })
+            .await
+                && let Ok(Some(found)) = lrclib.get_by_id(id).await
+                && let Some(lyrics) = found.to_lyrics()
+            {
+                if let Some((content, synced)) = found.content() {
+                    let content = content.to_string();
+                    let vid = video_id.clone();
+                    let storage = storage.clone();
+                    let _ = tokio::task::spawn_blocking(move || {
+                        storage.cache_lyrics(&vid, &content, synced, Some("lrclib"), now)
+                    })
+                    .await;
+                }
+                let _ = tx
+                    .send(Event::Network(crate::app::events::NetworkEvent::LyricsLoaded {
+                        video_id,
+                        lyrics,
+                        offset_ms,
+                    }))
+                    .await;
+                return;
+            }
+
+            // Nobody had lyrics last time; don't ask again until the miss expires
+            if let Ok(Ok(true)) = tokio::task::spawn_blocking({
+                let storage = storage.clone();
+                let vid = video_id.clone();
+                move || storage.lyrics_missing_since(&vid, now - miss_ttl_secs)
+            })
             .await
             {
+                let _ = tx
+                    .send(Event::Network(crate::app::events::NetworkEvent::LyricsNotFound {
+                        video_id,
+                    }))
+                    .await;
+                return;
+            }
+
+            // Ask the providers in priority order
+            match crate::lyrics::fetch_lyrics(&providers, &track).await {
                 Ok(Some(lyrics)) => {
-                    // Cache the lyrics
-                    let now = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs() as i64;
-
-                    // Reconstruct LRC content for caching
-                    let lrc_content: String = lyrics
-                        .lines
-                        .iter()
-                        .map(|l| {
-                            if lyrics.synced {
-                                let min = l.time_ms / 60000;
-                                let sec = (l.time_ms % 60000) / 1000;
-                                let ms = (l.time_ms % 1000) / 10;
-                                format!("[{:02}:{:02}.{:02}]{}", min, sec, ms, l.text)
-                            } else {
-                                l.text.clone()
-                            }
-                        })
-                        .collect::<Vec<_>>()
-                        .join("\n");
+                    let lrc_content = lyrics.to_lrc();
 
                     let _ = tokio::task::spawn_blocking({
                         let storage = storage.clone();
                         let vid = video_id.clone();
                         let synced = lyrics.synced;
-                        move || storage.cache_lyrics(&vid, &lrc_content, synced, now)
+                        let provider = lyrics.source.clone();
+                        move || storage.cache_lyrics(&vid, &lrc_content, synced, provider.as_deref(), now)
                     })
                     .await;
 
@@ -1417,16 +2629,24 @@ impl App {
                         .send(Event::Network(crate::app::events::NetworkEvent::LyricsLoaded {
                             video_id,
                             lyrics,
+                            offset_ms,
                         }))
                         .await;
                 }
                 Ok(None) => {
+                    let _ = tokio::task::spawn_blocking({
+                        let storage = storage.clone();
+                        let vid = video_id.clone();
+                        move || storage.mark_lyrics_missing(&vid, now)
+                    })
+                    .await;
                     let _ = tx
                         .send(Event::Network(crate::app::events::NetworkEvent::LyricsNotFound {
                             video_id,
                         }))
                         .await;
                 }
+                // Errors are usually transient, so they aren't remembered as misses
                 Err(_) => {
                     let _ = tx
                         .send(Event::Network(crate::app::events::NetworkEvent::LyricsNotFound {
@@ -1438,17 +2658,190 @@ impl App {
         });
     }
 
-    fn clear_cache(&mut self) {
-        let data_dir = &self.cfg.paths.data_dir;
-        let cache_db = data_dir.join("cache.sqlite3");
+    /// Seek playback to where lyrics line `line` starts
+    async fn seek_to_lyrics_line(&mut self, line: usize) {
+        let Some(lyrics) = &self.state.lyrics else {
+            return;
+        };
+        if !lyrics.synced {
+            self.state.toast = Some(Toast::error("These lyrics aren't synced"));
+            return;
+        }
+        let Some(line) = lyrics.lines.get(line) else {
+            return;
+        };
+
+        // Undo the nudge so the line lands where it is shown
+        let secs = (line.time_ms as i64 + self.state.lyrics_offset_ms).max(0) as f64 / 1000.0;
+        if let Some(mpv) = &self.mpv {
+            let _ = mpv.seek_absolute(secs).await;
+        }
+        self.state.position_secs = secs;
+        self.state.lyrics_view.browsing = false;
+    }
+
+    /// Move the lyrics cursor to the next line matching the search query
+    fn jump_to_lyrics_match(&mut self, forward: bool) {
+        let query = &self.state.lyrics_view.search_query;
+        let Some(lyrics) = &self.state.lyrics else {
+            return;
+        };
+        if query.is_empty() {
+            return;
+        }
+        match lyrics.find_line(query, self.state.lyrics_cursor(), forward) {
+            Some(line) => {
+                self.state.lyrics_view.cursor = line;
+                self.state.lyrics_view.browsing = true;
+            }
+            None => self.state.toast = Some(Toast::error(format!("No lyrics match \"{query}\""))),
+        }
+    }
+
+    /// Search LRCLIB for the playing track and list every result
+    fn open_lyrics_picker(&mut self, tx: &mpsc::Sender<Event>) {
+        let Some(track) = self.state.current_track.clone() else {
+            self.state.toast = Some(Toast::error("Nothing is playing"));
+            return;
+        };
+        self.state.lyrics_picker = Some(crate::app::state::LyricsPickerState::new(
+            track.video_id.clone(),
+            track.label(),
+        ));
+
+        let lrclib = self.lrclib.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let event = match lrclib.candidates(&track).await {
+                Ok(candidates) => crate::app::events::NetworkEvent::LyricsCandidatesLoaded {
+                    video_id: track.video_id,
+                    candidates,
+                },
+                Err(e) => crate::app::events::NetworkEvent::LyricsCandidatesFailed {
+                    error: format!("{e:#}"),
+                },
+            };
+            let _ = tx.send(Event::Network(event)).await;
+        });
+    }
 
-        // Clear database file
-        if cache_db.exists() {
-            let _ = std::fs::remove_file(&cache_db);
+    /// Use the selected LRCLIB result for the track now and on later plays
+    fn pin_lyrics_candidate(&mut self) {
+        let Some(picker) = &self.state.lyrics_picker else {
+            return;
+        };
+        let Some(candidate) = picker.selected_candidate().cloned() else {
+            return;
+        };
+        let Some((content, synced)) = candidate.content() else {
+            self.state.toast = Some(Toast::error("That result has no lyrics"));
+            return;
+        };
+        let content = content.to_string();
+        let video_id = picker.video_id.clone();
+        self.state.lyrics_picker = None;
+
+        if self.state.lyrics_video_id.as_deref() == Some(video_id.as_str()) {
+            self.state.lyrics = candidate.to_lyrics();
+            self.state.lyrics_loading = false;
+            self.state.lyrics_sync = None;
         }
+        self.state.toast = Some(Toast::success(format!(
+            "Pinned lyrics: {} - {}",
+            candidate.track_name, candidate.artist_name
+        )));
+
+        let storage = self.storage_cache_handle();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        tokio::task::spawn_blocking(move || {
+            storage.pin_lyrics(&video_id, candidate.id)?;
+            storage.cache_lyrics(&video_id, &content, synced, Some("lrclib"), now)
+        });
+    }
+
+    /// Turn a finished sync session into the track's synced lyrics, caching them
+    /// and optionally publishing them to LRCLIB
+    fn save_lyrics_sync(&mut self, publish: bool, tx: &mpsc::Sender<Event>) {
+        let Some(session) = self.state.lyrics_sync.take_if(|s| s.is_complete()) else {
+            if self.state.lyrics_sync.is_some() {
+                self.state.toast = Some(Toast::error("Stamp every line before saving"));
+            }
+            return;
+        };
+
+        let lrc_content = session.to_lrc();
+        let source = self.state.lyrics.as_ref().and_then(|l| l.source.clone());
+        let mut lyrics = crate::lyrics::ParsedLyrics::parse(&lrc_content, true);
+        lyrics.source = source.clone();
+        if self.state.lyrics_video_id.as_deref() == Some(session.video_id.as_str()) {
+            self.state.lyrics = Some(lyrics);
+        }
+
+        let storage = self.storage_cache_handle();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        {
+            let video_id = session.video_id.clone();
+            let lrc_content = lrc_content.clone();
+            tokio::task::spawn_blocking(move || {
+                storage.cache_lyrics(&video_id, &lrc_content, true, source.as_deref(), now)
+            });
+        }
+
+        if !publish {
+            self.state.toast = Some(Toast::success("Synced lyrics saved"));
+            return;
+        }
+
+        // LRCLIB rejects submissions without album and duration
+        let track = self
+            .state
+            .current_track
+            .as_ref()
+            .filter(|t| t.video_id == session.video_id);
+        let Some((track, album, duration)) =
+            track.and_then(|t| Some((t, t.album.clone()?, t.duration_seconds?)))
+        else {
+            self.state.toast = Some(Toast::error(
+                "Saved, but LRCLIB needs the track's album and duration to publish",
+            ));
+            return;
+        };
+        // The same clean names lookups use, so the entry can be found again
+        let (track_name, artist_name) = crate::lyrics::lrclib::search_terms(track);
+        let request = crate::lyrics::lrclib::PublishRequest {
+            track_name,
+            artist_name,
+            album_name: album,
+            duration,
+            plain_lyrics: session.plain_text(),
+            synced_lyrics: lrc_content,
+        };
+
+        self.state.toast = Some(Toast::success("Saved; publishing to LRCLIB..."));
+        let lrclib = self.lrclib.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let event = match lrclib.publish(&request).await {
+                Ok(()) => crate::app::events::NetworkEvent::LyricsPublished,
+                Err(e) => crate::app::events::NetworkEvent::LyricsPublishFailed {
+                    error: format!("{e:#}"),
+                },
+            };
+            let _ = tx.send(Event::Network(event)).await;
+        });
+    }
 
-        // Recreate database with schema
-        let _ = Storage::open(&cache_db);
+    fn clear_cache(&mut self) {
+        if let Err(e) = self.storage_cache_handle().open().and_then(|s| s.clear_cache()) {
+            self.state.toast = Some(Toast::error(format!("Clear cache failed: {e}")));
+            return;
+        }
 
         // Clear all in-memory cached state
         self.state.history_list.clear();
@@ -1459,6 +2852,8 @@ impl App {
         // Clear lyrics cache
         self.state.lyrics = None;
         self.state.lyrics_video_id = None;
+        self.state.lyrics_offset_ms = 0;
+        self.state.lyrics_sync = None;
 
         // Update cache sizes to reflect actual disk usage
         self.update_cache_sizes();
@@ -1476,7 +2871,7 @@ impl App {
             .unwrap_or(0);
     }
 
-    fn apply_selected_browser(&mut self) {
+    fn apply_selected_browser(&mut self, tx: &mpsc::Sender<Event>) {
         let browser = self.state.auth_browsers[self.state.auth_selected];
 
         // Update config
@@ -1503,67 +2898,158 @@ impl App {
             return;
         }
 
-        // Recreate YTM client with new auth settings
-        let auth = match self.cfg.ytm.cookies.as_deref() {
-            Some(p) if p.exists() => ytm::auth::load_netscape_cookies(p).ok(),
-            _ => None,
+        let msg = if browser == "none" {
+            "Authentication disabled".to_string()
+        } else {
+            format!("Browser set to: {}", browser)
         };
+        self.rebuild_client(msg, tx);
+    }
 
-        match YtmClient::new(auth) {
-            Ok(client) => {
-                self.ytm = client;
-                if browser == "none" {
-                    self.state.toast = Some(Toast::success("Authentication disabled"));
-                } else {
-                    self.state.toast = Some(Toast::success(format!("Browser set to: {}", browser)));
-                }
+    /// Move to the next account: the default, then named profiles, then channels of the
+    /// signed-in session that have no profile yet (saved as one when picked).
+    fn switch_account(&mut self, tx: &mpsc::Sender<Event>) {
+        let ytm_cfg = &self.cfg.ytm;
+        let mut choices: Vec<Option<crate::config::AuthProfile>> = vec![None];
+        choices.extend(ytm_cfg.profiles.iter().cloned().map(Some));
+
+        // Channels inherit the cookies of the account they were listed for
+        let base = ytm_cfg.active_profile().cloned().unwrap_or_default();
+        for c in &self.state.auth_status.channels {
+            let Some(page_id) = &c.page_id else { continue };
+            let known = ytm_cfg
+                .profiles
+                .iter()
+                .any(|p| p.on_behalf_of_user.as_deref() == Some(page_id.as_str()));
+            if !known {
+                choices.push(Some(crate::config::AuthProfile {
+                    name: c.name.clone(),
+                    on_behalf_of_user: Some(page_id.clone()),
+                    ..base.clone()
+                }));
             }
-            Err(e) => {
-                self.state.toast = Some(Toast::error(format!("Failed to reinitialize: {e}")));
+        }
+
+        if choices.len() < 2 {
+            self.state.toast = Some(Toast::error(
+                "No other accounts; add one with `void auth profile`",
+            ));
+            return;
+        }
+
+        let active = ytm_cfg.active_profile().map(|p| p.name.as_str());
+        let current = choices
+            .iter()
+            .position(|c| c.as_ref().map(|p| p.name.as_str()) == active)
+            .unwrap_or(0);
+        let next = choices.swap_remove((current + 1) % choices.len());
+
+        match next {
+            Some(profile) => {
+                if !self.cfg.ytm.profiles.iter().any(|p| p.name == profile.name) {
+                    self.cfg.ytm.profiles.push(profile.clone());
+                }
+                self.cfg.ytm.active_profile = Some(profile.name);
             }
+            None => self.cfg.ytm.active_profile = None,
         }
-    }
 
-    /// Detect Zen browser profile path and return yt-dlp compatible string.
-    /// Zen is Firefox-based, so we use "firefox:{profile_path}" format.
-    fn detect_zen_profile() -> anyhow::Result<String> {
-        use anyhow::Context;
+        if let Err(e) = crate::config::save(&self.cfg, Some(&self.config_path)) {
+            self.state.toast = Some(Toast::error(format!("Failed to save config: {e}")));
+            return;
+        }
 
-        let base_dirs = directories::BaseDirs::new()
-            .ok_or_else(|| anyhow::anyhow!("BaseDirs unavailable"))?;
+        let msg = format!("Account: {}", self.cfg.ytm.profile_name());
+        self.rebuild_client(msg, tx);
 
-        // Zen stores profiles in platform-specific locations
-        #[cfg(target_os = "macos")]
-        let base = base_dirs
-            .home_dir()
-            .join("Library")
-            .join("Application Support")
-            .join("zen");
+        // Reload personalized pages as the new account
+        self.state.home = state::ShelvesState::new();
+        if self.state.screen == Screen::Home {
+            self.spawn_load_home(tx);
+        }
+        if self.state.screen == Screen::Library {
+            self.spawn_load_library(tx);
+        }
+    }
 
-        #[cfg(target_os = "linux")]
-        let base = base_dirs.home_dir().join(".zen");
+    /// Step the selected Region row (language or country) to its next value
+    fn cycle_region_setting(&mut self, tx: &mpsc::Sender<Event>) {
+        use state::{CHART_COUNTRIES, LANGUAGES};
 
-        #[cfg(target_os = "windows")]
-        let base = base_dirs.data_dir().join("zen");
+        let ytm_cfg = &mut self.cfg.ytm;
+        let msg = if self.state.region_selected == 0 {
+            let next = next_code(LANGUAGES, ytm_cfg.hl.as_deref());
+            ytm_cfg.hl = next.map(|(code, _)| code.to_string());
+            format!("Language: {}", next.map(|(_, name)| *name).unwrap_or("Automatic"))
+        } else {
+            // The "Global" chart entry doubles as "let YouTube decide"
+            let countries = &CHART_COUNTRIES[1..];
+            let next = next_code(countries, ytm_cfg.gl.as_deref());
+            ytm_cfg.gl = next.map(|(code, _)| code.to_string());
+            format!("Country: {}", next.map(|(_, name)| *name).unwrap_or("Automatic"))
+        };
 
-        // Read profiles.ini to find default profile
-        let ini_path = base.join("profiles.ini");
-        let raw = std::fs::read_to_string(&ini_path)
-            .with_context(|| format!("read {}", ini_path.display()))?;
+        if let Err(e) = crate::config::save(&self.cfg, Some(&self.config_path)) {
+            self.state.toast = Some(Toast::error(format!("Failed to save config: {e}")));
+            return;
+        }
+        self.rebuild_client(msg, tx);
+
+        // Localized pages have to be fetched again
+        self.state.home = state::ShelvesState::new();
+        self.state.explore.reset_page();
+        self.state.explore.moods.clear();
+        self.state.explore.moods_loaded = false;
+        self.on_screen_enter(tx);
+    }
 
-        // Find the default profile path
-        let default_rel: String = raw
-            .lines()
-            .find_map(|l: &str| l.strip_prefix("Default="))
-            .map(|s: &str| s.trim().to_string())
-            .ok_or_else(|| anyhow::anyhow!("no Default=... in zen profiles.ini"))?;
+    /// Flip radio autoplay and remember the choice in the config file
+    fn toggle_autoplay_radio(&mut self) {
+        self.cfg.player.autoplay_radio = !self.cfg.player.autoplay_radio;
+        if let Err(e) = crate::config::save(&self.cfg, Some(&self.config_path)) {
+            self.state.toast = Some(Toast::error(format!("Failed to save config: {e}")));
+            return;
+        }
+        let msg = if self.cfg.player.autoplay_radio { "Autoplay radio on" } else { "Autoplay radio off" };
+        self.state.toast = Some(Toast::success(msg));
+    }
 
-        let profile_dir = base.join(&default_rel);
+    /// Recreate the YTM client from the current auth settings
+    fn rebuild_client(&mut self, success_msg: String, tx: &mpsc::Sender<Event>) {
+        let (cookies, browser) = self.cfg.ytm.cookie_sources();
+        let (auth, auth_error) = match ytm::auth::load_auth(cookies, browser) {
+            Ok(auth) => (auth, None),
+            Err(e) => (None, Some(format!("Browser cookies: {e:#}"))),
+        };
 
-        if !profile_dir.exists() {
-            anyhow::bail!("Zen profile not found: {}", profile_dir.display());
+        match YtmClient::new(auth, self.cfg.client_options(&self.overrides)) {
+            Ok(client) => {
+                self.ytm = client;
+                // Library contents depend on who is signed in
+                self.state.library_list = state::ScreenListState::new();
+                self.state.playlist_list = state::PlaylistListState::new();
+                self.reload_local_playlists(tx);
+                self.state.auth_status = AuthStatus::from_auth(self.ytm.auth());
+                if let Some(e) = auth_error {
+                    // yt-dlp may still manage to use the browser for streams
+                    self.state.toast = Some(Toast::error(e.clone()));
+                    self.state.auth_status.error = Some(e);
+                    return;
+                }
+                self.state.toast = Some(Toast::success(success_msg));
+                // May replace the toast with an expiry warning
+                self.check_auth(tx);
+            }
+            Err(e) => {
+                self.state.toast = Some(Toast::error(format!("Failed to reinitialize: {e}")));
+            }
         }
+    }
 
+    /// Detect Zen browser profile path and return yt-dlp compatible string.
+    /// Zen is Firefox-based, so we use "firefox:{profile_path}" format.
+    fn detect_zen_profile() -> anyhow::Result<String> {
+        let profile_dir = ytm::auth::browser::find_profile("zen")?;
         Ok(format!("firefox:{}", profile_dir.display()))
     }
 
@@ -1614,32 +3100,377 @@ impl App {
         }
     }
 
+    /// Track under the cursor on a shelf, playlist or track list
+    fn selected_track(&self) -> Option<crate::ytm::models::Track> {
+        if let Some(shelves) = self.state.active_shelves() {
+            if shelves.view.is_open() {
+                shelves.view.selected_track().cloned()
+            } else {
+                match shelves.selected_item() {
+                    Some(crate::ytm::models::ShelfItem::Track(t)) => Some(t.clone()),
+                    _ => None,
+                }
+            }
+        } else if self.state.screen == Screen::Library
+            && self.state.library_tab == LibraryTab::Playlists
+            && self.state.playlist_view.is_open()
+        {
+            self.state.playlist_view.selected_track().cloned()
+        } else {
+            self.state.active_list().selected_track().cloned()
+        }
+    }
+
+    /// Track on `row` of the track list in front
+    fn track_at_row(&self, row: usize) -> Option<crate::ytm::models::Track> {
+        if self.state.screen == Screen::Queue {
+            self.state.queue.tracks().get(row).cloned()
+        } else if let Some(shelves) = self.state.active_shelves() {
+            shelves.view.tracks.get(row).cloned()
+        } else if self.state.screen == Screen::Library && self.state.library_tab == LibraryTab::Playlists {
+            self.state.playlist_view.tracks.get(row).cloned()
+        } else {
+            self.state.active_list().track_at(row).cloned()
+        }
+    }
+
+    /// Tracks marked in multi-select mode, or the one under the cursor
+    fn selected_tracks(&self) -> Vec<crate::ytm::models::Track> {
+        match self.state.active_selection() {
+            Some((selection, _)) if !selection.is_empty() => selection
+                .rows()
+                .into_iter()
+                .filter_map(|row| self.track_at_row(row))
+                .collect(),
+            Some((_, cursor)) => self.track_at_row(cursor).into_iter().collect(),
+            None => self.selected_track().into_iter().collect(),
+        }
+    }
+
+    /// Leave multi-select mode in the list in front, once a bulk action is done
+    fn clear_selection(&mut self) {
+        if let Some((selection, _)) = self.state.active_selection_mut() {
+            selection.clear();
+        }
+    }
+
+    fn like_selected(&mut self, tx: &mpsc::Sender<Event>) {
+        let tracks = self.selected_tracks();
+        if tracks.is_empty() {
+            return;
+        }
+        self.clear_selection();
+        let what = tracks_label(&tracks);
+        self.state.toast = Some(Toast::success(format!("Liking {what}...")));
+
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let mut result = Ok(());
+            for track in &tracks {
+                result = ytm.rate_track(&track.video_id, crate::ytm::models::LikeStatus::Like).await;
+                if result.is_err() {
+                    break;
+                }
+            }
+            let event = match result {
+                Ok(()) => crate::app::events::NetworkEvent::TracksLiked { what },
+                Err(e) => crate::app::events::NetworkEvent::LikeFailed {
+                    error: format!("{e:#}"),
+                },
+            };
+            let _ = tx.send(Event::Network(event)).await;
+        });
+    }
+
+    /// Add the picker's tracks to the playlist chosen in it
+    fn add_to_picked_playlist(&mut self, tx: &mpsc::Sender<Event>) {
+        let Some(picker) = self.state.playlist_picker.take() else {
+            return;
+        };
+        let Some(playlist) = self.state.playlist_list.get(picker.selected).cloned() else {
+            // Still loading, or nothing to pick
+            self.state.playlist_picker = Some(picker);
+            return;
+        };
+        self.clear_selection();
+        let what = tracks_label(&picker.tracks);
+
+        if let Some(id) = local_playlist_id(&playlist.id) {
+            let view = &mut self.state.playlist_view;
+            if view.playlist.as_ref().is_some_and(|p| p.id == playlist.id) {
+                view.tracks.extend(picker.tracks.iter().cloned());
+            }
+            let message = format!("Added {what} to {}", playlist.title);
+            self.spawn_local_playlist_change(tx, "Adding to playlist failed", move |library| {
+                library.add_local_playlist_tracks(id, &picker.tracks, ytm::auth::unix_now())?;
+                Ok((None, Some(message)))
+            });
+            return;
+        }
+        let video_ids: Vec<String> = picker.tracks.into_iter().map(|t| t.video_id).collect();
+
+        let ytm = self.ytm.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let event = match ytm.add_playlist_items(&playlist.id, &video_ids).await {
+                Ok(()) => crate::app::events::NetworkEvent::PlaylistItemsAdded {
+                    what,
+                    playlist: playlist.title,
+                },
+                Err(e) => crate::app::events::NetworkEvent::PlaylistEditFailed {
+                    error: format!("{e:#}"),
+                },
+            };
+            let _ = tx.send(Event::Network(event)).await;
+        });
+    }
+
+    /// Refresh the local playlists listed in the Library
+    fn reload_local_playlists(&self, tx: &mpsc::Sender<Event>) {
+        self.spawn_local_playlist_change(tx, "Local playlists", |_| Ok((None, None)));
+    }
+
+    /// Queue `change` against the local playlist database off the UI thread, then
+    /// reload the list. It returns a playlist to select and a message to show.
+    fn spawn_local_playlist_change<F>(&self, tx: &mpsc::Sender<Event>, failure: &'static str, change: F)
+    where
+        F: FnOnce(&StorageHandle) -> anyhow::Result<(Option<i64>, Option<String>)> + Send + 'static,
+    {
+        let _ = self.local_playlist_jobs.send(LocalPlaylistJob {
+            library: self.library_handle(),
+            tx: tx.clone(),
+            failure,
+            change: Box::new(change),
+        });
+    }
+
+    /// The local playlist under the cursor in the Library, as (id, title)
+    fn selected_local_playlist(&self) -> Option<(i64, String)> {
+        if self.state.screen != Screen::Library
+            || self.state.library_tab != LibraryTab::Playlists
+            || self.state.playlist_view.is_open()
+        {
+            return None;
+        }
+        let playlist = self.state.playlist_list.selected_playlist()?;
+        Some((local_playlist_id(&playlist.id)?, playlist.title.clone()))
+    }
+
+    fn rename_local_playlist(&mut self) {
+        match self.selected_local_playlist() {
+            Some((id, title)) => {
+                self.state.name_prompt = Some(state::NamePromptState::new(
+                    state::NamePromptPurpose::Rename(id),
+                    title,
+                ));
+            }
+            None => self.state.toast = Some(Toast::error("Only local playlists can be renamed")),
+        }
+    }
+
+    /// Delete the local playlist under the cursor on the second press
+    fn delete_local_playlist(&mut self, tx: &mpsc::Sender<Event>) {
+        let Some((id, title)) = self.selected_local_playlist() else {
+            self.state.toast = Some(Toast::error("Only local playlists can be deleted"));
+            return;
+        };
+        if self.state.playlist_list.confirm_delete != Some(id) {
+            self.state.playlist_list.confirm_delete = Some(id);
+            self.state.toast = Some(Toast::error(format!("Press D again to delete \"{title}\"")));
+            return;
+        }
+        self.state.playlist_list.confirm_delete = None;
+        self.spawn_local_playlist_change(tx, "Delete failed", move |library| {
+            library.delete_local_playlist(id)?;
+            Ok((None, Some(format!("Deleted \"{title}\""))))
+        });
+    }
+
+    /// Create, save the queue as, or rename a local playlist with the typed name
+    fn submit_name_prompt(&mut self, tx: &mpsc::Sender<Event>) {
+        use state::NamePromptPurpose;
+
+        let Some(prompt) = self.state.name_prompt.take() else {
+            return;
+        };
+        let name = prompt.input.trim().to_string();
+        if name.is_empty() {
+            self.state.name_prompt = Some(prompt);
+            self.state.toast = Some(Toast::error("Name can't be empty"));
+            return;
+        }
+
+        let queue = match prompt.purpose {
+            NamePromptPurpose::SaveQueue => self.state.queue.tracks().to_vec(),
+            _ => Vec::new(),
+        };
+        self.spawn_local_playlist_change(tx, "Saving playlist failed", move |library| {
+            let now = ytm::auth::unix_now();
+            match prompt.purpose {
+                NamePromptPurpose::NewPlaylist => {
+                    let id = library.create_local_playlist(&name, &[], now)?;
+                    Ok((Some(id), Some(format!("Created \"{name}\""))))
+                }
+                NamePromptPurpose::SaveQueue => {
+                    let id = library.create_local_playlist(&name, &queue, now)?;
+                    Ok((Some(id), Some(format!("Saved {} to \"{name}\"", tracks_label(&queue)))))
+                }
+                NamePromptPurpose::Rename(id) => {
+                    library.rename_local_playlist(id, &name, now)?;
+                    Ok((None, Some(format!("Renamed to \"{name}\""))))
+                }
+            }
+        });
+    }
+
+    /// Reorder or remove tracks in the open local playlist, then save it
+    fn edit_local_playlist_view(&mut self, action: &Action, tx: &mpsc::Sender<Event>) {
+        let view = &self.state.playlist_view;
+        let Some(id) = view.playlist.as_ref().and_then(|p| local_playlist_id(&p.id)) else {
+            return;
+        };
+        if view.loading || view.tracks.is_empty() {
+            return;
+        }
+
+        let mut tracks = view.tracks.clone();
+        let mut cursor = view.selected.min(tracks.len() - 1);
+        match action {
+            Action::LocalPlaylistMoveUp if cursor > 0 => {
+                tracks.swap(cursor, cursor - 1);
+                cursor -= 1;
+            }
+            Action::LocalPlaylistMoveDown if cursor + 1 < tracks.len() => {
+                tracks.swap(cursor, cursor + 1);
+                cursor += 1;
+            }
+            Action::LocalPlaylistRemove => {
+                let mut rows = view.selection.rows();
+                if rows.is_empty() {
+                    rows.push(cursor);
+                }
+                // Back to front so earlier rows stay put
+                for &row in rows.iter().rev() {
+                    if row < tracks.len() {
+                        tracks.remove(row);
+                    }
+                }
+                cursor = cursor.min(tracks.len().saturating_sub(1));
+            }
+            _ => return,
+        }
+
+        let view = &mut self.state.playlist_view;
+        if let Some(playlist) = &mut view.playlist {
+            playlist.track_count = Some(tracks.len() as u32);
+        }
+        view.set_tracks(tracks.clone());
+        view.selected = cursor;
+        view.update_scroll(20);
+        self.spawn_local_playlist_change(tx, "Saving playlist failed", move |library| {
+            library.set_local_playlist_tracks(id, &tracks, ytm::auth::unix_now())?;
+            Ok((None, None))
+        });
+    }
+
     fn storage_cache_handle(&self) -> StorageHandle {
         StorageHandle {
             path: self.cfg.paths.data_dir.join("cache.sqlite3"),
         }
     }
+
+    /// Local playlists live apart from the cache, so clearing it keeps them
+    fn library_handle(&self) -> StorageHandle {
+        StorageHandle {
+            path: self.cfg.paths.data_dir.join("library.sqlite3"),
+        }
+    }
+}
+
+/// Applies a change, returning a playlist to select and a message to show
+type LocalPlaylistChange = dyn FnOnce(&StorageHandle) -> anyhow::Result<(Option<i64>, Option<String>)> + Send;
+
+/// A change to the local playlist database and where to report the result
+struct LocalPlaylistJob {
+    library: StorageHandle,
+    tx: mpsc::Sender<Event>,
+    failure: &'static str,
+    change: Box<LocalPlaylistChange>,
+}
+
+/// Apply local playlist changes in order, so an older edit can never land
+/// after (or report over) a newer one
+async fn run_local_playlist_jobs(mut jobs: mpsc::UnboundedReceiver<LocalPlaylistJob>) {
+    while let Some(job) = jobs.recv().await {
+        let LocalPlaylistJob { library, tx, failure, change } = job;
+        let result = tokio::task::spawn_blocking(move || {
+            let (select, message) = change(&library)?;
+            let playlists = library.local_playlists()?;
+            anyhow::Ok((playlists, select, message))
+        })
+        .await;
+        let event = match result {
+            Ok(Ok((playlists, select, message))) => crate::app::events::NetworkEvent::LocalPlaylistsLoaded {
+                playlists: playlists.iter().map(|p| p.to_playlist()).collect(),
+                select,
+                message,
+            },
+            Ok(Err(e)) => crate::app::events::NetworkEvent::LocalPlaylistFailed {
+                error: format!("{failure}: {e:#}"),
+            },
+            Err(e) => crate::app::events::NetworkEvent::LocalPlaylistFailed {
+                error: format!("spawn error: {e:#}"),
+            },
+        };
+        let _ = tx.send(Event::Network(event)).await;
+    }
+}
+
+/// "\"Title\"" for one track, "N tracks" for more
+fn tracks_label(tracks: &[crate::ytm::models::Track]) -> String {
+    match tracks {
+        [one] => format!("\"{}\"", one.title),
+        many => format!("{} tracks", many.len()),
+    }
 }
 
 fn sidebar_to_screen(idx: usize) -> Screen {
     match idx {
-        0 => Screen::History,
-        1 => Screen::Search,
-        2 => Screen::Queue,
-        3 => Screen::Library,
-        4 => Screen::Settings,
+        0 => Screen::Home,
+        1 => Screen::Explore,
+        2 => Screen::History,
+        3 => Screen::Search,
+        4 => Screen::Queue,
+        5 => Screen::Library,
+        6 => Screen::Lyrics,
+        7 => Screen::Settings,
         _ => Screen::Help,
     }
 }
 
+/// Entry after `current` in a (code, name) list, wrapping through "unset"
+fn next_code(
+    list: &'static [(&'static str, &'static str)],
+    current: Option<&str>,
+) -> Option<&'static (&'static str, &'static str)> {
+    match current.and_then(|c| list.iter().position(|(code, _)| code.eq_ignore_ascii_case(c))) {
+        Some(i) => list.get(i + 1),
+        None => list.first(),
+    }
+}
+
 fn screen_to_sidebar(screen: Screen) -> usize {
     match screen {
-        Screen::History => 0,
-        Screen::Search => 1,
-        Screen::Queue => 2,
-        Screen::Library => 3,
-        Screen::Settings => 4,
-        Screen::Help => 5,
+        Screen::Home => 0,
+        Screen::Explore => 1,
+        Screen::History => 2,
+        Screen::Search => 3,
+        Screen::Queue => 4,
+        Screen::Library => 5,
+        Screen::Lyrics => 6,
+        Screen::Settings => 7,
+        Screen::Help => 8,
     }
 }
 
@@ -1669,6 +3500,10 @@ impl StorageHandle {
         self.open()?.get_stream_url(video_id, now_unix)
     }
 
+    fn save_playback(&self, saved: &crate::storage::SavedPlayback) -> anyhow::Result<()> {
+        self.open()?.save_playback(saved)
+    }
+
     fn cache_stream_url(
         &self,
         video_id: &str,
@@ -1688,12 +3523,86 @@ impl StorageHandle {
         self.open()?.get_history(limit)
     }
 
-    fn get_lyrics(&self, video_id: &str) -> anyhow::Result<Option<(String, bool)>> {
+    fn get_lyrics(&self, video_id: &str) -> anyhow::Result<Option<(String, bool, Option<String>)>> {
         self.open()?.get_lyrics(video_id)
     }
 
-    fn cache_lyrics(&self, video_id: &str, lrc_content: &str, synced: bool, now_unix: i64) -> anyhow::Result<()> {
-        self.open()?.cache_lyrics(video_id, lrc_content, synced, now_unix)
+    fn get_pinned_lyrics(&self, video_id: &str) -> anyhow::Result<Option<i64>> {
+        self.open()?.get_pinned_lyrics(video_id)
+    }
+
+    fn pin_lyrics(&self, video_id: &str, lrclib_id: i64) -> anyhow::Result<()> {
+        self.open()?.pin_lyrics(video_id, lrclib_id)
+    }
+
+    fn lyrics_missing_since(&self, video_id: &str, min_checked_at: i64) -> anyhow::Result<bool> {
+        self.open()?.lyrics_missing_since(video_id, min_checked_at)
+    }
+
+    fn mark_lyrics_missing(&self, video_id: &str, now_unix: i64) -> anyhow::Result<()> {
+        self.open()?.mark_lyrics_missing(video_id, now_unix)
+    }
+
+    fn get_lyrics_offset(&self, video_id: &str) -> anyhow::Result<i64> {
+        self.open()?.get_lyrics_offset(video_id)
+    }
+
+    fn set_lyrics_offset(&self, video_id: &str, offset_ms: i64) -> anyhow::Result<()> {
+        self.open()?.set_lyrics_offset(video_id, offset_ms)
+    }
+
+    fn local_playlists(&self) -> anyhow::Result<Vec<crate::storage::LocalPlaylist>> {
+        self.open()?.local_playlists()
+    }
+
+    fn local_playlist_tracks(&self, id: i64) -> anyhow::Result<Vec<crate::ytm::models::Track>> {
+        self.open()?.local_playlist_tracks(id)
+    }
+
+    fn create_local_playlist(
+        &self,
+        name: &str,
+        tracks: &[crate::ytm::models::Track],
+        now_unix: i64,
+    ) -> anyhow::Result<i64> {
+        self.open()?.create_local_playlist(name, tracks, now_unix)
+    }
+
+    fn rename_local_playlist(&self, id: i64, name: &str, now_unix: i64) -> anyhow::Result<()> {
+        self.open()?.rename_local_playlist(id, name, now_unix)
+    }
+
+    fn delete_local_playlist(&self, id: i64) -> anyhow::Result<()> {
+        self.open()?.delete_local_playlist(id)
+    }
+
+    fn add_local_playlist_tracks(
+        &self,
+        id: i64,
+        tracks: &[crate::ytm::models::Track],
+        now_unix: i64,
+    ) -> anyhow::Result<()> {
+        self.open()?.add_local_playlist_tracks(id, tracks, now_unix)
+    }
+
+    fn set_local_playlist_tracks(
+        &self,
+        id: i64,
+        tracks: &[crate::ytm::models::Track],
+        now_unix: i64,
+    ) -> anyhow::Result<()> {
+        self.open()?.set_local_playlist_tracks(id, tracks, now_unix)
+    }
+
+    fn cache_lyrics(
+        &self,
+        video_id: &str,
+        lrc_content: &str,
+        synced: bool,
+        provider: Option<&str>,
+        now_unix: i64,
+    ) -> anyhow::Result<()> {
+        self.open()?.cache_lyrics(video_id, lrc_content, synced, provider, now_unix)
     }
 }
 
@@ -1,9 +1,12 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Screen {
+    Home,
+    Explore,
     History,
     Search,
     Queue,
     Library,
+    Lyrics,
     Settings,
     Help,
 }
@@ -43,6 +46,87 @@ impl LibraryTab {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExploreTab {
+    #[default]
+    Explore,
+    NewReleases,
+    Charts,
+    MoodsGenres,
+}
+
+impl ExploreTab {
+    pub const ALL: [ExploreTab; 4] = [
+        ExploreTab::Explore,
+        ExploreTab::NewReleases,
+        ExploreTab::Charts,
+        ExploreTab::MoodsGenres,
+    ];
+
+    pub fn next(self) -> Self {
+        match self {
+            ExploreTab::Explore => ExploreTab::NewReleases,
+            ExploreTab::NewReleases => ExploreTab::Charts,
+            ExploreTab::Charts => ExploreTab::MoodsGenres,
+            ExploreTab::MoodsGenres => ExploreTab::Explore,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            ExploreTab::Explore => ExploreTab::MoodsGenres,
+            ExploreTab::NewReleases => ExploreTab::Explore,
+            ExploreTab::Charts => ExploreTab::NewReleases,
+            ExploreTab::MoodsGenres => ExploreTab::Charts,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExploreTab::Explore => "Explore",
+            ExploreTab::NewReleases => "New releases",
+            ExploreTab::Charts => "Charts",
+            ExploreTab::MoodsGenres => "Moods & genres",
+        }
+    }
+}
+
+/// Countries offered by the Charts tab; `ZZ` is YouTube Music's global chart
+pub const CHART_COUNTRIES: &[(&str, &str)] = &[
+    ("ZZ", "Global"),
+    ("US", "United States"),
+    ("GB", "United Kingdom"),
+    ("DE", "Germany"),
+    ("FR", "France"),
+    ("ES", "Spain"),
+    ("IT", "Italy"),
+    ("BR", "Brazil"),
+    ("MX", "Mexico"),
+    ("CA", "Canada"),
+    ("AU", "Australia"),
+    ("IN", "India"),
+    ("JP", "Japan"),
+    ("KR", "South Korea"),
+];
+
+/// Interface languages offered in Settings, sent as `hl`
+pub const LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("de", "Deutsch"),
+    ("es", "Español"),
+    ("fr", "Français"),
+    ("it", "Italiano"),
+    ("nl", "Nederlands"),
+    ("pt", "Português"),
+    ("pl", "Polski"),
+    ("tr", "Türkçe"),
+    ("ru", "Русский"),
+    ("hi", "हिन्दी"),
+    ("ja", "日本語"),
+    ("ko", "한국어"),
+    ("zh-CN", "中文 (简体)"),
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SearchFocus {
     Input,
@@ -53,6 +137,8 @@ pub enum SearchFocus {
 pub enum SettingsFocus {
     #[default]
     Authentication,
+    Region,
+    Playback,
     AudioDevice,
     Cache,
 }
@@ -74,6 +160,23 @@ impl RepeatMode {
         }
     }
 
+    /// Name stored with the saved queue
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RepeatMode::Off => "off",
+            RepeatMode::One => "one",
+            RepeatMode::All => "all",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Self {
+        match s {
+            "one" => RepeatMode::One,
+            "all" => RepeatMode::All,
+            _ => RepeatMode::Off,
+        }
+    }
+
     pub fn label(self) -> &'static str {
         match self {
             RepeatMode::Off => "Repeat: Off",
@@ -121,22 +224,28 @@ impl Toast {
 impl Screen {
     pub fn next(self) -> Self {
         match self {
+            Screen::Home => Screen::Explore,
+            Screen::Explore => Screen::History,
             Screen::History => Screen::Search,
             Screen::Search => Screen::Queue,
             Screen::Queue => Screen::Library,
-            Screen::Library => Screen::Settings,
+            Screen::Library => Screen::Lyrics,
+            Screen::Lyrics => Screen::Settings,
             Screen::Settings => Screen::Help,
-            Screen::Help => Screen::History,
+            Screen::Help => Screen::Home,
         }
     }
 
     pub fn prev(self) -> Self {
         match self {
-            Screen::History => Screen::Help,
+            Screen::Home => Screen::Help,
+            Screen::Explore => Screen::Home,
+            Screen::History => Screen::Explore,
             Screen::Search => Screen::History,
             Screen::Queue => Screen::Search,
             Screen::Library => Screen::Queue,
-            Screen::Settings => Screen::Library,
+            Screen::Lyrics => Screen::Library,
+            Screen::Settings => Screen::Lyrics,
             Screen::Help => Screen::Settings,
         }
     }
@@ -147,6 +256,77 @@ pub struct AudioDevice {
     pub name: String,
 }
 
+/// Rows marked in multi-select mode, by index into the list that owns it
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    active: bool,
+    marked: std::collections::BTreeSet<usize>,
+    /// Row a range started with `V` extends from
+    anchor: usize,
+}
+
+impl Selection {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn len(&self) -> usize {
+        self.marked.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.marked.is_empty()
+    }
+
+    pub fn contains(&self, row: usize) -> bool {
+        self.marked.contains(&row)
+    }
+
+    /// Marked rows, top to bottom
+    pub fn rows(&self) -> Vec<usize> {
+        self.marked.iter().copied().collect()
+    }
+
+    /// Start selecting with the cursor row marked, or stop and drop the marks
+    pub fn toggle_mode(&mut self, cursor: usize) {
+        if self.active {
+            self.clear();
+        } else {
+            self.active = true;
+            self.marked = [cursor].into();
+            self.anchor = cursor;
+        }
+    }
+
+    /// Mark or unmark one row
+    pub fn toggle(&mut self, row: usize) {
+        self.active = true;
+        if !self.marked.remove(&row) {
+            self.marked.insert(row);
+        }
+        self.anchor = row;
+    }
+
+    /// Mark every row between the anchor and `row`; starts selecting if not yet
+    pub fn mark_range(&mut self, row: usize) {
+        if !self.active {
+            self.toggle_mode(row);
+            return;
+        }
+        self.marked.extend(self.anchor.min(row)..=self.anchor.max(row));
+        self.anchor = row;
+    }
+
+    /// Mark `rows` instead, e.g. after the marked tracks moved
+    pub fn set_rows(&mut self, rows: Vec<usize>) {
+        self.marked = rows.into_iter().collect();
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
 /// Per-screen list state to keep each screen's selection independent
 #[derive(Debug, Clone, Default)]
 pub struct ScreenListState {
@@ -160,6 +340,7 @@ pub struct ScreenListState {
     pub continuation: Option<String>,
     pub has_more: bool,
     pub loading_more: bool,
+    pub selection: Selection,
 }
 
 impl ScreenListState {
@@ -181,20 +362,27 @@ impl ScreenListState {
         self.tracks.get(self.selected)
     }
 
+    /// Track shown on `row`; `None` for rows that are playlists in search results
+    pub fn track_at(&self, row: usize) -> Option<&crate::ytm::models::Track> {
+        use crate::ytm::models::SearchItem;
+        if self.search_items.is_empty() {
+            return self.tracks.get(row);
+        }
+        match self.search_items.get(row) {
+            Some(SearchItem::Track(t)) => Some(t),
+            _ => None,
+        }
+    }
+
     pub fn set_tracks(&mut self, tracks: Vec<crate::ytm::models::Track>) {
         self.items = tracks
             .iter()
-            .map(|t| {
-                if t.artists.is_empty() {
-                    t.title.clone()
-                } else {
-                    format!("{} - {}", t.title, t.artists.join(", "))
-                }
-            })
+            .map(|t| t.label())
             .collect();
         self.tracks = tracks;
         self.search_items.clear();
         self.selected = 0;
+        self.selection.clear();
         self.loaded = true;
         self.loading = false;
     }
@@ -204,13 +392,7 @@ impl ScreenListState {
         self.items = items
             .iter()
             .map(|item| match item {
-                SearchItem::Track(t) => {
-                    if t.artists.is_empty() {
-                        format!("♫ {}", t.title)
-                    } else {
-                        format!("♫ {} - {}", t.title, t.artists.join(", "))
-                    }
-                }
+                SearchItem::Track(t) => format!("♫ {}", t.label()),
                 SearchItem::Playlist(p) => {
                     let count = p.track_count.map(|c| format!(" ({} tracks)", c)).unwrap_or_default();
                     format!("📁 {}{}", p.title, count)
@@ -230,6 +412,7 @@ impl ScreenListState {
             .collect();
         self.search_items = items;
         self.selected = 0;
+        self.selection.clear();
         self.loaded = true;
         self.loading = false;
     }
@@ -238,13 +421,7 @@ impl ScreenListState {
         use crate::ytm::models::SearchItem;
         for item in items {
             let display = match &item {
-                SearchItem::Track(t) => {
-                    if t.artists.is_empty() {
-                        format!("♫ {}", t.title)
-                    } else {
-                        format!("♫ {} - {}", t.title, t.artists.join(", "))
-                    }
-                }
+                SearchItem::Track(t) => format!("♫ {}", t.label()),
                 SearchItem::Playlist(p) => {
                     let count = p.track_count.map(|c| format!(" ({} tracks)", c)).unwrap_or_default();
                     format!("📁 {}{}", p.title, count)
@@ -266,12 +443,7 @@ impl ScreenListState {
     #[allow(dead_code)]
     pub fn append_tracks(&mut self, tracks: Vec<crate::ytm::models::Track>) {
         for t in tracks {
-            let display = if t.artists.is_empty() {
-                t.title.clone()
-            } else {
-                format!("{} - {}", t.title, t.artists.join(", "))
-            };
-            self.items.push(display);
+            self.items.push(t.label());
             self.tracks.push(t);
         }
         self.loading_more = false;
@@ -300,6 +472,7 @@ impl ScreenListState {
         self.items.clear();
         self.tracks.clear();
         self.selected = 0;
+        self.selection.clear();
         self.scroll_offset = 0;
         self.continuation = None;
         self.has_more = false;
@@ -313,6 +486,7 @@ impl ScreenListState {
 pub struct QueueListState {
     pub selected: usize,
     pub scroll_offset: usize,
+    pub selection: Selection,
 }
 
 impl QueueListState {
@@ -345,11 +519,15 @@ impl QueueListState {
 /// State for playlist list in Library
 #[derive(Debug, Clone, Default)]
 pub struct PlaylistListState {
+    /// Playlists kept only in void, listed before the account's
+    pub local: Vec<crate::ytm::models::Playlist>,
     pub playlists: Vec<crate::ytm::models::Playlist>,
     pub selected: usize,
     pub scroll_offset: usize,
     pub loading: bool,
     pub loaded: bool,
+    /// Local playlist waiting for a second delete press
+    pub confirm_delete: Option<i64>,
 }
 
 impl PlaylistListState {
@@ -359,16 +537,40 @@ impl PlaylistListState {
 
     pub fn select_prev(&mut self) {
         self.selected = self.selected.saturating_sub(1);
+        self.confirm_delete = None;
     }
 
     pub fn select_next(&mut self) {
-        if !self.playlists.is_empty() {
-            self.selected = (self.selected + 1).min(self.playlists.len().saturating_sub(1));
+        self.confirm_delete = None;
+        if !self.is_empty() {
+            self.selected = (self.selected + 1).min(self.len() - 1);
+        }
+    }
+
+    /// Local and account playlists together
+    pub fn len(&self) -> usize {
+        self.local.len() + self.playlists.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&crate::ytm::models::Playlist> {
+        match index.checked_sub(self.local.len()) {
+            None => self.local.get(index),
+            Some(i) => self.playlists.get(i),
         }
     }
 
     pub fn selected_playlist(&self) -> Option<&crate::ytm::models::Playlist> {
-        self.playlists.get(self.selected)
+        self.get(self.selected)
+    }
+
+    /// Replace the local playlists, keeping the selection in range
+    pub fn set_local(&mut self, local: Vec<crate::ytm::models::Playlist>) {
+        self.local = local;
+        self.selected = self.selected.min(self.len().saturating_sub(1));
     }
 
     pub fn set_playlists(&mut self, playlists: Vec<crate::ytm::models::Playlist>) {
@@ -390,6 +592,382 @@ impl PlaylistListState {
     }
 }
 
+/// A page of shelves (Home, Explore) with a two-level (shelf, item) selection
+#[derive(Debug, Clone, Default)]
+pub struct ShelvesState {
+    pub shelves: Vec<crate::ytm::models::Shelf>,
+    pub selected_shelf: usize,
+    pub selected_item: usize,
+    pub loading: bool,
+    pub loaded: bool,
+    pub continuation: Option<String>,
+    pub loading_more: bool,
+    /// Album/playlist/artist opened in place from a shelf
+    pub view: PlaylistViewState,
+}
+
+impl ShelvesState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_shelves(&mut self, shelves: Vec<crate::ytm::models::Shelf>) {
+        self.shelves = shelves;
+        self.selected_shelf = 0;
+        self.selected_item = 0;
+        self.loaded = true;
+        self.loading = false;
+    }
+
+    pub fn append_shelves(&mut self, shelves: Vec<crate::ytm::models::Shelf>) {
+        self.shelves.extend(shelves);
+        self.loading_more = false;
+    }
+
+    pub fn selected_shelf(&self) -> Option<&crate::ytm::models::Shelf> {
+        self.shelves.get(self.selected_shelf)
+    }
+
+    pub fn selected_item(&self) -> Option<&crate::ytm::models::ShelfItem> {
+        self.selected_shelf()?.items.get(self.selected_item)
+    }
+
+    fn shelf_len(&self, idx: usize) -> usize {
+        self.shelves.get(idx).map(|s| s.items.len()).unwrap_or(0)
+    }
+
+    fn is_list_shelf(&self, idx: usize) -> bool {
+        self.shelves
+            .get(idx)
+            .is_some_and(|s| s.layout == crate::ytm::models::ShelfLayout::List)
+    }
+
+    /// Move down: through the rows of a list shelf, then on to the next shelf
+    pub fn select_down(&mut self) {
+        if self.is_list_shelf(self.selected_shelf)
+            && self.selected_item + 1 < self.shelf_len(self.selected_shelf)
+        {
+            self.selected_item += 1;
+            return;
+        }
+        if self.selected_shelf + 1 < self.shelves.len() {
+            self.selected_shelf += 1;
+            self.selected_item = if self.is_list_shelf(self.selected_shelf) {
+                0
+            } else {
+                self.selected_item.min(self.shelf_len(self.selected_shelf).saturating_sub(1))
+            };
+        }
+    }
+
+    /// Move up: through the rows of a list shelf, then on to the previous shelf
+    pub fn select_up(&mut self) {
+        if self.is_list_shelf(self.selected_shelf) && self.selected_item > 0 {
+            self.selected_item -= 1;
+            return;
+        }
+        if self.selected_shelf > 0 {
+            self.selected_shelf -= 1;
+            let len = self.shelf_len(self.selected_shelf);
+            self.selected_item = if self.is_list_shelf(self.selected_shelf) {
+                len.saturating_sub(1)
+            } else {
+                self.selected_item.min(len.saturating_sub(1))
+            };
+        }
+    }
+
+    pub fn select_left(&mut self) {
+        if !self.is_list_shelf(self.selected_shelf) {
+            self.selected_item = self.selected_item.saturating_sub(1);
+        }
+    }
+
+    pub fn select_right(&mut self) {
+        if !self.is_list_shelf(self.selected_shelf) {
+            self.selected_item =
+                (self.selected_item + 1).min(self.shelf_len(self.selected_shelf).saturating_sub(1));
+        }
+    }
+
+    pub fn select_first_shelf(&mut self) {
+        self.selected_shelf = 0;
+        self.selected_item = 0;
+    }
+
+    pub fn select_last_shelf(&mut self) {
+        self.selected_shelf = self.shelves.len().saturating_sub(1);
+        self.selected_item = 0;
+    }
+
+    pub fn should_load_more(&self) -> bool {
+        if self.loading_more || self.continuation.is_none() {
+            return false;
+        }
+        self.selected_shelf + 2 >= self.shelves.len()
+    }
+}
+
+/// State for the Explore screen. The tabs share one shelves page, which is
+/// reloaded whenever the tab, chart country or mood category changes.
+#[derive(Debug, Clone, Default)]
+pub struct ExploreState {
+    pub tab: ExploreTab,
+    pub page: ShelvesState,
+    /// Index into `CHART_COUNTRIES`
+    pub country: usize,
+
+    // Moods & genres category list
+    pub moods: Vec<crate::ytm::models::MoodCategory>,
+    pub moods_selected: usize,
+    pub moods_scroll: usize,
+    pub moods_loading: bool,
+    pub moods_loaded: bool,
+    /// Category opened from the list; its playlists are shown in `page`
+    pub category: Option<crate::ytm::models::MoodCategory>,
+}
+
+impl ExploreState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True while the Moods & genres category list (rather than shelves) is shown
+    pub fn showing_moods(&self) -> bool {
+        self.tab == ExploreTab::MoodsGenres && self.category.is_none()
+    }
+
+    pub fn country_code(&self) -> &'static str {
+        CHART_COUNTRIES[self.country % CHART_COUNTRIES.len()].0
+    }
+
+    pub fn country_name(&self) -> &'static str {
+        CHART_COUNTRIES[self.country % CHART_COUNTRIES.len()].1
+    }
+
+    pub fn next_country(&mut self) {
+        self.country = (self.country + 1) % CHART_COUNTRIES.len();
+    }
+
+    /// Identifies what `page` should contain, so stale responses can be dropped
+    pub fn page_key(&self) -> String {
+        match self.tab {
+            ExploreTab::Explore => "explore".to_string(),
+            ExploreTab::NewReleases => "new_releases".to_string(),
+            ExploreTab::Charts => format!("charts:{}", self.country_code()),
+            ExploreTab::MoodsGenres => match &self.category {
+                Some(c) => format!("mood:{}", c.params),
+                None => "moods".to_string(),
+            },
+        }
+    }
+
+    /// Switch tab and drop the shelves of the previous one
+    pub fn set_tab(&mut self, tab: ExploreTab) {
+        self.tab = tab;
+        self.category = None;
+        self.reset_page();
+    }
+
+    pub fn reset_page(&mut self) {
+        self.page = ShelvesState::new();
+    }
+
+    pub fn selected_mood(&self) -> Option<&crate::ytm::models::MoodCategory> {
+        self.moods.get(self.moods_selected)
+    }
+
+    pub fn select_mood(&mut self, idx: usize) {
+        self.moods_selected = idx.min(self.moods.len().saturating_sub(1));
+    }
+
+    pub fn update_moods_scroll(&mut self, visible_height: usize) {
+        if visible_height == 0 {
+            return;
+        }
+        if self.moods_selected < self.moods_scroll {
+            self.moods_scroll = self.moods_selected;
+        } else if self.moods_selected >= self.moods_scroll + visible_height {
+            self.moods_scroll = self.moods_selected - visible_height + 1;
+        }
+    }
+}
+
+/// Radio that keeps the queue topped up once it runs out
+#[derive(Debug, Clone, Default)]
+pub struct RadioState {
+    /// Radio playlist being followed (e.g. `RDAMVM{videoId}`)
+    pub playlist_id: Option<String>,
+    /// What the radio was started from, for display
+    pub seed_title: Option<String>,
+    pub continuation: Option<String>,
+    pub loading: bool,
+    /// Start playing the first new track as soon as the next batch arrives
+    pub pending_play: bool,
+    /// Video ids played this session; radio never re-adds them
+    pub played: std::collections::HashSet<String>,
+}
+
+impl RadioState {
+    pub fn stop(&mut self) {
+        self.playlist_id = None;
+        self.seed_title = None;
+        self.continuation = None;
+        self.pending_play = false;
+    }
+
+    /// Drop tracks that were already played, are already queued, or repeat within the batch
+    pub fn filter_new(
+        &self,
+        tracks: Vec<crate::ytm::models::Track>,
+        queue: &crate::queue::Queue,
+    ) -> Vec<crate::ytm::models::Track> {
+        let mut seen = std::collections::HashSet::new();
+        tracks
+            .into_iter()
+            .filter(|t| {
+                !self.played.contains(&t.video_id)
+                    && !queue.contains(&t.video_id)
+                    && seen.insert(t.video_id.clone())
+            })
+            .collect()
+    }
+}
+
+/// What is known about the current login, shown in Settings
+#[derive(Debug, Clone, Default)]
+pub struct AuthStatus {
+    /// Where cookies come from; `None` when auth is not configured or failed
+    pub source: Option<String>,
+    pub has_sapisid: bool,
+    /// Earliest login cookie expiry (unix seconds)
+    pub expires_utc: Option<i64>,
+    pub account: Option<crate::ytm::models::AccountInfo>,
+    /// Channels of the signed-in session, offered when switching accounts
+    pub channels: Vec<crate::ytm::models::Channel>,
+    /// Account check in flight
+    pub checking: bool,
+    /// Cookies could not be loaded, or the account check failed
+    pub error: Option<String>,
+}
+
+impl AuthStatus {
+    pub fn from_auth(auth: Option<&crate::ytm::auth::AuthState>) -> Self {
+        match auth {
+            Some(a) => Self {
+                source: Some(a.source.to_string()),
+                has_sapisid: a.has_sapisid(),
+                expires_utc: a.login_expiry(),
+                ..Self::default()
+            },
+            None => Self::default(),
+        }
+    }
+
+    /// Login cookies are expired or will be within the warning window
+    pub fn expiring_soon(&self, now: i64) -> bool {
+        self.expires_utc
+            .is_some_and(|t| t - now < crate::ytm::auth::EXPIRY_WARNING_SECS)
+    }
+}
+
+/// Full-screen lyrics: a cursor that follows the song until moved, plus search
+#[derive(Debug, Clone, Default)]
+pub struct LyricsViewState {
+    /// Line under the cursor while browsing
+    pub cursor: usize,
+    /// Set once the user moves the cursor; cleared to follow playback again
+    pub browsing: bool,
+    pub search_query: String,
+    /// Typing into the search bar
+    pub searching: bool,
+    /// Lyrics line shown on each row and the area drawn into, from the last
+    /// render, to map clicks to lines
+    pub rows: Vec<usize>,
+    pub area: ratatui::layout::Rect,
+}
+
+/// LRCLIB search results for a track, to pin the right lyrics by hand
+#[derive(Debug, Clone)]
+pub struct LyricsPickerState {
+    pub video_id: String,
+    pub title: String,
+    /// Best match first; `None` scores are results that look like other songs
+    pub candidates: Vec<(crate::lyrics::lrclib::LrclibResponse, Option<i32>)>,
+    pub selected: usize,
+    pub loading: bool,
+}
+
+impl LyricsPickerState {
+    pub fn new(video_id: String, title: String) -> Self {
+        Self {
+            video_id,
+            title,
+            candidates: Vec::new(),
+            selected: 0,
+            loading: true,
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(self.candidates.len().saturating_sub(1));
+    }
+
+    pub fn selected_candidate(&self) -> Option<&crate::lyrics::lrclib::LrclibResponse> {
+        self.candidates.get(self.selected).map(|(c, _)| c)
+    }
+}
+
+/// Tracks waiting for a playlist to be picked for them
+#[derive(Debug, Clone)]
+pub struct PlaylistPickerState {
+    pub tracks: Vec<crate::ytm::models::Track>,
+    pub selected: usize,
+}
+
+impl PlaylistPickerState {
+    pub fn new(tracks: Vec<crate::ytm::models::Track>) -> Self {
+        Self { tracks, selected: 0 }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self, len: usize) {
+        self.selected = (self.selected + 1).min(len.saturating_sub(1));
+    }
+}
+
+/// What the name typed into the prompt is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamePromptPurpose {
+    NewPlaylist,
+    SaveQueue,
+    /// Rename the local playlist with this id
+    Rename(i64),
+}
+
+/// Name being typed for a local playlist
+#[derive(Debug, Clone)]
+pub struct NamePromptState {
+    pub purpose: NamePromptPurpose,
+    pub input: String,
+}
+
+impl NamePromptState {
+    pub fn new(purpose: NamePromptPurpose, input: impl Into<String>) -> Self {
+        Self {
+            purpose,
+            input: input.into(),
+        }
+    }
+}
+
 /// State when viewing a specific playlist's tracks
 #[derive(Debug, Clone, Default)]
 pub struct PlaylistViewState {
@@ -398,6 +976,7 @@ pub struct PlaylistViewState {
     pub selected: usize,
     pub scroll_offset: usize,
     pub loading: bool,
+    pub selection: Selection,
 }
 
 impl PlaylistViewState {
@@ -413,6 +992,7 @@ impl PlaylistViewState {
         self.playlist = Some(playlist);
         self.tracks.clear();
         self.selected = 0;
+        self.selection.clear();
         self.scroll_offset = 0;
         self.loading = true;
     }
@@ -421,6 +1001,7 @@ impl PlaylistViewState {
         self.playlist = None;
         self.tracks.clear();
         self.selected = 0;
+        self.selection.clear();
         self.scroll_offset = 0;
         self.loading = false;
     }
@@ -428,6 +1009,7 @@ impl PlaylistViewState {
     pub fn set_tracks(&mut self, tracks: Vec<crate::ytm::models::Track>) {
         self.tracks = tracks;
         self.selected = 0;
+        self.selection.clear();
         self.loading = false;
     }
 
@@ -464,6 +1046,12 @@ pub struct AppState {
     pub screen: Screen,
     pub sidebar_selected: usize,
 
+    // Home shelves
+    pub home: ShelvesState,
+
+    // Explore tabs
+    pub explore: ExploreState,
+
     // Independent screen lists
     pub history_list: ScreenListState,
     pub search_list: ScreenListState,
@@ -498,10 +1086,29 @@ pub struct AppState {
     pub lyrics: Option<crate::lyrics::ParsedLyrics>,
     pub lyrics_video_id: Option<String>,
     pub lyrics_loading: bool,
+    /// User nudge for the current track's lyrics in ms; positive shows them later
+    pub lyrics_offset_ms: i64,
+    /// Set while the user is timing plain lyrics line by line
+    pub lyrics_sync: Option<crate::lyrics::SyncSession>,
+    /// Open while choosing among LRCLIB results for the current track
+    pub lyrics_picker: Option<LyricsPickerState>,
+    /// Open while choosing a playlist to add tracks to
+    pub playlist_picker: Option<PlaylistPickerState>,
+    /// Open while naming a local playlist
+    pub name_prompt: Option<NamePromptState>,
+    pub lyrics_view: LyricsViewState,
+    /// Show translation lines under the lyrics they belong to
+    pub lyrics_show_secondary: bool,
+    /// Add a romanized line under kana and hangul lyrics
+    pub lyrics_romanize: bool,
 
     // Settings: authentication
     pub auth_browsers: Vec<&'static str>,
     pub auth_selected: usize,
+    pub auth_status: AuthStatus,
+
+    // Settings: language and region (0 = language, 1 = country)
+    pub region_selected: usize,
 
     // Settings: audio device selection
     pub audio_devices: Vec<AudioDevice>,
@@ -515,6 +1122,12 @@ pub struct AppState {
     // Repeat mode
     pub repeat_mode: RepeatMode,
 
+    // Autoplay radio
+    pub radio: RadioState,
+
+    // Album art
+    pub art: crate::tui::graphics::ArtState,
+
     // Toast notification
     pub toast: Option<Toast>,
 
@@ -523,6 +1136,8 @@ pub struct AppState {
 
     // Track whether current playback is from the queue (vs search/history/library)
     pub playing_from_queue: bool,
+    // Where the track resumed from last session should start, until mpv has loaded it
+    pub resume_at: Option<f64>,
 }
 
 impl Default for AppState {
@@ -537,7 +1152,9 @@ impl AppState {
             should_quit: false,
             tick: 0,
             screen: Screen::History,
-            sidebar_selected: 0,
+            sidebar_selected: 2,
+            home: ShelvesState::new(),
+            explore: ExploreState::new(),
             history_list: ScreenListState::new(),
             search_list: ScreenListState::new(),
             library_list: ScreenListState::new(),
@@ -560,17 +1177,30 @@ impl AppState {
             lyrics: None,
             lyrics_video_id: None,
             lyrics_loading: false,
+            lyrics_offset_ms: 0,
+            lyrics_sync: None,
+            lyrics_picker: None,
+            playlist_picker: None,
+            name_prompt: None,
+            lyrics_view: LyricsViewState::default(),
+            lyrics_show_secondary: true,
+            lyrics_romanize: false,
             auth_browsers: vec!["none", "chrome", "firefox", "brave", "edge", "safari", "chromium", "opera", "zen"],
             auth_selected: 0,
+            auth_status: AuthStatus::default(),
+            region_selected: 0,
             audio_devices: Vec::new(),
             audio_selected: 0,
             audio_loaded: false,
             settings_focus: SettingsFocus::default(),
             cache_size_bytes: 0,
             repeat_mode: RepeatMode::default(),
+            radio: RadioState::default(),
+            art: crate::tui::graphics::ArtState::default(),
             toast: None,
             status: String::new(),
             playing_from_queue: false,
+            resume_at: None,
         }
     }
 
@@ -579,7 +1209,12 @@ impl AppState {
             Screen::History => &self.history_list,
             Screen::Search => &self.search_list,
             Screen::Library => &self.library_list,
-            Screen::Queue | Screen::Settings | Screen::Help => &self.history_list,
+            Screen::Home
+            | Screen::Explore
+            | Screen::Queue
+            | Screen::Lyrics
+            | Screen::Settings
+            | Screen::Help => &self.history_list,
         }
     }
 
@@ -588,7 +1223,107 @@ impl AppState {
             Screen::History => &mut self.history_list,
             Screen::Search => &mut self.search_list,
             Screen::Library => &mut self.library_list,
-            Screen::Queue | Screen::Settings | Screen::Help => &mut self.history_list,
+            Screen::Home
+            | Screen::Explore
+            | Screen::Queue
+            | Screen::Lyrics
+            | Screen::Settings
+            | Screen::Help => &mut self.history_list,
+        }
+    }
+
+    /// Playback position with the user's lyrics nudge applied; a positive nudge holds lyrics back
+    pub fn lyrics_position_ms(&self) -> u64 {
+        ((self.position_secs * 1000.0) as i64 - self.lyrics_offset_ms).max(0) as u64
+    }
+
+    /// Line of the current lyrics being sung now
+    pub fn active_lyrics_line(&self) -> usize {
+        self.lyrics
+            .as_ref()
+            .map_or(0, |l| l.line_at(self.lyrics_position_ms()))
+    }
+
+    /// Line the full-screen lyrics cursor is on, following playback unless browsing
+    pub fn lyrics_cursor(&self) -> usize {
+        if self.lyrics_view.browsing {
+            self.lyrics_view.cursor
+        } else {
+            self.active_lyrics_line()
+        }
+    }
+
+    /// Move the full-screen lyrics cursor by `delta` lines and start browsing
+    pub fn move_lyrics_cursor(&mut self, delta: isize) {
+        let len = self.lyrics.as_ref().map_or(0, |l| l.lines.len());
+        let cursor = self.lyrics_cursor().saturating_add_signed(delta);
+        self.lyrics_view.cursor = cursor.min(len.saturating_sub(1));
+        self.lyrics_view.browsing = true;
+    }
+
+    /// Multi-select state of the track list in front, with its cursor row
+    pub fn active_selection(&self) -> Option<(&Selection, usize)> {
+        match self.screen {
+            Screen::Queue => Some((&self.queue_list.selection, self.queue_list.selected)),
+            Screen::Home | Screen::Explore => self
+                .active_shelves()
+                .filter(|s| s.view.is_open())
+                .map(|s| (&s.view.selection, s.view.selected)),
+            Screen::Library => match self.library_tab {
+                LibraryTab::LikedSongs => {
+                    Some((&self.library_list.selection, self.library_list.selected))
+                }
+                LibraryTab::Playlists if self.playlist_view.is_open() => {
+                    Some((&self.playlist_view.selection, self.playlist_view.selected))
+                }
+                _ => None,
+            },
+            Screen::History | Screen::Search => {
+                let list = self.active_list();
+                Some((&list.selection, list.selected))
+            }
+            Screen::Lyrics | Screen::Settings | Screen::Help => None,
+        }
+    }
+
+    pub fn active_selection_mut(&mut self) -> Option<(&mut Selection, usize)> {
+        match self.screen {
+            Screen::Queue => Some((&mut self.queue_list.selection, self.queue_list.selected)),
+            Screen::Home | Screen::Explore => self
+                .active_shelves_mut()
+                .filter(|s| s.view.is_open())
+                .map(|s| (&mut s.view.selection, s.view.selected)),
+            Screen::Library => match self.library_tab {
+                LibraryTab::LikedSongs => {
+                    Some((&mut self.library_list.selection, self.library_list.selected))
+                }
+                LibraryTab::Playlists if self.playlist_view.is_open() => {
+                    Some((&mut self.playlist_view.selection, self.playlist_view.selected))
+                }
+                _ => None,
+            },
+            Screen::History | Screen::Search => {
+                let list = self.active_list_mut();
+                Some((&mut list.selection, list.selected))
+            }
+            Screen::Lyrics | Screen::Settings | Screen::Help => None,
+        }
+    }
+
+    /// Shelves shown on the current screen, if it is a shelves page
+    pub fn active_shelves(&self) -> Option<&ShelvesState> {
+        match self.screen {
+            Screen::Home => Some(&self.home),
+            Screen::Explore if !self.explore.showing_moods() => Some(&self.explore.page),
+            _ => None,
+        }
+    }
+
+    pub fn active_shelves_mut(&mut self) -> Option<&mut ShelvesState> {
+        match self.screen {
+            Screen::Home => Some(&mut self.home),
+            Screen::Explore if !self.explore.showing_moods() => Some(&mut self.explore.page),
+            _ => None,
         }
     }
 }
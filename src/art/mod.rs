@@ -0,0 +1,150 @@
+//! Album art: downloads thumbnails into a size-capped disk cache and decodes them
+
+use anyhow::Context;
+use image::RgbaImage;
+use sha1::{Digest, Sha1};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// Decoded images are shrunk to fit this box; terminals never show art larger
+const MAX_DECODED_PX: u32 = 320;
+
+/// Smallest thumbnail width worth drawing as album art
+pub const ART_MIN_WIDTH: u32 = 120;
+
+#[derive(Clone)]
+pub struct ArtCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    http: reqwest::Client,
+}
+
+impl ArtCache {
+    pub fn new(dir: PathBuf, max_bytes: u64) -> Self {
+        Self {
+            dir,
+            max_bytes,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Load the image at `url`, from disk when cached
+    pub async fn fetch(&self, url: &str) -> anyhow::Result<Arc<RgbaImage>> {
+        let path = self.path_for(url);
+        let cached = {
+            let path = path.clone();
+            tokio::task::spawn_blocking(move || read_cached(&path))
+                .await
+                .context("read art task")?
+        };
+
+        let image = match cached {
+            Some(bytes) => tokio::task::spawn_blocking(move || decode(&bytes)).await,
+            None => {
+                let bytes = self.download(url).await?;
+                let dir = self.dir.clone();
+                let max_bytes = self.max_bytes;
+                tokio::task::spawn_blocking(move || {
+                    // Only cache what decodes
+                    let image = decode(&bytes)?;
+                    store(&dir, &path, &bytes, max_bytes)?;
+                    Ok(image)
+                })
+                .await
+            }
+        }
+        .context("decode art task")??;
+        Ok(Arc::new(image))
+    }
+
+    fn path_for(&self, url: &str) -> PathBuf {
+        let digest = Sha1::digest(url.as_bytes());
+        self.dir.join(hex::encode(digest))
+    }
+
+    async fn download(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+        let resp = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .context("fetch art")?
+            .error_for_status()
+            .context("fetch art")?;
+        Ok(resp.bytes().await.context("read art")?.to_vec())
+    }
+}
+
+/// Cached bytes for a file, marking it as recently used
+fn read_cached(path: &Path) -> Option<Vec<u8>> {
+    let bytes = std::fs::read(path).ok()?;
+    if let Ok(file) = std::fs::File::options().write(true).open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+    Some(bytes)
+}
+
+fn store(dir: &Path, path: &Path, bytes: &[u8], max_bytes: u64) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("create dir {}", dir.display()))?;
+    std::fs::write(path, bytes).with_context(|| format!("write {}", path.display()))?;
+    prune(dir, max_bytes)
+}
+
+fn decode(bytes: &[u8]) -> anyhow::Result<RgbaImage> {
+    let image = image::load_from_memory(bytes).context("decode art")?;
+    Ok(image.thumbnail(MAX_DECODED_PX, MAX_DECODED_PX).to_rgba8())
+}
+
+/// Delete least recently used files until the directory fits in `max_bytes`
+fn prune(dir: &Path, max_bytes: u64) -> anyhow::Result<()> {
+    let mut files: Vec<(SystemTime, u64, PathBuf)> = std::fs::read_dir(dir)
+        .with_context(|| format!("read dir {}", dir.display()))?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let meta = entry.metadata().ok()?;
+            meta.is_file()
+                .then(|| (meta.modified().unwrap_or(SystemTime::UNIX_EPOCH), meta.len(), entry.path()))
+        })
+        .collect();
+
+    let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+    files.sort_by_key(|(modified, _, _)| *modified);
+    for (_, len, path) in files {
+        if total <= max_bytes {
+            break;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            total -= len;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_prune_evicts_oldest_first() {
+        let dir = std::env::temp_dir().join(format!("void-art-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let base = SystemTime::now() - Duration::from_secs(100);
+        for (i, name) in ["old", "mid", "new"].iter().enumerate() {
+            let path = dir.join(name);
+            std::fs::write(&path, [0u8; 10]).unwrap();
+            let file = std::fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(base + Duration::from_secs(i as u64 * 10)).unwrap();
+        }
+
+        prune(&dir, 20).unwrap();
+        assert!(!dir.join("old").exists());
+        assert!(dir.join("mid").exists());
+        assert!(dir.join("new").exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -14,6 +14,7 @@ pub struct Config {
     pub paths: PathsConfig,
     pub ytm: YtmConfig,
     pub player: PlayerConfig,
+    pub lyrics: LyricsConfig,
     pub ui: UiConfig,
 }
 
@@ -37,12 +38,76 @@ pub struct PathsConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
-#[derive(Default)]
 pub struct YtmConfig {
     /// Path to a Netscape cookie file (yt-dlp compatible).
     pub cookies: Option<PathBuf>,
     /// Use yt-dlp `--cookies-from-browser` (e.g. "chrome", "firefox", "brave").
     pub cookies_from_browser: Option<String>,
+    /// Interface language sent as `hl` (e.g. "en", "ja"); YouTube guesses when unset.
+    pub hl: Option<String>,
+    /// Content region sent as `gl` (e.g. "US", "DE"); YouTube uses IP geolocation when unset.
+    pub gl: Option<String>,
+    /// Give up on a request after this many seconds.
+    pub timeout_secs: u64,
+    /// Retries for rate-limited, failing (5xx) or dropped requests.
+    pub max_retries: u32,
+    /// Upper bound on requests started per second (0 = unlimited).
+    pub requests_per_second: f64,
+    /// Override the YouTube Music host, e.g. a local stand-in server for tests or demos.
+    pub base_url: Option<String>,
+    /// Reuse the scraped API key and client version for this many hours.
+    pub bootstrap_ttl_hours: u64,
+    /// Name of the profile in use; `None` uses the settings above as-is.
+    pub active_profile: Option<String>,
+    /// Named accounts, e.g. a personal account and a brand channel.
+    pub profiles: Vec<AuthProfile>,
+}
+
+/// A named account. Cookie settings left unset are taken from `YtmConfig`,
+/// so brand channels of the same Google login only need `on_behalf_of_user`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthProfile {
+    pub name: String,
+    pub cookies: Option<PathBuf>,
+    pub cookies_from_browser: Option<String>,
+    /// Index of the Google account within a multi-login browser session (`X-Goog-AuthUser`).
+    pub auth_user: Option<u32>,
+    /// Brand account page id to act as (`onBehalfOfUser`).
+    pub on_behalf_of_user: Option<String>,
+}
+
+impl YtmConfig {
+    pub fn active_profile(&self) -> Option<&AuthProfile> {
+        let name = self.active_profile.as_deref()?;
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    /// Cookie file and browser of the active account
+    pub fn cookie_sources(&self) -> (Option<&Path>, Option<&str>) {
+        match self.active_profile() {
+            Some(p) if p.cookies.is_some() || p.cookies_from_browser.is_some() => {
+                (p.cookies.as_deref(), p.cookies_from_browser.as_deref())
+            }
+            _ => (self.cookies.as_deref(), self.cookies_from_browser.as_deref()),
+        }
+    }
+
+    /// Which identity of the signed-in session requests are made as
+    pub fn identity(&self) -> crate::ytm::auth::Identity {
+        match self.active_profile() {
+            Some(p) => crate::ytm::auth::Identity {
+                auth_user: p.auth_user,
+                on_behalf_of_user: p.on_behalf_of_user.clone(),
+            },
+            None => crate::ytm::auth::Identity::default(),
+        }
+    }
+
+    /// Display name of the active account
+    pub fn profile_name(&self) -> &str {
+        self.active_profile().map(|p| p.name.as_str()).unwrap_or("default")
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,14 +117,60 @@ pub struct PlayerConfig {
     pub audio_device: Option<String>,
     /// Volume level (0-100)
     pub volume: u8,
+    /// Keep playing a radio of similar tracks when the queue runs out
+    pub autoplay_radio: bool,
+    /// Load the track playing at quit on the next start, paused where it was left
+    pub resume_on_start: bool,
+    /// Keep tracks by the same artist or from the same album apart when shuffling
+    pub smart_shuffle: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LyricsConfig {
+    /// Providers to try, in order: local, lrclib, ytmusic
+    pub providers: Vec<String>,
+    /// Directory of `.lrc`/`.txt` sidecar files for the local provider
+    pub local_dir: Option<PathBuf>,
+    /// How long to wait before asking the providers again about a track they had no lyrics for
+    pub miss_ttl_hours: u64,
+    /// Show translation lines that share a timestamp with the original
+    pub show_secondary: bool,
+    /// Add a built-in romanization under kana and hangul lines
+    pub romanize: bool,
+}
+
+impl Default for LyricsConfig {
+    fn default() -> Self {
+        Self {
+            providers: crate::lyrics::DEFAULT_PROVIDERS.iter().map(|s| s.to_string()).collect(),
+            local_dir: None,
+            miss_ttl_hours: 72,
+            show_secondary: true,
+            romanize: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
-#[derive(Default)]
 pub struct UiConfig {
     /// Last visited screen (restored on startup)
     pub last_screen: Option<String>,
+    /// Album art protocol: auto, kitty, sixel, iterm2, halfblocks or off
+    pub image_protocol: String,
+    /// Disk space for cached album art, in megabytes
+    pub art_cache_mb: u64,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            last_screen: None,
+            image_protocol: "auto".to_string(),
+            art_cache_mb: 64,
+        }
+    }
 }
 
 impl Default for Config {
@@ -76,15 +187,50 @@ impl Default for Config {
             },
             input: InputConfig { mouse: true },
             paths: PathsConfig { data_dir },
-            ytm: YtmConfig {
-                cookies: None,
-                cookies_from_browser: None,
-            },
+            ytm: YtmConfig::default(),
             player: PlayerConfig {
                 audio_device: None,
                 volume: 80,
+                autoplay_radio: false,
+                resume_on_start: true,
+                smart_shuffle: false,
+            },
+            lyrics: LyricsConfig::default(),
+            ui: UiConfig::default(),
+        }
+    }
+}
+
+/// Request settings given for a single run (e.g. `--gl`). They take precedence
+/// over the config file but live outside `Config`, so saving it never keeps them.
+#[derive(Debug, Clone, Default)]
+pub struct ClientOverrides {
+    pub hl: Option<String>,
+    pub gl: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Request settings for `YtmClient`
+    pub fn client_options(&self, overrides: &ClientOverrides) -> crate::ytm::api::ClientOptions {
+        let ytm = &self.ytm;
+        crate::ytm::api::ClientOptions {
+            identity: ytm.identity(),
+            hl: overrides.hl.clone().or_else(|| ytm.hl.clone()),
+            gl: overrides.gl.clone().or_else(|| ytm.gl.clone()),
+            timeout: std::time::Duration::from_secs(ytm.timeout_secs.max(1)),
+            retry: crate::ytm::http::RetryPolicy {
+                max_retries: ytm.max_retries,
+                ..Default::default()
             },
-            ui: UiConfig { last_screen: None },
+            requests_per_second: ytm.requests_per_second.max(0.0),
+            base_url: overrides
+                .base_url
+                .clone()
+                .or_else(|| ytm.base_url.clone())
+                .unwrap_or_else(|| crate::ytm::api::DEFAULT_BASE_URL.to_string()),
+            bootstrap_cache: Some(self.paths.data_dir.join("cache.sqlite3")),
+            bootstrap_ttl: std::time::Duration::from_secs(ytm.bootstrap_ttl_hours * 60 * 60),
         }
     }
 }
@@ -115,11 +261,32 @@ impl Default for PathsConfig {
 }
 
 
+impl Default for YtmConfig {
+    fn default() -> Self {
+        Self {
+            cookies: None,
+            cookies_from_browser: None,
+            hl: None,
+            gl: None,
+            timeout_secs: 15,
+            max_retries: 3,
+            requests_per_second: 5.0,
+            base_url: None,
+            bootstrap_ttl_hours: 24,
+            active_profile: None,
+            profiles: Vec::new(),
+        }
+    }
+}
+
 impl Default for PlayerConfig {
     fn default() -> Self {
         Self {
             audio_device: None,
             volume: 80,
+            autoplay_radio: false,
+            resume_on_start: true,
+            smart_shuffle: false,
         }
     }
 }
@@ -175,3 +342,43 @@ pub fn load(override_path: Option<&Path>) -> anyhow::Result<Config> {
     Ok(cfg)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles_round_trip() {
+        let mut cfg = Config::default();
+        cfg.ytm.cookies_from_browser = Some("firefox".to_string());
+        cfg.ytm.profiles.push(AuthProfile {
+            name: "brand".to_string(),
+            on_behalf_of_user: Some("1234567890".to_string()),
+            ..AuthProfile::default()
+        });
+        cfg.ytm.profiles.push(AuthProfile {
+            name: "work".to_string(),
+            cookies_from_browser: Some("chrome".to_string()),
+            auth_user: Some(1),
+            ..AuthProfile::default()
+        });
+        cfg.ytm.active_profile = Some("brand".to_string());
+
+        let raw = toml::to_string_pretty(&cfg).unwrap();
+        let cfg: Config = toml::from_str(&raw).unwrap();
+        assert_eq!(cfg.ytm.profiles.len(), 2);
+
+        // Brand channel inherits the browser cookies
+        assert_eq!(cfg.ytm.cookie_sources(), (None, Some("firefox")));
+        assert_eq!(cfg.ytm.identity().on_behalf_of_user.as_deref(), Some("1234567890"));
+
+        let mut cfg = cfg;
+        cfg.ytm.active_profile = Some("work".to_string());
+        assert_eq!(cfg.ytm.cookie_sources(), (None, Some("chrome")));
+        assert_eq!(cfg.ytm.identity().auth_user, Some(1));
+
+        // Unknown profile falls back to the top-level settings
+        cfg.ytm.active_profile = Some("gone".to_string());
+        assert_eq!(cfg.ytm.profile_name(), "default");
+        assert_eq!(cfg.ytm.cookie_sources(), (None, Some("firefox")));
+    }
+}
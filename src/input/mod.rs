@@ -1,8 +1,8 @@
 use crate::app::actions::Action;
 use crate::app::events::{Event, InputEvent};
-use crate::app::state::{AppState, Screen, SearchFocus, SettingsFocus};
+use crate::app::state::{AppState, LibraryTab, Screen, SearchFocus, SettingsFocus};
 use crossterm::event::{
-    self, Event as CtEvent, KeyCode, KeyEventKind, KeyModifiers, MouseEventKind,
+    self, Event as CtEvent, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
 };
 use tokio::sync::mpsc;
 
@@ -45,6 +45,9 @@ pub fn map_input_to_action(state: &AppState, ev: InputEvent) -> Option<Action> {
         InputEvent::Mouse(m) => match m.kind {
             MouseEventKind::ScrollUp => Some(Action::ListUp),
             MouseEventKind::ScrollDown => Some(Action::ListDown),
+            MouseEventKind::Down(MouseButton::Left) if state.screen == Screen::Lyrics => {
+                Some(Action::LyricsClick(m.row))
+            }
             _ => None,
         },
         InputEvent::Key(k) => handle_normal_mode(state, k),
@@ -68,16 +71,53 @@ fn handle_search_results(k: crossterm::event::KeyEvent) -> Option<Action> {
         KeyCode::Left | KeyCode::Char('h') => Some(Action::SidebarUp),
         KeyCode::Right | KeyCode::Char('l') => Some(Action::SidebarDown),
         KeyCode::Char('r') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::Refresh),
+        KeyCode::Char('r') => Some(Action::StartRadio),
         KeyCode::Char(' ') => Some(Action::TogglePause),
         KeyCode::Char('=') | KeyCode::Char('+') => Some(Action::VolumeUp),
         KeyCode::Char('-') | KeyCode::Char('_') => Some(Action::VolumeDown),
         KeyCode::Char(']') => Some(Action::SeekForward),
         KeyCode::Char('[') => Some(Action::SeekBack),
+        KeyCode::Char('}') => Some(Action::NudgeLyrics(crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('{') => Some(Action::NudgeLyrics(-crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('S') => Some(Action::ToggleLyricsSync),
+        KeyCode::Char('L') => Some(Action::OpenLyricsPicker),
+        KeyCode::Char('T') => Some(Action::ToggleLyricsSecondary),
+        KeyCode::Char('Y') => Some(Action::ToggleLyricsRomanize),
         _ => None,
     }
 }
 
 fn handle_normal_mode(state: &AppState, k: crossterm::event::KeyEvent) -> Option<Action> {
+    if state.name_prompt.is_some() {
+        return handle_name_prompt(k);
+    }
+
+    if state.lyrics_picker.is_some()
+        && let Some(action) = handle_lyrics_picker(k)
+    {
+        return Some(action);
+    }
+
+    if state.playlist_picker.is_some()
+        && let Some(action) = handle_playlist_picker(k)
+    {
+        return Some(action);
+    }
+
+    let typing = state.screen == Screen::Search && state.search_focus == SearchFocus::Input;
+    if state.lyrics_sync.is_some()
+        && !typing
+        && let Some(action) = handle_lyrics_sync(k)
+    {
+        return Some(action);
+    }
+
+    if !typing
+        && let Some(action) = handle_selection(state, k)
+    {
+        return Some(action);
+    }
+
     if state.screen == Screen::Search {
         return handle_search_screen_normal(state, k);
     }
@@ -94,6 +134,18 @@ fn handle_normal_mode(state: &AppState, k: crossterm::event::KeyEvent) -> Option
         return handle_library_screen(state, k);
     }
 
+    if state.screen == Screen::Home {
+        return handle_home_screen(state, k);
+    }
+
+    if state.screen == Screen::Explore {
+        return handle_explore_screen(state, k);
+    }
+
+    if state.screen == Screen::Lyrics {
+        return handle_lyrics_screen(state, k);
+    }
+
     match k.code {
         // Quit
         KeyCode::Char('q') => Some(Action::Quit),
@@ -114,12 +166,15 @@ fn handle_normal_mode(state: &AppState, k: crossterm::event::KeyEvent) -> Option
         // Screen switching - Tab cycles through screens
         KeyCode::Tab => Some(Action::NextScreen),
         KeyCode::BackTab => Some(Action::PrevScreen),
-        KeyCode::Char('1') => Some(Action::SetScreen(Screen::History)),
-        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Search)),
-        KeyCode::Char('3') => Some(Action::SetScreen(Screen::Queue)),
-        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Library)),
-        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Settings)),
-        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Help)),
+        KeyCode::Char('1') => Some(Action::SetScreen(Screen::Home)),
+        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Explore)),
+        KeyCode::Char('3') => Some(Action::SetScreen(Screen::History)),
+        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Search)),
+        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Queue)),
+        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Library)),
+        KeyCode::Char('7') => Some(Action::SetScreen(Screen::Lyrics)),
+        KeyCode::Char('8') => Some(Action::SetScreen(Screen::Settings)),
+        KeyCode::Char('9') => Some(Action::SetScreen(Screen::Help)),
 
         // Quick queue access
         KeyCode::Char('Q') => Some(Action::SetScreen(Screen::Queue)),
@@ -134,10 +189,17 @@ fn handle_normal_mode(state: &AppState, k: crossterm::event::KeyEvent) -> Option
         KeyCode::Char('-') | KeyCode::Char('_') => Some(Action::VolumeDown),
         KeyCode::Char(']') => Some(Action::SeekForward),
         KeyCode::Char('[') => Some(Action::SeekBack),
+        KeyCode::Char('}') => Some(Action::NudgeLyrics(crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('{') => Some(Action::NudgeLyrics(-crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('S') => Some(Action::ToggleLyricsSync),
+        KeyCode::Char('L') => Some(Action::OpenLyricsPicker),
+        KeyCode::Char('T') => Some(Action::ToggleLyricsSecondary),
+        KeyCode::Char('Y') => Some(Action::ToggleLyricsRomanize),
 
         // Actions
         KeyCode::Enter => Some(Action::Activate),
         KeyCode::Char('r') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::Refresh),
+        KeyCode::Char('r') => Some(Action::StartRadio),
         KeyCode::Char('R') => Some(Action::ToggleRepeatMode),
         KeyCode::F(5) => Some(Action::Refresh),
         KeyCode::Char('?') | KeyCode::F(1) => Some(Action::SetScreen(Screen::Help)),
@@ -146,6 +208,142 @@ fn handle_normal_mode(state: &AppState, k: crossterm::event::KeyEvent) -> Option
     }
 }
 
+/// The picker covers the main area, so list keys drive it
+fn handle_lyrics_picker(k: crossterm::event::KeyEvent) -> Option<Action> {
+    match k.code {
+        KeyCode::Up | KeyCode::Char('k') => Some(Action::LyricsPickerUp),
+        KeyCode::Down | KeyCode::Char('j') => Some(Action::LyricsPickerDown),
+        KeyCode::Enter => Some(Action::PinLyricsCandidate),
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('L') => Some(Action::CloseLyricsPicker),
+        _ => None,
+    }
+}
+
+/// The picker covers the main area, so list keys drive it
+fn handle_playlist_picker(k: crossterm::event::KeyEvent) -> Option<Action> {
+    match k.code {
+        KeyCode::Up | KeyCode::Char('k') => Some(Action::PlaylistPickerUp),
+        KeyCode::Down | KeyCode::Char('j') => Some(Action::PlaylistPickerDown),
+        KeyCode::Enter => Some(Action::AddToPickedPlaylist),
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('P') => Some(Action::ClosePlaylistPicker),
+        _ => None,
+    }
+}
+
+/// Typing a playlist name takes every key until it is saved or cancelled
+fn handle_name_prompt(k: crossterm::event::KeyEvent) -> Option<Action> {
+    match k.code {
+        KeyCode::Enter => Some(Action::NamePromptSubmit),
+        KeyCode::Esc => Some(Action::NamePromptCancel),
+        KeyCode::Backspace => Some(Action::NamePromptBackspace),
+        KeyCode::Char(c) => Some(Action::NamePromptInput(c)),
+        _ => None,
+    }
+}
+
+/// Multi-select and bulk actions in track lists; while selecting, Space marks
+/// rows instead of pausing and Esc stops selecting instead of quitting
+fn handle_selection(state: &AppState, k: crossterm::event::KeyEvent) -> Option<Action> {
+    let (selection, _) = state.active_selection()?;
+    let in_queue = state.screen == Screen::Queue;
+    match k.code {
+        KeyCode::Char('v') => Some(Action::ToggleSelectMode),
+        KeyCode::Char('V') => Some(Action::SelectRange),
+        KeyCode::Char(' ') if selection.is_active() => Some(Action::ToggleSelected),
+        KeyCode::Esc if selection.is_active() => Some(Action::ClearSelection),
+        KeyCode::Char('a') if !in_queue => Some(Action::AddSelectedToQueue),
+        KeyCode::Char('e') if !in_queue => Some(Action::PlaySelectedNext),
+        KeyCode::Char('f') => Some(Action::LikeSelected),
+        KeyCode::Char('P') => Some(Action::OpenPlaylistPicker),
+        _ => None,
+    }
+}
+
+/// Sync mode keys take priority; anything else (pause, seek) behaves as usual
+fn handle_lyrics_sync(k: crossterm::event::KeyEvent) -> Option<Action> {
+    match k.code {
+        KeyCode::Enter => Some(Action::SyncStamp),
+        KeyCode::Backspace => Some(Action::SyncUndo),
+        KeyCode::Char('}') => Some(Action::SyncAdjust(crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('{') => Some(Action::SyncAdjust(-crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('w') => Some(Action::SaveLyricsSync),
+        KeyCode::Char('P') => Some(Action::PublishLyricsSync),
+        KeyCode::Esc | KeyCode::Char('S') => Some(Action::ToggleLyricsSync),
+        _ => None,
+    }
+}
+
+fn handle_lyrics_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Option<Action> {
+    let view = &state.lyrics_view;
+    if view.searching {
+        return match k.code {
+            KeyCode::Esc => Some(Action::LyricsSearchCancel),
+            KeyCode::Enter => Some(Action::LyricsSearchSubmit),
+            KeyCode::Backspace => Some(Action::LyricsSearchBackspace),
+            KeyCode::Char(c) => Some(Action::LyricsSearchInput(c)),
+            _ => None,
+        };
+    }
+
+    match k.code {
+        // Quit, or stop browsing first
+        KeyCode::Char('q') => Some(Action::Quit),
+        KeyCode::Esc if view.browsing || !view.search_query.is_empty() => Some(Action::FollowLyrics),
+        KeyCode::Esc => Some(Action::Quit),
+
+        // Cursor
+        KeyCode::Up | KeyCode::Char('k') => Some(Action::ListUp),
+        KeyCode::Down | KeyCode::Char('j') => Some(Action::ListDown),
+        KeyCode::Char('g') => Some(Action::GoTop),
+        KeyCode::Char('G') => Some(Action::GoBottom),
+        KeyCode::Char('d') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::PageDown),
+        KeyCode::Char('u') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::PageUp),
+        KeyCode::Char('f') => Some(Action::FollowLyrics),
+        KeyCode::Enter => Some(Action::SeekToLyricsLine),
+
+        // Search within the lyrics; n/N only step through matches once there is a query
+        KeyCode::Char('/') => Some(Action::StartLyricsSearch),
+        KeyCode::Char('n') if !view.search_query.is_empty() => Some(Action::LyricsSearchNext),
+        KeyCode::Char('N') if !view.search_query.is_empty() => Some(Action::LyricsSearchPrev),
+
+        // Sidebar navigation
+        KeyCode::Left | KeyCode::Char('h') => Some(Action::SidebarUp),
+        KeyCode::Right | KeyCode::Char('l') => Some(Action::SidebarDown),
+
+        // Screen switching
+        KeyCode::Tab => Some(Action::NextScreen),
+        KeyCode::BackTab => Some(Action::PrevScreen),
+        KeyCode::Char('1') => Some(Action::SetScreen(Screen::Home)),
+        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Explore)),
+        KeyCode::Char('3') => Some(Action::SetScreen(Screen::History)),
+        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Search)),
+        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Queue)),
+        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Library)),
+        KeyCode::Char('8') => Some(Action::SetScreen(Screen::Settings)),
+        KeyCode::Char('9') => Some(Action::SetScreen(Screen::Help)),
+
+        // Playback
+        KeyCode::Char(' ') => Some(Action::TogglePause),
+        KeyCode::Char('=') | KeyCode::Char('+') => Some(Action::VolumeUp),
+        KeyCode::Char('-') | KeyCode::Char('_') => Some(Action::VolumeDown),
+        KeyCode::Char(']') => Some(Action::SeekForward),
+        KeyCode::Char('[') => Some(Action::SeekBack),
+        KeyCode::Char('}') => Some(Action::NudgeLyrics(crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('{') => Some(Action::NudgeLyrics(-crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('S') => Some(Action::ToggleLyricsSync),
+        KeyCode::Char('L') => Some(Action::OpenLyricsPicker),
+        KeyCode::Char('T') => Some(Action::ToggleLyricsSecondary),
+        KeyCode::Char('Y') => Some(Action::ToggleLyricsRomanize),
+        KeyCode::Char('R') => Some(Action::ToggleRepeatMode),
+        KeyCode::Char('n') => Some(Action::PlayNext),
+        KeyCode::Char('p') => Some(Action::PlayPrev),
+        KeyCode::Char('Q') => Some(Action::SetScreen(Screen::Queue)),
+        KeyCode::Char('?') | KeyCode::F(1) => Some(Action::SetScreen(Screen::Help)),
+
+        _ => None,
+    }
+}
+
 fn handle_settings_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Option<Action> {
     match k.code {
         KeyCode::Char('q') => Some(Action::Quit),
@@ -164,11 +362,14 @@ fn handle_settings_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Op
         KeyCode::Right | KeyCode::Char('l') => Some(Action::SidebarDown),
 
         // Direct screen switching
-        KeyCode::Char('1') => Some(Action::SetScreen(Screen::History)),
-        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Search)),
-        KeyCode::Char('3') => Some(Action::SetScreen(Screen::Queue)),
-        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Library)),
-        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Help)),
+        KeyCode::Char('1') => Some(Action::SetScreen(Screen::Home)),
+        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Explore)),
+        KeyCode::Char('3') => Some(Action::SetScreen(Screen::History)),
+        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Search)),
+        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Queue)),
+        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Library)),
+        KeyCode::Char('7') => Some(Action::SetScreen(Screen::Lyrics)),
+        KeyCode::Char('9') => Some(Action::SetScreen(Screen::Help)),
 
         // Playback
         KeyCode::Char(' ') => Some(Action::TogglePause),
@@ -180,10 +381,17 @@ fn handle_settings_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Op
             Some(Action::ClearCache)
         }
 
+        // Cycle accounts when on auth section
+        KeyCode::Char('a') if state.settings_focus == SettingsFocus::Authentication => {
+            Some(Action::SwitchAccount)
+        }
+
         // Apply selection
         KeyCode::Enter => {
             match state.settings_focus {
                 SettingsFocus::Authentication => Some(Action::ApplySelectedBrowser),
+                SettingsFocus::Region => Some(Action::CycleRegionSetting),
+                SettingsFocus::Playback => Some(Action::ToggleAutoplayRadio),
                 SettingsFocus::AudioDevice => Some(Action::ApplySelectedAudioDevice),
                 SettingsFocus::Cache => Some(Action::ClearCache),
             }
@@ -224,9 +432,19 @@ fn handle_search_screen_normal(state: &AppState, k: crossterm::event::KeyEvent)
 fn handle_library_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Option<Action> {
     // If playlist view is open, handle navigation within it
     if state.playlist_view.is_open() {
+        let local = state
+            .playlist_view
+            .playlist
+            .as_ref()
+            .is_some_and(|p| crate::storage::local_playlist_id(&p.id).is_some());
+        if local && let Some(action) = handle_local_playlist_view(k) {
+            return Some(action);
+        }
         return handle_playlist_view(k);
     }
 
+    let on_playlists = state.library_tab == LibraryTab::Playlists;
+
     match k.code {
         // Quit
         KeyCode::Char('q') => Some(Action::Quit),
@@ -249,11 +467,14 @@ fn handle_library_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Opt
         KeyCode::Right | KeyCode::Char('l') => Some(Action::SidebarDown),
 
         // Screen switching
-        KeyCode::Char('1') => Some(Action::SetScreen(Screen::History)),
-        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Search)),
-        KeyCode::Char('3') => Some(Action::SetScreen(Screen::Queue)),
-        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Settings)),
-        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Help)),
+        KeyCode::Char('1') => Some(Action::SetScreen(Screen::Home)),
+        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Explore)),
+        KeyCode::Char('3') => Some(Action::SetScreen(Screen::History)),
+        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Search)),
+        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Queue)),
+        KeyCode::Char('7') => Some(Action::SetScreen(Screen::Lyrics)),
+        KeyCode::Char('8') => Some(Action::SetScreen(Screen::Settings)),
+        KeyCode::Char('9') => Some(Action::SetScreen(Screen::Help)),
 
         // Playback
         KeyCode::Char(' ') => Some(Action::TogglePause),
@@ -261,14 +482,160 @@ fn handle_library_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Opt
         KeyCode::Char('-') | KeyCode::Char('_') => Some(Action::VolumeDown),
         KeyCode::Char(']') => Some(Action::SeekForward),
         KeyCode::Char('[') => Some(Action::SeekBack),
+        KeyCode::Char('}') => Some(Action::NudgeLyrics(crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('{') => Some(Action::NudgeLyrics(-crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('S') => Some(Action::ToggleLyricsSync),
+        KeyCode::Char('L') => Some(Action::OpenLyricsPicker),
+        KeyCode::Char('T') => Some(Action::ToggleLyricsSecondary),
+        KeyCode::Char('Y') => Some(Action::ToggleLyricsRomanize),
         KeyCode::Char('R') => Some(Action::ToggleRepeatMode),
         KeyCode::Char('n') => Some(Action::PlayNext),
         KeyCode::Char('p') => Some(Action::PlayPrev),
         KeyCode::Char('Q') => Some(Action::SetScreen(Screen::Queue)),
 
+        // Local playlists
+        KeyCode::Char('N') if on_playlists => Some(Action::NewLocalPlaylist),
+        KeyCode::Char('E') if on_playlists => Some(Action::RenameLocalPlaylist),
+        KeyCode::Char('D') if on_playlists => Some(Action::DeleteLocalPlaylist),
+
         // Actions
         KeyCode::Enter => Some(Action::Activate),
         KeyCode::Char('r') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::Refresh),
+        KeyCode::Char('r') => Some(Action::StartRadio),
+        KeyCode::F(5) => Some(Action::Refresh),
+        KeyCode::Char('?') | KeyCode::F(1) => Some(Action::SetScreen(Screen::Help)),
+
+        _ => None,
+    }
+}
+
+fn handle_home_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Option<Action> {
+    // An album/playlist opened from a shelf behaves like the Library playlist view
+    if state.home.view.is_open() {
+        return handle_playlist_view(k);
+    }
+
+    match k.code {
+        // Quit
+        KeyCode::Char('q') => Some(Action::Quit),
+        KeyCode::Esc => Some(Action::Quit),
+
+        // Navigation: j/k across shelves (and list rows), h/l within a card shelf
+        KeyCode::Up | KeyCode::Char('k') => Some(Action::ListUp),
+        KeyCode::Down | KeyCode::Char('j') => Some(Action::ListDown),
+        KeyCode::Left | KeyCode::Char('h') => Some(Action::ShelfLeft),
+        KeyCode::Right | KeyCode::Char('l') => Some(Action::ShelfRight),
+        KeyCode::Char('g') => Some(Action::GoTop),
+        KeyCode::Char('G') => Some(Action::GoBottom),
+
+        // Screen switching
+        KeyCode::Tab => Some(Action::NextScreen),
+        KeyCode::BackTab => Some(Action::PrevScreen),
+        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Explore)),
+        KeyCode::Char('3') => Some(Action::SetScreen(Screen::History)),
+        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Search)),
+        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Queue)),
+        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Library)),
+        KeyCode::Char('7') => Some(Action::SetScreen(Screen::Lyrics)),
+        KeyCode::Char('8') => Some(Action::SetScreen(Screen::Settings)),
+        KeyCode::Char('9') => Some(Action::SetScreen(Screen::Help)),
+        KeyCode::Char('Q') => Some(Action::SetScreen(Screen::Queue)),
+
+        // Playback
+        KeyCode::Char(' ') => Some(Action::TogglePause),
+        KeyCode::Char('=') | KeyCode::Char('+') => Some(Action::VolumeUp),
+        KeyCode::Char('-') | KeyCode::Char('_') => Some(Action::VolumeDown),
+        KeyCode::Char(']') => Some(Action::SeekForward),
+        KeyCode::Char('[') => Some(Action::SeekBack),
+        KeyCode::Char('}') => Some(Action::NudgeLyrics(crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('{') => Some(Action::NudgeLyrics(-crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('S') => Some(Action::ToggleLyricsSync),
+        KeyCode::Char('L') => Some(Action::OpenLyricsPicker),
+        KeyCode::Char('T') => Some(Action::ToggleLyricsSecondary),
+        KeyCode::Char('Y') => Some(Action::ToggleLyricsRomanize),
+        KeyCode::Char('R') => Some(Action::ToggleRepeatMode),
+        KeyCode::Char('n') => Some(Action::PlayNext),
+        KeyCode::Char('p') => Some(Action::PlayPrev),
+
+        // Actions
+        KeyCode::Enter => Some(Action::Activate),
+        KeyCode::Char('a') => Some(Action::AddSelectedToQueue),
+        KeyCode::Char('e') => Some(Action::PlaySelectedNext),
+        KeyCode::Char('r') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::Refresh),
+        KeyCode::Char('r') => Some(Action::StartRadio),
+        KeyCode::F(5) => Some(Action::Refresh),
+        KeyCode::Char('?') | KeyCode::F(1) => Some(Action::SetScreen(Screen::Help)),
+
+        _ => None,
+    }
+}
+
+fn handle_explore_screen(state: &AppState, k: crossterm::event::KeyEvent) -> Option<Action> {
+    // An album/playlist opened from a shelf behaves like the Library playlist view
+    if state.explore.page.view.is_open() {
+        return handle_playlist_view(k);
+    }
+
+    let on_shelves = !state.explore.showing_moods();
+
+    match k.code {
+        // Quit, or leave an opened mood category
+        KeyCode::Char('q') => Some(Action::Quit),
+        KeyCode::Esc | KeyCode::Backspace if state.explore.category.is_some() => {
+            Some(Action::ClosePlaylist)
+        }
+        KeyCode::Esc => Some(Action::Quit),
+
+        // Tab switching within Explore
+        KeyCode::Tab => Some(Action::ExploreTabNext),
+        KeyCode::BackTab => Some(Action::ExploreTabPrev),
+
+        // Navigation: j/k across shelves (or the category list), h/l within a card shelf
+        KeyCode::Up | KeyCode::Char('k') => Some(Action::ListUp),
+        KeyCode::Down | KeyCode::Char('j') => Some(Action::ListDown),
+        KeyCode::Left | KeyCode::Char('h') if on_shelves => Some(Action::ShelfLeft),
+        KeyCode::Right | KeyCode::Char('l') if on_shelves => Some(Action::ShelfRight),
+        KeyCode::Left | KeyCode::Char('h') => Some(Action::SidebarUp),
+        KeyCode::Right | KeyCode::Char('l') => Some(Action::SidebarDown),
+        KeyCode::Char('g') => Some(Action::GoTop),
+        KeyCode::Char('G') => Some(Action::GoBottom),
+        KeyCode::Char('d') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::PageDown),
+        KeyCode::Char('u') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::PageUp),
+
+        // Screen switching
+        KeyCode::Char('1') => Some(Action::SetScreen(Screen::Home)),
+        KeyCode::Char('3') => Some(Action::SetScreen(Screen::History)),
+        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Search)),
+        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Queue)),
+        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Library)),
+        KeyCode::Char('7') => Some(Action::SetScreen(Screen::Lyrics)),
+        KeyCode::Char('8') => Some(Action::SetScreen(Screen::Settings)),
+        KeyCode::Char('9') => Some(Action::SetScreen(Screen::Help)),
+        KeyCode::Char('Q') => Some(Action::SetScreen(Screen::Queue)),
+
+        // Playback
+        KeyCode::Char(' ') => Some(Action::TogglePause),
+        KeyCode::Char('=') | KeyCode::Char('+') => Some(Action::VolumeUp),
+        KeyCode::Char('-') | KeyCode::Char('_') => Some(Action::VolumeDown),
+        KeyCode::Char(']') => Some(Action::SeekForward),
+        KeyCode::Char('[') => Some(Action::SeekBack),
+        KeyCode::Char('}') => Some(Action::NudgeLyrics(crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('{') => Some(Action::NudgeLyrics(-crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('S') => Some(Action::ToggleLyricsSync),
+        KeyCode::Char('L') => Some(Action::OpenLyricsPicker),
+        KeyCode::Char('T') => Some(Action::ToggleLyricsSecondary),
+        KeyCode::Char('Y') => Some(Action::ToggleLyricsRomanize),
+        KeyCode::Char('R') => Some(Action::ToggleRepeatMode),
+        KeyCode::Char('n') => Some(Action::PlayNext),
+        KeyCode::Char('p') => Some(Action::PlayPrev),
+
+        // Actions
+        KeyCode::Enter => Some(Action::Activate),
+        KeyCode::Char('a') if on_shelves => Some(Action::AddSelectedToQueue),
+        KeyCode::Char('e') if on_shelves => Some(Action::PlaySelectedNext),
+        KeyCode::Char('c') => Some(Action::ExploreNextCountry),
+        KeyCode::Char('r') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::Refresh),
+        KeyCode::Char('r') => Some(Action::StartRadio),
         KeyCode::F(5) => Some(Action::Refresh),
         KeyCode::Char('?') | KeyCode::F(1) => Some(Action::SetScreen(Screen::Help)),
 
@@ -301,7 +668,9 @@ fn handle_playlist_view(k: crossterm::event::KeyEvent) -> Option<Action> {
 
         // Add to queue
         KeyCode::Char('a') => Some(Action::AddSelectedToQueue),
+        KeyCode::Char('e') => Some(Action::PlaySelectedNext),
         KeyCode::Char('A') => Some(Action::AddAllToQueue),
+        KeyCode::Char('r') => Some(Action::StartRadio),
 
         // Quick quit
         KeyCode::Char('q') => Some(Action::Quit),
@@ -310,6 +679,19 @@ fn handle_playlist_view(k: crossterm::event::KeyEvent) -> Option<Action> {
     }
 }
 
+/// Editing keys for an opened local playlist; the rest are as in any playlist view
+fn handle_local_playlist_view(k: crossterm::event::KeyEvent) -> Option<Action> {
+    match k.code {
+        KeyCode::Char('K') => Some(Action::LocalPlaylistMoveUp),
+        KeyCode::Char('J') => Some(Action::LocalPlaylistMoveDown),
+        KeyCode::Char('d') if !k.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(Action::LocalPlaylistRemove)
+        }
+        KeyCode::Delete => Some(Action::LocalPlaylistRemove),
+        _ => None,
+    }
+}
+
 fn handle_queue_screen(k: crossterm::event::KeyEvent) -> Option<Action> {
     match k.code {
         // Quit
@@ -331,11 +713,14 @@ fn handle_queue_screen(k: crossterm::event::KeyEvent) -> Option<Action> {
         // Screen switching
         KeyCode::Tab => Some(Action::NextScreen),
         KeyCode::BackTab => Some(Action::PrevScreen),
-        KeyCode::Char('1') => Some(Action::SetScreen(Screen::History)),
-        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Search)),
-        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Library)),
-        KeyCode::Char('5') => Some(Action::SetScreen(Screen::Settings)),
-        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Help)),
+        KeyCode::Char('1') => Some(Action::SetScreen(Screen::Home)),
+        KeyCode::Char('2') => Some(Action::SetScreen(Screen::Explore)),
+        KeyCode::Char('3') => Some(Action::SetScreen(Screen::History)),
+        KeyCode::Char('4') => Some(Action::SetScreen(Screen::Search)),
+        KeyCode::Char('6') => Some(Action::SetScreen(Screen::Library)),
+        KeyCode::Char('7') => Some(Action::SetScreen(Screen::Lyrics)),
+        KeyCode::Char('8') => Some(Action::SetScreen(Screen::Settings)),
+        KeyCode::Char('9') => Some(Action::SetScreen(Screen::Help)),
 
         // Playback
         KeyCode::Char(' ') => Some(Action::TogglePause),
@@ -343,6 +728,12 @@ fn handle_queue_screen(k: crossterm::event::KeyEvent) -> Option<Action> {
         KeyCode::Char('-') | KeyCode::Char('_') => Some(Action::VolumeDown),
         KeyCode::Char(']') => Some(Action::SeekForward),
         KeyCode::Char('[') => Some(Action::SeekBack),
+        KeyCode::Char('}') => Some(Action::NudgeLyrics(crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('{') => Some(Action::NudgeLyrics(-crate::lyrics::NUDGE_MS)),
+        KeyCode::Char('S') => Some(Action::ToggleLyricsSync),
+        KeyCode::Char('L') => Some(Action::OpenLyricsPicker),
+        KeyCode::Char('T') => Some(Action::ToggleLyricsSecondary),
+        KeyCode::Char('Y') => Some(Action::ToggleLyricsRomanize),
         KeyCode::Char('R') => Some(Action::ToggleRepeatMode),
 
         // Queue-specific actions
@@ -352,6 +743,10 @@ fn handle_queue_screen(k: crossterm::event::KeyEvent) -> Option<Action> {
         KeyCode::Char('s') => Some(Action::QueueShuffle),
         KeyCode::Char('K') => Some(Action::QueueMoveUp),   // Shift+K to move up
         KeyCode::Char('J') => Some(Action::QueueMoveDown), // Shift+J to move down
+        KeyCode::Char('u') => Some(Action::QueueUndo),
+        KeyCode::Char('w') => Some(Action::SaveQueueAsPlaylist),
+        KeyCode::Char('r') if k.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::QueueRedo),
+        KeyCode::Char('r') => Some(Action::StartRadio),
         KeyCode::Char('n') => Some(Action::PlayNext),
         KeyCode::Char('p') => Some(Action::PlayPrev),
 
@@ -1,4 +1,5 @@
 pub mod app;
+pub mod art;
 pub mod config;
 pub mod input;
 pub mod lyrics;
@@ -0,0 +1,106 @@
+//! Lyrics from `.lrc`/`.txt` sidecar files in a local directory
+//!
+//! Files are looked up by video id, then "Artist - Title", then title alone,
+//! preferring `.lrc` over `.txt` for each name.
+
+use super::{LyricsFuture, LyricsProvider, ParsedLyrics};
+use crate::ytm::models::Track;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct LocalLyricsProvider {
+    dir: PathBuf,
+}
+
+impl LocalLyricsProvider {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Sidecar paths to try for `track`, best match first
+    fn candidates(&self, track: &Track) -> Vec<PathBuf> {
+        let mut stems = vec![track.video_id.clone()];
+        if let Some(artist) = track.artists.first() {
+            stems.push(format!("{} - {}", artist, track.title));
+        }
+        stems.push(track.title.clone());
+
+        stems
+            .iter()
+            .map(|stem| sanitize(stem))
+            .flat_map(|stem| ["lrc", "txt"].map(|ext| self.dir.join(format!("{stem}.{ext}"))))
+            .collect()
+    }
+}
+
+impl LyricsProvider for LocalLyricsProvider {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    fn fetch<'a>(&'a self, track: &'a Track) -> LyricsFuture<'a> {
+        let candidates = self.candidates(track);
+        Box::pin(async move {
+            let found = tokio::task::spawn_blocking(move || {
+                candidates
+                    .into_iter()
+                    .find_map(|path| Some((std::fs::read_to_string(&path).ok()?, path)))
+            })
+            .await?;
+            Ok(found.map(|(content, path)| read_sidecar(&content, &path)))
+        })
+    }
+}
+
+/// `.lrc` files are synced when they carry timestamps; anything else is plain text
+fn read_sidecar(content: &str, path: &Path) -> ParsedLyrics {
+    let is_lrc = path.extension().is_some_and(|e| e == "lrc");
+    ParsedLyrics::parse(content, is_lrc && ParsedLyrics::has_timestamps(content))
+}
+
+/// Replace characters that cannot appear in file names
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_finds_sidecar_by_artist_and_title() {
+        let dir = std::env::temp_dir().join(format!("void-lyrics-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("AC_DC - Song.lrc"), "[00:01.00]Hello\n[00:02.50]World").unwrap();
+        std::fs::write(dir.join("Song.txt"), "Plain").unwrap();
+
+        let provider = LocalLyricsProvider::new(dir.clone());
+        let track = Track {
+            video_id: "vid".into(),
+            title: "Song".into(),
+            artists: vec!["AC/DC".into()],
+            ..Track::default()
+        };
+        let lyrics = provider.fetch(&track).await.unwrap().unwrap();
+        assert!(lyrics.synced);
+        assert_eq!(lyrics.lines.len(), 2);
+        assert_eq!(lyrics.lines[1].time_ms, 2500);
+
+        // Without an artist match the plain-text file is used
+        let other = Track {
+            artists: vec!["Someone".into()],
+            ..track
+        };
+        let lyrics = provider.fetch(&other).await.unwrap().unwrap();
+        assert!(!lyrics.synced);
+        assert_eq!(lyrics.lines[0].text, "Plain");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
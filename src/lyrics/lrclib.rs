@@ -3,30 +3,168 @@
 //! LRCLIB is a free lyrics API that provides synchronized (LRC format) lyrics.
 //! API Documentation: https://lrclib.net/docs
 
-use serde::Deserialize;
+use super::{LyricsFuture, LyricsProvider, ParsedLyrics};
+use crate::ytm::models::Track;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// LRCLIB API response
 #[derive(Debug, Deserialize, Clone)]
 pub struct LrclibResponse {
-    #[allow(dead_code)]
-    id: i64,
-    #[allow(dead_code)]
+    pub id: i64,
     #[serde(rename = "trackName")]
-    track_name: String,
-    #[allow(dead_code)]
+    pub track_name: String,
     #[serde(rename = "artistName")]
-    artist_name: String,
-    #[allow(dead_code)]
+    pub artist_name: String,
     #[serde(rename = "albumName")]
-    album_name: Option<String>,
-    #[allow(dead_code)]
-    duration: Option<f64>,
+    pub album_name: Option<String>,
+    pub duration: Option<f64>,
     #[serde(rename = "plainLyrics")]
     pub plain_lyrics: Option<String>,
     #[serde(rename = "syncedLyrics")]
     pub synced_lyrics: Option<String>,
 }
 
+impl LrclibResponse {
+    /// Raw lyrics and whether they are synced, preferring synced
+    pub fn content(&self) -> Option<(&str, bool)> {
+        if let Some(synced) = self.synced_lyrics.as_deref().filter(|s| !s.is_empty()) {
+            return Some((synced, true));
+        }
+        self.plain_lyrics
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .map(|plain| (plain, false))
+    }
+
+    pub fn to_lyrics(&self) -> Option<ParsedLyrics> {
+        let (content, synced) = self.content()?;
+        let mut lyrics = ParsedLyrics::parse(content, synced);
+        lyrics.source = Some("lrclib".to_string());
+        Some(lyrics)
+    }
+}
+
+/// Largest difference from the track's length for a result to count as the same recording
+const DURATION_TOLERANCE_SECS: f64 = 3.0;
+
+/// Words in bracketed title suffixes that are YouTube packaging, not part of the song name
+const TITLE_NOISE: &[&str] = &[
+    "official", "video", "audio", "lyric", "lyrics", "visualizer", "visualiser", "mv", "hd",
+    "4k", "remaster", "remastered", "explicit", "clean", "feat", "ft", "featuring",
+];
+
+/// Title and primary artist as LRCLIB knows them: no "(Official Video)" or
+/// "feat." credits, no "Artist - " prefix and no " - Topic" channel suffix
+pub fn search_terms(track: &Track) -> (String, String) {
+    let artist = track
+        .artists
+        .first()
+        .map(|a| a.trim_end_matches(" - Topic").trim().to_string())
+        .unwrap_or_default();
+
+    let mut title = strip_bracket_noise(&track.title);
+    if !artist.is_empty()
+        && let Some(rest) = title
+            .strip_prefix(&artist)
+            .and_then(|r| r.trim_start().strip_prefix(['-', '–', '—']))
+    {
+        title = rest.trim().to_string();
+    }
+    for marker in [" feat. ", " ft. ", " featuring ", " feat ", " ft "] {
+        if let Some(pos) = title.to_ascii_lowercase().find(marker) {
+            title.truncate(pos);
+        }
+    }
+    (title.trim().to_string(), artist)
+}
+
+/// Drop `(...)`/`[...]` groups whose words are all noise
+fn strip_bracket_noise(title: &str) -> String {
+    let mut out = String::new();
+    let mut rest = title;
+    while let Some(open) = rest.find(['(', '[']) {
+        let close_char = if rest[open..].starts_with('(') { ')' } else { ']' };
+        let Some(len) = rest[open..].find(close_char) else {
+            break;
+        };
+        let inner = &rest[open + 1..open + len];
+        out.push_str(&rest[..open]);
+        let is_noise = inner
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .any(|w| TITLE_NOISE.contains(&w.to_lowercase().as_str()));
+        if !is_noise {
+            out.push_str(&rest[open..=open + len]);
+        }
+        rest = &rest[open + len + 1..];
+    }
+    out.push_str(rest);
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Lowercase letters and digits only, for loose comparisons
+fn match_key(s: &str) -> String {
+    s.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// How well a search result fits a track, or `None` when it is clearly another
+/// song: the title doesn't match or the length is off by more than the tolerance
+fn score(candidate: &LrclibResponse, title: &str, artist: &str, duration_secs: Option<u32>) -> Option<i32> {
+    let (title, artist) = (match_key(title), match_key(artist));
+    let candidate_title = match_key(&candidate.track_name);
+    let candidate_artist = match_key(&candidate.artist_name);
+
+    let mut score = if candidate_title == title {
+        50
+    } else if !title.is_empty() && (candidate_title.contains(&title) || title.contains(&candidate_title)) {
+        25
+    } else {
+        return None;
+    };
+
+    if !artist.is_empty() {
+        if candidate_artist == artist {
+            score += 30;
+        } else if candidate_artist.contains(&artist) || artist.contains(&candidate_artist) {
+            score += 15;
+        }
+    }
+
+    if let (Some(expected), Some(actual)) = (duration_secs, candidate.duration) {
+        let diff = (expected as f64 - actual).abs();
+        if diff > DURATION_TOLERANCE_SECS {
+            return None;
+        }
+        score += 20 - (diff * 5.0) as i32;
+    }
+
+    if candidate.synced_lyrics.as_deref().is_some_and(|s| !s.is_empty()) {
+        score += 10;
+    }
+    Some(score)
+}
+
+/// Lyrics to contribute to LRCLIB; every field is required by the API
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishRequest {
+    pub track_name: String,
+    pub artist_name: String,
+    pub album_name: String,
+    pub duration: u32,
+    pub plain_lyrics: String,
+    pub synced_lyrics: String,
+}
+
+/// Proof-of-work puzzle handed out before publishing
+#[derive(Debug, Deserialize)]
+struct Challenge {
+    prefix: String,
+    target: String,
+}
+
 /// LRCLIB API client
 #[derive(Debug, Clone)]
 pub struct LrclibClient {
@@ -50,21 +188,62 @@ impl LrclibClient {
         }
     }
 
-    /// Get lyrics by track info
-    pub async fn get_lyrics(
-        &self,
-        track_name: &str,
-        artist_name: &str,
-        album_name: Option<&str>,
-        duration_secs: Option<u32>,
-    ) -> anyhow::Result<Option<LrclibResponse>> {
-        // First try the "get" endpoint with exact match
-        if let Some(lyrics) = self.get_exact(track_name, artist_name, album_name, duration_secs).await? {
-            return Ok(Some(lyrics));
+    #[cfg(test)]
+    fn with_base_url(base_url: String) -> Self {
+        Self {
+            base_url,
+            ..Self::new()
+        }
+    }
+
+    /// Publish lyrics, solving LRCLIB's proof-of-work challenge first. Solving
+    /// can take a while, so it runs on a blocking thread.
+    pub async fn publish(&self, request: &PublishRequest) -> anyhow::Result<()> {
+        let challenge: Challenge = self
+            .client
+            .post(format!("{}/request-challenge", self.base_url))
+            .send()
+            .await
+            .context("request publish challenge")?
+            .error_for_status()
+            .context("request publish challenge")?
+            .json()
+            .await
+            .context("parse publish challenge")?;
+
+        let token = tokio::task::spawn_blocking(move || {
+            let nonce = solve_challenge(&challenge.prefix, &challenge.target)?;
+            anyhow::Ok(format!("{}:{}", challenge.prefix, nonce))
+        })
+        .await
+        .context("solve challenge task")??;
+
+        let response = self
+            .client
+            .post(format!("{}/publish", self.base_url))
+            .header("X-Publish-Token", token)
+            .json(request)
+            .send()
+            .await
+            .context("publish lyrics")?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!("LRCLIB publish error: {status} {body}");
         }
+    }
 
-        // Fall back to search
-        self.search(track_name, artist_name).await
+    /// Lyrics with this LRCLIB id
+    pub async fn get_by_id(&self, id: i64) -> anyhow::Result<Option<LrclibResponse>> {
+        let response = self.client.get(format!("{}/get/{id}", self.base_url)).send().await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = response.error_for_status().context("LRCLIB get")?;
+        Ok(Some(response.json().await?))
     }
 
     /// Get lyrics with exact match
@@ -102,36 +281,70 @@ impl LrclibClient {
         }
     }
 
-    /// Search for lyrics
-    async fn search(
-        &self,
-        track_name: &str,
-        artist_name: &str,
-    ) -> anyhow::Result<Option<LrclibResponse>> {
-        let query = format!("{} {}", track_name, artist_name);
+    /// Every search result for a track, best match first. Results that are
+    /// clearly other songs are kept, after the plausible ones, so a picker can
+    /// still offer them.
+    pub async fn candidates(&self, track: &Track) -> anyhow::Result<Vec<(LrclibResponse, Option<i32>)>> {
+        let (title, artist) = search_terms(track);
         let url = format!(
-            "{}/search?q={}",
+            "{}/search?track_name={}&artist_name={}",
             self.base_url,
-            urlencoding::encode(&query)
+            urlencoding::encode(&title),
+            urlencoding::encode(&artist)
         );
 
         let response = self.client.get(&url).send().await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+        let results: Vec<LrclibResponse> = response
+            .error_for_status()
+            .context("LRCLIB search")?
+            .json()
+            .await?;
 
-        if response.status().is_success() {
-            let results: Vec<LrclibResponse> = response.json().await?;
+        let mut ranked: Vec<_> = results
+            .into_iter()
+            .map(|r| {
+                let score = score(&r, &title, &artist, track.duration_seconds);
+                (r, score)
+            })
+            .collect();
+        ranked.sort_by_key(|(_, score)| std::cmp::Reverse(score.unwrap_or(i32::MIN)));
+        Ok(ranked)
+    }
+}
 
-            // Return the first result that has synced lyrics, or any result
-            let best = results
-                .iter()
-                .find(|r| r.synced_lyrics.is_some())
-                .or_else(|| results.first());
+/// Find a nonce whose SHA-256 of `prefix + nonce` is at most `target` (hex)
+fn solve_challenge(prefix: &str, target: &str) -> anyhow::Result<u64> {
+    let target = hex::decode(target).context("decode challenge target")?;
+    (0..u64::MAX)
+        .find(|nonce| Sha256::digest(format!("{prefix}{nonce}")).as_slice() <= target.as_slice())
+        .context("no nonce satisfies the challenge")
+}
 
-            Ok(best.cloned())
-        } else if response.status() == reqwest::StatusCode::NOT_FOUND {
-            Ok(None)
-        } else {
-            anyhow::bail!("LRCLIB search error: {}", response.status());
-        }
+impl LyricsProvider for LrclibClient {
+    fn name(&self) -> &'static str {
+        "lrclib"
+    }
+
+    fn fetch<'a>(&'a self, track: &'a Track) -> LyricsFuture<'a> {
+        Box::pin(async move {
+            let (title, artist) = search_terms(track);
+            let exact = self
+                .get_exact(&title, &artist, track.album.as_deref(), track.duration_seconds)
+                .await?;
+            let found = match exact {
+                Some(found) => Some(found),
+                None => self
+                    .candidates(track)
+                    .await?
+                    .into_iter()
+                    .find(|(_, score)| score.is_some())
+                    .map(|(found, _)| found),
+            };
+            Ok(found.and_then(|f| f.to_lyrics()))
+        })
     }
 }
 
@@ -140,3 +353,133 @@ impl Default for LrclibClient {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Read one HTTP request: head plus `content-length` bytes of body
+    async fn read_request(sock: &mut tokio::net::TcpStream) -> String {
+        let mut data = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            let n = sock.read(&mut buf).await.unwrap();
+            data.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&data).to_string();
+            if let Some(head_end) = text.find("\r\n\r\n") {
+                let len = text[..head_end]
+                    .lines()
+                    .find_map(|l| l.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse().unwrap()))
+                    .unwrap_or(0usize);
+                if n == 0 || data.len() >= head_end + 4 + len {
+                    return text;
+                }
+            } else if n == 0 {
+                return text;
+            }
+        }
+    }
+
+    fn track(title: &str, artist: &str, duration: u32) -> Track {
+        Track {
+            title: title.into(),
+            artists: vec![artist.into()],
+            duration_seconds: Some(duration),
+            ..Track::default()
+        }
+    }
+
+    fn result(title: &str, artist: &str, duration: f64, synced: bool) -> LrclibResponse {
+        LrclibResponse {
+            id: 1,
+            track_name: title.into(),
+            artist_name: artist.into(),
+            album_name: None,
+            duration: Some(duration),
+            plain_lyrics: Some("la".into()),
+            synced_lyrics: synced.then(|| "[00:01.00]la".into()),
+        }
+    }
+
+    #[test]
+    fn test_search_terms_strip_youtube_noise() {
+        let t = track("Daft Punk - Get Lucky (Official Video) [4K] (feat. Pharrell)", "Daft Punk - Topic", 248);
+        assert_eq!(search_terms(&t), ("Get Lucky".into(), "Daft Punk".into()));
+
+        let t = track("Song ft. Someone (Live at Wembley)", "Band", 200);
+        assert_eq!(search_terms(&t).0, "Song");
+        let t = track("Song (Live at Wembley) [Official Audio]", "Band", 200);
+        assert_eq!(search_terms(&t).0, "Song (Live at Wembley)");
+    }
+
+    #[test]
+    fn test_score_prefers_matching_duration() {
+        let exact = result("Get Lucky", "Daft Punk", 248.0, false);
+        let synced_close = result("Get Lucky", "Daft Punk", 250.0, true);
+        let radio_edit = result("Get Lucky", "Daft Punk", 200.0, true);
+        let other = result("Lose Yourself to Dance", "Daft Punk", 248.0, true);
+
+        let s = |r| score(r, "Get Lucky", "Daft Punk", Some(248));
+        assert!(s(&exact).unwrap() > 0);
+        assert!(s(&synced_close).is_some());
+        assert_eq!(s(&radio_edit), None);
+        assert_eq!(s(&other), None);
+        assert!(score(&radio_edit, "Get Lucky", "Daft Punk", None).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_publish_solves_challenge() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut sock, _)) = listener.accept().await else { break };
+                let request = read_request(&mut sock).await;
+                let (status, body) = if request.starts_with("POST /api/request-challenge") {
+                    // Any hash starting with a zero nibble passes
+                    (200, format!("{{\"prefix\":\"abc\",\"target\":\"0f{}\"}}", "f".repeat(62)))
+                } else {
+                    (201, String::new())
+                };
+                seen.lock().unwrap().push(request);
+                let resp = format!(
+                    "HTTP/1.1 {status} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = sock.write_all(resp.as_bytes()).await;
+            }
+        });
+
+        let client = LrclibClient::with_base_url(format!("http://{addr}/api"));
+        let request = PublishRequest {
+            track_name: "Song".into(),
+            artist_name: "Artist".into(),
+            album_name: "Album".into(),
+            duration: 180,
+            plain_lyrics: "Hello".into(),
+            synced_lyrics: "[00:01.00]Hello".into(),
+        };
+        client.publish(&request).await.unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let publish = &requests[1];
+        assert!(publish.starts_with("POST /api/publish"));
+        assert!(publish.contains("\"syncedLyrics\":\"[00:01.00]Hello\""));
+
+        // The token must carry a nonce that actually meets the target
+        let token = publish
+            .lines()
+            .find_map(|l| l.strip_prefix("x-publish-token: "))
+            .unwrap();
+        let (prefix, nonce) = token.split_once(':').unwrap();
+        assert_eq!(prefix, "abc");
+        assert!(Sha256::digest(format!("abc{nonce}"))[0] <= 0x0f);
+    }
+}
@@ -1,37 +1,116 @@
 //! Lyrics module for fetching and displaying synchronized lyrics
 //!
 //! This module provides:
-//! - LRCLIB API client for fetching lyrics
+//! - The `LyricsProvider` trait and providers for LRCLIB, YouTube Music and local files
 //! - LRC format parser for synchronized lyrics
+//! - Sync mode for timing plain lyrics by hand
+//! - Romanization of kana and hangul
 //! - Data structures for lyrics display
 
+pub mod local;
 pub mod lrclib;
 pub mod parser;
+pub mod romanize;
+pub mod sync;
+pub mod ytmusic;
 
+pub use local::LocalLyricsProvider;
 pub use lrclib::LrclibClient;
 pub use parser::ParsedLyrics;
+pub use sync::SyncSession;
+pub use ytmusic::YtmLyricsProvider;
 
-/// Get lyrics for a track
+use crate::config::LyricsConfig;
+use crate::ytm::api::YtmClient;
+use crate::ytm::models::Track;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Step for nudging a track's lyrics earlier or later
+pub const NUDGE_MS: i64 = 100;
+
+/// Future returned by `LyricsProvider::fetch`
+pub type LyricsFuture<'a> =
+    Pin<Box<dyn Future<Output = anyhow::Result<Option<ParsedLyrics>>> + Send + 'a>>;
+
+/// A source of lyrics. Providers return `Ok(None)` when they have nothing for a track.
+pub trait LyricsProvider: Send + Sync {
+    /// Identifier used in `lyrics.providers` and stored with cached lyrics
+    fn name(&self) -> &'static str;
+
+    fn fetch<'a>(&'a self, track: &'a Track) -> LyricsFuture<'a>;
+}
+
+/// Provider names in the default priority order
+pub const DEFAULT_PROVIDERS: &[&str] = &["local", "lrclib", "ytmusic"];
+
+/// Providers in the configured order. Unknown names are skipped, as is the
+/// local provider when no directory is configured.
+pub fn build_providers(cfg: &LyricsConfig, ytm: &YtmClient) -> Vec<Box<dyn LyricsProvider>> {
+    cfg.providers
+        .iter()
+        .filter_map(|name| -> Option<Box<dyn LyricsProvider>> {
+            match name.as_str() {
+                "lrclib" => Some(Box::new(LrclibClient::new())),
+                "ytmusic" => Some(Box::new(YtmLyricsProvider::new(ytm.clone()))),
+                "local" => Some(Box::new(LocalLyricsProvider::new(cfg.local_dir.clone()?))),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Display name of a provider for the lyrics pane
+pub fn provider_label(name: &str) -> &str {
+    match name {
+        "lrclib" => "LRCLIB",
+        "ytmusic" => "YouTube Music",
+        "local" => "Local file",
+        other => other,
+    }
+}
+
+/// Get lyrics for a track from the first provider that has them
 pub async fn fetch_lyrics(
-    client: &LrclibClient,
-    title: &str,
-    artist: &str,
-    album: Option<&str>,
-    duration_secs: Option<u32>,
+    providers: &[Box<dyn LyricsProvider>],
+    track: &Track,
 ) -> anyhow::Result<Option<ParsedLyrics>> {
-    let result = client.get_lyrics(title, artist, album, duration_secs).await?;
-
-    if let Some(lyrics) = result {
-        // Try synced lyrics first, fall back to plain
-        if let Some(synced) = &lyrics.synced_lyrics
-            && !synced.is_empty() {
-                return Ok(Some(ParsedLyrics::parse(synced, true)));
+    let mut last_err = None;
+    for provider in providers {
+        match provider.fetch(track).await {
+            Ok(Some(mut lyrics)) if !lyrics.lines.is_empty() => {
+                lyrics.source = Some(provider.name().to_string());
+                return Ok(Some(lyrics));
             }
-        if let Some(plain) = &lyrics.plain_lyrics
-            && !plain.is_empty() {
-                return Ok(Some(ParsedLyrics::parse(plain, false)));
+            Ok(_) => {}
+            Err(e) => {
+                tracing::debug!(provider = provider.name(), error = %e, "lyrics provider failed");
+                last_err = Some(e);
             }
+        }
+    }
+
+    // Only an error if nothing was found and a provider could not answer
+    match last_err {
+        Some(e) => Err(e),
+        None => Ok(None),
     }
+}
 
-    Ok(None)
+/// A standalone `.lrc` file for `track`: `[ti:]`, `[ar:]`, `[al:]` and `[length:]`
+/// headers, then the lyrics
+pub fn lrc_file(track: &Track, lyrics: &ParsedLyrics) -> String {
+    let mut out = format!("[ti:{}]\n", track.title);
+    if !track.artists.is_empty() {
+        out.push_str(&format!("[ar:{}]\n", track.artists.join(", ")));
+    }
+    if let Some(album) = &track.album {
+        out.push_str(&format!("[al:{album}]\n"));
+    }
+    if let Some(secs) = track.duration_seconds {
+        out.push_str(&format!("[length:{:02}:{:02}]\n", secs / 60, secs % 60));
+    }
+    out.push_str(&lyrics.to_lrc());
+    out.push('\n');
+    out
 }
@@ -6,6 +6,9 @@
 //! Example:
 //! [00:12.34] Hello world
 //! [00:15.00] Another line
+//!
+//! Enhanced LRC adds per-word timings after the line timestamp:
+//! [00:12.34]<00:12.34>Hello <00:12.90>world
 
 /// A single line of lyrics with timestamp
 #[derive(Debug, Clone)]
@@ -14,14 +17,35 @@ pub struct LrcLine {
     pub time_ms: u64,
     /// The lyrics text
     pub text: String,
+    /// Per-word timings from enhanced LRC; empty for line-level lyrics
+    pub words: Vec<LrcWord>,
+    /// Further lines with the same timestamp, usually a translation or romanization
+    pub secondary: Vec<String>,
+}
+
+/// A word (with its trailing space) and when it starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LrcWord {
+    pub time_ms: u64,
+    pub text: String,
 }
 
 impl LrcLine {
     pub fn new(time_ms: u64, text: String) -> Self {
-        Self { time_ms, text }
+        Self {
+            time_ms,
+            text,
+            words: Vec::new(),
+            secondary: Vec::new(),
+        }
     }
 }
 
+/// Format milliseconds as an LRC timestamp body, `mm:ss.xx`
+pub fn format_timestamp(ms: u64) -> String {
+    format!("{:02}:{:02}.{:02}", ms / 60000, (ms % 60000) / 1000, (ms % 1000) / 10)
+}
+
 /// Parsed lyrics with metadata
 #[derive(Debug, Clone)]
 pub struct ParsedLyrics {
@@ -29,12 +53,15 @@ pub struct ParsedLyrics {
     pub lines: Vec<LrcLine>,
     /// Whether the lyrics are synchronized
     pub synced: bool,
+    /// Provider the lyrics came from (`LyricsProvider::name`)
+    pub source: Option<String>,
 }
 
 impl ParsedLyrics {
     /// Parse LRC formatted lyrics
     pub fn parse(content: &str, synced: bool) -> Self {
         let mut lines = Vec::new();
+        let mut offset_ms: i64 = 0;
 
         for line in content.lines() {
             let line = line.trim();
@@ -42,8 +69,11 @@ impl ParsedLyrics {
                 continue;
             }
 
-            // Skip metadata tags like [ti:Title]
-            if Self::parse_metadata(line).is_some() {
+            // Skip metadata tags like [ti:Title], keeping only the offset
+            if let Some((tag, value)) = Self::parse_metadata(line) {
+                if tag.eq_ignore_ascii_case("offset") {
+                    offset_ms = value.trim_start_matches('+').parse().unwrap_or(0);
+                }
                 continue;
             }
 
@@ -60,10 +90,108 @@ impl ParsedLyrics {
             }
         }
 
-        // Sort by timestamp
+        // Sort by timestamp; the sort is stable, so lines sharing a time keep file order
         lines.sort_by_key(|l| l.time_ms);
+        if synced {
+            // Before the offset, so lines it clamps to 0 are not taken for translations
+            lines = Self::group_same_time(lines);
+        }
+
+        // A positive offset makes the lyrics come sooner
+        if synced && offset_ms != 0 {
+            let shift = |ms: u64| (ms as i64 - offset_ms).max(0) as u64;
+            for line in &mut lines {
+                line.time_ms = shift(line.time_ms);
+                for word in &mut line.words {
+                    word.time_ms = shift(word.time_ms);
+                }
+            }
+        }
+
+        Self {
+            lines,
+            synced,
+            source: None,
+        }
+    }
+
+    /// Fold lines that repeat the previous timestamp into its `secondary` lines.
+    /// An empty spacer line is never a primary, so text after it stays a lyric.
+    fn group_same_time(lines: Vec<LrcLine>) -> Vec<LrcLine> {
+        let mut grouped: Vec<LrcLine> = Vec::with_capacity(lines.len());
+        for line in lines {
+            match grouped.last_mut() {
+                Some(primary)
+                    if primary.time_ms == line.time_ms
+                        && !primary.text.is_empty()
+                        && !line.text.is_empty() =>
+                {
+                    primary.secondary.push(line.text);
+                }
+                _ => grouped.push(line),
+            }
+        }
+        grouped
+    }
+
+    /// Serialize back to LRC that `parse` reads into the same lines: timestamps,
+    /// enhanced LRC word tags and translations sharing their line's timestamp.
+    /// Unsynced lyrics come out as plain text.
+    pub fn to_lrc(&self) -> String {
+        self.lines
+            .iter()
+            .flat_map(|l| {
+                if !self.synced {
+                    return vec![l.text.clone()];
+                }
+                let stamp = format_timestamp(l.time_ms);
+                let primary = if l.words.is_empty() {
+                    format!("[{stamp}]{}", l.text)
+                } else {
+                    let words: String = l
+                        .words
+                        .iter()
+                        .map(|w| format!("<{}>{}", format_timestamp(w.time_ms), w.text))
+                        .collect();
+                    format!("[{stamp}]{words}")
+                };
+                std::iter::once(primary)
+                    .chain(l.secondary.iter().map(|t| format!("[{stamp}]{t}")))
+                    .collect()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 
-        Self { lines, synced }
+    /// Index of the line playing at `position_ms`; always 0 for unsynced lyrics
+    pub fn line_at(&self, position_ms: u64) -> usize {
+        if !self.synced {
+            return 0;
+        }
+        self.lines
+            .iter()
+            .rposition(|l| l.time_ms <= position_ms)
+            .unwrap_or(0)
+    }
+
+    /// Next line after `from` (or before, going back) containing `query`,
+    /// ignoring case and wrapping around
+    pub fn find_line(&self, query: &str, from: usize, forward: bool) -> Option<usize> {
+        let query = query.to_lowercase();
+        if query.is_empty() || self.lines.is_empty() {
+            return None;
+        }
+        let len = self.lines.len();
+        (1..=len)
+            .map(|step| if forward { (from + step) % len } else { (from + len * 2 - step) % len })
+            .find(|&i| self.lines[i].text.to_lowercase().contains(&query))
+    }
+
+    /// Whether any line of `content` carries a timestamp
+    pub fn has_timestamps(content: &str) -> bool {
+        content
+            .lines()
+            .any(|line| Self::parse_timed_line(line.trim()).is_some())
     }
 
     /// Parse metadata tag like [ti:Title]
@@ -79,8 +207,8 @@ impl ParsedLyrics {
         let colon_pos = tag_content.find(':')?;
         let tag = &tag_content[..colon_pos];
 
-        // Metadata tags are typically 2-3 chars
-        if tag.len() <= 3 && tag.chars().all(|c| c.is_ascii_alphabetic()) {
+        // Metadata tags are letters, e.g. [ar:] or [offset:]; timestamps start with digits
+        if !tag.is_empty() && tag.chars().all(|c| c.is_ascii_alphabetic()) {
             let value = tag_content[colon_pos + 1..].trim().to_string();
             return Some((tag.to_string(), value));
         }
@@ -112,18 +240,61 @@ impl ParsedLyrics {
             return None;
         }
 
-        // The rest is the lyrics text
-        let text = line[pos..].trim().to_string();
+        // The rest is the lyrics text, possibly with <mm:ss.xx> word tags
+        let (text, words) = Self::parse_words(&line[pos..]);
 
         // Create a line for each timestamp
         let lines = timestamps
             .into_iter()
-            .map(|ts| LrcLine::new(ts, text.clone()))
+            .map(|ts| LrcLine {
+                words: words.clone(),
+                ..LrcLine::new(ts, text.clone())
+            })
             .collect();
 
         Some(lines)
     }
 
+    /// Split enhanced LRC text into plain text and timed words. Text without
+    /// word tags comes back unchanged with no words.
+    fn parse_words(rest: &str) -> (String, Vec<LrcWord>) {
+        let mut words: Vec<LrcWord> = Vec::new();
+        let mut text = String::new();
+        let mut remaining = rest;
+
+        while let Some(start) = remaining.find('<') {
+            let Some(len) = remaining[start..].find('>') else {
+                break;
+            };
+            let Some(ms) = Self::parse_timestamp(&remaining[start + 1..start + len]) else {
+                break;
+            };
+
+            // Anything before the first tag belongs to no word
+            let before = &remaining[..start];
+            match words.last_mut() {
+                Some(word) => word.text.push_str(before),
+                None => text.push_str(before),
+            }
+            words.push(LrcWord {
+                time_ms: ms,
+                text: String::new(),
+            });
+            remaining = &remaining[start + len + 1..];
+        }
+        match words.last_mut() {
+            Some(word) => word.text.push_str(remaining),
+            None => text.push_str(remaining),
+        }
+
+        // A closing tag with no text only marks when the last word ends
+        words.retain(|w| !w.text.trim().is_empty());
+        for word in &words {
+            text.push_str(&word.text);
+        }
+        (text.trim().to_string(), words)
+    }
+
     /// Parse timestamp string like "00:12.34" or "00:12:34" to milliseconds
     fn parse_timestamp(s: &str) -> Option<u64> {
         // Format: mm:ss.xx or mm:ss:xx or mm:ss
@@ -181,5 +352,87 @@ mod tests {
         assert_eq!(parsed.lines.len(), 2);
         assert_eq!(parsed.lines[0].time_ms, 12340);
         assert_eq!(parsed.lines[0].text, "First line");
+        assert!(parsed.lines[0].words.is_empty());
+    }
+
+    #[test]
+    fn test_parse_enhanced_lrc() {
+        let lrc = "[00:12.00]<00:12.00>Hello <00:12.50>big <00:13.10>world<00:14.00>";
+        let parsed = ParsedLyrics::parse(lrc, true);
+        let line = &parsed.lines[0];
+        assert_eq!(line.text, "Hello big world");
+        assert_eq!(
+            line.words,
+            vec![
+                LrcWord { time_ms: 12000, text: "Hello ".into() },
+                LrcWord { time_ms: 12500, text: "big ".into() },
+                LrcWord { time_ms: 13100, text: "world".into() },
+            ]
+        );
+        assert_eq!(format_timestamp(line.words[2].time_ms), "00:13.10");
+    }
+
+    #[test]
+    fn test_group_translation_lines() {
+        let lrc = "[00:01.00]君の名前\n[00:01.00]Your name\n[00:04.00]\n[00:04.00]Instrumental\n[00:05.00]Next";
+        let parsed = ParsedLyrics::parse(lrc, true);
+        assert_eq!(parsed.lines.len(), 4);
+        assert_eq!(parsed.lines[0].text, "君の名前");
+        assert_eq!(parsed.lines[0].secondary, vec!["Your name"]);
+        // Text after an empty spacer is a lyric of its own
+        assert!(parsed.lines[1].secondary.is_empty());
+        assert_eq!(parsed.lines[2].text, "Instrumental");
+        assert!(parsed.lines[3].secondary.is_empty());
+    }
+
+    #[test]
+    fn test_line_at_and_find_line() {
+        let parsed = ParsedLyrics::parse("[00:01.00]One love\n[00:05.00]Two hearts\n[00:09.00]One more", true);
+        assert_eq!(parsed.line_at(0), 0);
+        assert_eq!(parsed.line_at(6000), 1);
+        assert_eq!(parsed.line_at(60000), 2);
+
+        assert_eq!(parsed.find_line("ONE", 0, true), Some(2));
+        assert_eq!(parsed.find_line("one", 2, true), Some(0));
+        assert_eq!(parsed.find_line("one", 0, false), Some(2));
+        assert_eq!(parsed.find_line("three", 0, true), None);
+    }
+
+    #[test]
+    fn test_to_lrc_round_trip() {
+        let lrc = "[ti:Song]\n[00:01.00]君の名前\n[00:01.00]Your name\n[00:04.50]<00:04.50>Hello <00:05.10>world\n[01:02.03]Last";
+        let parsed = ParsedLyrics::parse(lrc, true);
+        let again = ParsedLyrics::parse(&parsed.to_lrc(), true);
+        assert_eq!(again.lines.len(), parsed.lines.len());
+        for (a, b) in parsed.lines.iter().zip(&again.lines) {
+            assert_eq!(a.time_ms, b.time_ms);
+            assert_eq!(a.text, b.text);
+            assert_eq!(a.words, b.words);
+            assert_eq!(a.secondary, b.secondary);
+        }
+        assert_eq!(again.to_lrc(), parsed.to_lrc());
+
+        let plain = ParsedLyrics::parse("First line\nSecond line", false);
+        assert_eq!(plain.to_lrc(), "First line\nSecond line");
+        let again = ParsedLyrics::parse(&plain.to_lrc(), false);
+        assert_eq!(again.lines[1].text, "Second line");
+    }
+
+    #[test]
+    fn test_parse_offset_tag() {
+        let lrc = "[offset:+500]\n[00:00.20]Intro\n[00:12.00]<00:12.00>Hello <00:12.50>world";
+        let parsed = ParsedLyrics::parse(lrc, true);
+        assert_eq!(parsed.lines.len(), 2);
+        assert_eq!(parsed.lines[0].time_ms, 0);
+        assert_eq!(parsed.lines[1].time_ms, 11500);
+        assert_eq!(parsed.lines[1].words[1].time_ms, 12000);
+
+        let parsed = ParsedLyrics::parse("[offset:-250]\n[00:01.00]Late", true);
+        assert_eq!(parsed.lines[0].time_ms, 1250);
+
+        // Lines the offset clamps to 0 are still separate lines
+        let parsed = ParsedLyrics::parse("[offset:+1000]\n[00:00.20]One\n[00:00.60]Two", true);
+        assert_eq!(parsed.lines.len(), 2);
+        assert!(parsed.lines.iter().all(|l| l.time_ms == 0 && l.secondary.is_empty()));
     }
 }
@@ -0,0 +1,160 @@
+//! Built-in romanization for kana (Hepburn) and hangul (Revised Romanization).
+//! Kanji and hanja need a dictionary, so they pass through unchanged.
+
+/// Romanized `text`, or `None` when it has no kana or hangul
+pub fn romanize(text: &str) -> Option<String> {
+    if !text.chars().any(|c| is_kana(c) || is_hangul(c)) {
+        return None;
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut double_next = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if is_hangul(c) {
+            out.push_str(&hangul_syllable(c));
+        } else if let Some(h) = to_hiragana(c) {
+            match h {
+                // Small tsu doubles the next consonant
+                'っ' => double_next = true,
+                // Long vowel mark repeats the vowel before it
+                'ー' => {
+                    if let Some(v) = out.chars().last().filter(|v| "aeiou".contains(*v)) {
+                        out.push(v);
+                    }
+                }
+                _ => {
+                    let mut syllable = kana_syllable(h).to_string();
+                    // Small ya/yu/yo and small vowels combine with the kana before them
+                    let small = chars.get(i + 1).and_then(|&n| to_hiragana(n)).and_then(small_kana);
+                    if let Some(small) = small
+                        && !syllable.is_empty()
+                    {
+                        syllable = combine(&syllable, small);
+                        i += 1;
+                    }
+                    if double_next {
+                        double_next = false;
+                        match syllable.as_str() {
+                            s if s.starts_with("ch") => out.push('t'),
+                            s => out.extend(s.chars().next().filter(|c| !"aeiou".contains(*c))),
+                        }
+                    }
+                    out.push_str(&syllable);
+                }
+            }
+        } else {
+            out.push(c);
+        }
+        i += 1;
+    }
+    Some(out)
+}
+
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{3096}' | '\u{30A1}'..='\u{30FA}' | 'ー')
+}
+
+fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{AC00}'..='\u{D7A3}')
+}
+
+/// Katakana folded onto hiragana, which shares its layout
+fn to_hiragana(c: char) -> Option<char> {
+    match c {
+        '\u{3041}'..='\u{3096}' | 'ー' => Some(c),
+        '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60),
+        'ヷ' => Some('わ'),
+        'ヸ' | 'ヹ' | 'ヺ' => Some('ゔ'),
+        _ => None,
+    }
+}
+
+/// Vowel glide of a small kana that attaches to the syllable before it
+fn small_kana(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ゃ' => "ya",
+        'ゅ' => "yu",
+        'ょ' => "yo",
+        'ぁ' => "a",
+        'ぃ' => "i",
+        'ぅ' => "u",
+        'ぇ' => "e",
+        'ぉ' => "o",
+        _ => return None,
+    })
+}
+
+/// `syllable` followed by a small kana: きゃ kya, しゃ sha, ファ fa, ウィ wi
+fn combine(syllable: &str, small: &str) -> String {
+    let stem = &syllable[..syllable.len() - 1];
+    match (syllable, small.strip_prefix('y')) {
+        ("shi" | "chi" | "ji", Some(vowel)) => format!("{stem}{vowel}"),
+        ("u", None) => format!("w{small}"),
+        _ => format!("{stem}{small}"),
+    }
+}
+
+fn kana_syllable(c: char) -> &'static str {
+    match c {
+        'あ' => "a", 'い' => "i", 'う' => "u", 'え' => "e", 'お' => "o",
+        'か' => "ka", 'き' => "ki", 'く' => "ku", 'け' => "ke", 'こ' => "ko",
+        'が' => "ga", 'ぎ' => "gi", 'ぐ' => "gu", 'げ' => "ge", 'ご' => "go",
+        'さ' => "sa", 'し' => "shi", 'す' => "su", 'せ' => "se", 'そ' => "so",
+        'ざ' => "za", 'じ' => "ji", 'ず' => "zu", 'ぜ' => "ze", 'ぞ' => "zo",
+        'た' => "ta", 'ち' => "chi", 'つ' => "tsu", 'て' => "te", 'と' => "to",
+        'だ' => "da", 'ぢ' => "ji", 'づ' => "zu", 'で' => "de", 'ど' => "do",
+        'な' => "na", 'に' => "ni", 'ぬ' => "nu", 'ね' => "ne", 'の' => "no",
+        'は' => "ha", 'ひ' => "hi", 'ふ' => "fu", 'へ' => "he", 'ほ' => "ho",
+        'ば' => "ba", 'び' => "bi", 'ぶ' => "bu", 'べ' => "be", 'ぼ' => "bo",
+        'ぱ' => "pa", 'ぴ' => "pi", 'ぷ' => "pu", 'ぺ' => "pe", 'ぽ' => "po",
+        'ま' => "ma", 'み' => "mi", 'む' => "mu", 'め' => "me", 'も' => "mo",
+        'や' => "ya", 'ゆ' => "yu", 'よ' => "yo",
+        'ら' => "ra", 'り' => "ri", 'る' => "ru", 'れ' => "re", 'ろ' => "ro",
+        'わ' => "wa", 'ゐ' => "i", 'ゑ' => "e", 'を' => "o", 'ん' => "n",
+        'ゔ' => "vu",
+        // Small kana on their own
+        'ぁ' => "a", 'ぃ' => "i", 'ぅ' => "u", 'ぇ' => "e", 'ぉ' => "o",
+        'ゃ' => "ya", 'ゅ' => "yu", 'ょ' => "yo", 'ゎ' => "wa",
+        'ゕ' => "ka", 'ゖ' => "ke",
+        _ => "",
+    }
+}
+
+const INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p", "h",
+];
+const MEDIALS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+const FINALS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// A precomposed hangul syllable split into its jamo
+fn hangul_syllable(c: char) -> String {
+    let index = c as usize - 0xAC00;
+    let (initial, medial, fin) = (index / 588, (index % 588) / 28, index % 28);
+    format!("{}{}{}", INITIALS[initial], MEDIALS[medial], FINALS[fin])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_romanize_kana_and_hangul() {
+        assert_eq!(romanize("きみのなまえ").as_deref(), Some("kiminonamae"));
+        assert_eq!(romanize("しゃしん きって").as_deref(), Some("shashin kitte"));
+        assert_eq!(romanize("マッチ コーヒー").as_deref(), Some("matchi koohii"));
+        assert_eq!(romanize("ファン ウィ").as_deref(), Some("fan wi"));
+        assert_eq!(romanize("夢をみた").as_deref(), Some("夢omita"));
+        assert_eq!(romanize("안녕하세요").as_deref(), Some("annyeonghaseyo"));
+        assert_eq!(romanize("사랑해 baby").as_deref(), Some("saranghae baby"));
+        assert_eq!(romanize("plain english"), None);
+    }
+}
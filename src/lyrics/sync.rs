@@ -0,0 +1,131 @@
+//! Sync mode: turning plain lyrics into synced LRC by stamping each line as it is sung
+
+use super::parser::format_timestamp;
+use super::ParsedLyrics;
+
+/// Smallest step between stamps. LRC keeps centiseconds, and lines sharing a
+/// timestamp would read back as a line and its translation.
+const MIN_GAP_MS: u64 = 10;
+
+/// An in-progress timing of a track's plain lyrics
+#[derive(Debug, Clone)]
+pub struct SyncSession {
+    pub video_id: String,
+    lines: Vec<String>,
+    /// Start time of each stamped line; line `i` is stamped once `stamps.len() > i`
+    stamps: Vec<u64>,
+}
+
+impl SyncSession {
+    pub fn new(video_id: String, lyrics: &ParsedLyrics) -> Self {
+        Self {
+            video_id,
+            lines: lyrics.lines.iter().map(|l| l.text.clone()).collect(),
+            stamps: Vec::new(),
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// When line `i` starts, if stamped yet
+    pub fn stamp_at(&self, i: usize) -> Option<u64> {
+        self.stamps.get(i).copied()
+    }
+
+    /// Index of the next line to stamp
+    pub fn next_index(&self) -> usize {
+        self.stamps.len()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.stamps.len() >= self.lines.len()
+    }
+
+    /// Stamp the next line at `position_ms`. Returns false once every line is stamped.
+    pub fn stamp(&mut self, position_ms: u64) -> bool {
+        if self.is_complete() {
+            return false;
+        }
+        // Seeking back mid-session must not make lines go out of order
+        let floor = self.stamps.last().map_or(0, |&prev| prev + MIN_GAP_MS);
+        self.stamps.push(position_ms.max(floor));
+        true
+    }
+
+    /// Forget the most recent stamp
+    pub fn undo(&mut self) {
+        self.stamps.pop();
+    }
+
+    /// Move the most recent stamp by `delta_ms`, keeping it after the one before
+    pub fn adjust(&mut self, delta_ms: i64) {
+        let floor = self
+            .stamps
+            .len()
+            .checked_sub(2)
+            .map_or(0, |i| self.stamps[i] + MIN_GAP_MS);
+        if let Some(last) = self.stamps.last_mut() {
+            *last = (*last as i64 + delta_ms).max(floor as i64) as u64;
+        }
+    }
+
+    /// The stamped lines as LRC
+    pub fn to_lrc(&self) -> String {
+        self.lines
+            .iter()
+            .zip(&self.stamps)
+            .map(|(text, ms)| format!("[{}]{}", format_timestamp(*ms), text))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The plain lyrics, one line each, as LRCLIB expects them alongside the synced ones
+    pub fn plain_text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stamp_undo_and_adjust() {
+        let plain = ParsedLyrics::parse("First\nSecond\nThird", false);
+        let mut session = SyncSession::new("vid".into(), &plain);
+
+        assert!(session.stamp(1000));
+        assert!(session.stamp(2500));
+        session.undo();
+        assert!(session.stamp(3000));
+        session.adjust(-5000);
+        assert_eq!(session.stamp_at(1), Some(1010));
+        session.adjust(200);
+        assert!(session.stamp(900));
+        assert!(!session.stamp(9000));
+        assert!(session.is_complete());
+
+        assert_eq!(session.to_lrc(), "[00:01.00]First\n[00:01.21]Second\n[00:01.22]Third");
+        let synced = ParsedLyrics::parse(&session.to_lrc(), true);
+        assert_eq!(synced.lines[1].time_ms, 1210);
+    }
+
+    #[test]
+    fn test_stamps_at_one_position_stay_separate_lines() {
+        let plain = ParsedLyrics::parse("One\nTwo\nThree", false);
+        let mut session = SyncSession::new("vid".into(), &plain);
+        session.stamp(4005);
+        session.stamp(4005);
+        session.stamp(4000);
+
+        let synced = ParsedLyrics::parse(&session.to_lrc(), true);
+        let again = ParsedLyrics::parse(&synced.to_lrc(), true);
+        for lyrics in [&synced, &again] {
+            let texts: Vec<_> = lyrics.lines.iter().map(|l| l.text.as_str()).collect();
+            assert_eq!(texts, ["One", "Two", "Three"]);
+            assert!(lyrics.lines.iter().all(|l| l.secondary.is_empty()));
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Lyrics from YouTube Music's own Lyrics tab
+//!
+//! These are plain text (no timestamps), licensed from providers like Musixmatch.
+
+use super::{LyricsFuture, LyricsProvider, ParsedLyrics};
+use crate::ytm::api::YtmClient;
+use crate::ytm::models::Track;
+
+#[derive(Clone)]
+pub struct YtmLyricsProvider {
+    ytm: YtmClient,
+}
+
+impl YtmLyricsProvider {
+    pub fn new(ytm: YtmClient) -> Self {
+        Self { ytm }
+    }
+}
+
+impl LyricsProvider for YtmLyricsProvider {
+    fn name(&self) -> &'static str {
+        "ytmusic"
+    }
+
+    fn fetch<'a>(&'a self, track: &'a Track) -> LyricsFuture<'a> {
+        Box::pin(async move {
+            let text = self.ytm.get_lyrics(&track.video_id).await?;
+            Ok(text.map(|t| ParsedLyrics::parse(&t, false)))
+        })
+    }
+}
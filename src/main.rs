@@ -1,4 +1,5 @@
 mod app;
+mod art;
 mod config;
 mod input;
 mod lyrics;
@@ -18,6 +19,18 @@ struct Cli {
     #[arg(long)]
     config: Option<std::path::PathBuf>,
 
+    /// Language for YouTube Music results, e.g. "en" or "ja" (overrides config).
+    #[arg(long, global = true)]
+    hl: Option<String>,
+
+    /// Region for charts and search ranking, e.g. "US" or "DE" (overrides config).
+    #[arg(long, global = true)]
+    gl: Option<String>,
+
+    /// Talk to this server instead of music.youtube.com (overrides config).
+    #[arg(long, global = true)]
+    base_url: Option<String>,
+
     #[command(subcommand)]
     command: Option<Command>,
 }
@@ -26,7 +39,7 @@ struct Cli {
 enum Command {
     /// Run the interactive TUI (default).
     Tui,
-    /// Print Home tracks to stdout (headless).
+    /// Print Home shelves to stdout (headless).
     Home,
     /// Search tracks and print to stdout (headless).
     Search {
@@ -36,6 +49,20 @@ enum Command {
     Playlist {
         playlist_id: String,
     },
+    /// Print radio tracks seeded from a video (headless).
+    Radio {
+        video_id: String,
+    },
+    /// Print lyrics for a video id or "title - artist" (headless).
+    Lyrics {
+        query: String,
+        /// Print only the text, without timestamps.
+        #[arg(long)]
+        plain: bool,
+        /// Also write an .lrc file with title, artist, album and length tags.
+        #[arg(long, short)]
+        output: Option<std::path::PathBuf>,
+    },
     /// Dump raw Search JSON to stdout (headless).
     SearchJson {
         query: String,
@@ -63,7 +90,7 @@ enum AuthCommand {
         /// Browser name: chrome, firefox, brave, etc.
         browser: String,
     },
-    /// Configure cookies from Zen browser on macOS (auto-detect profile).
+    /// Configure cookies from Zen browser (auto-detect profile).
     Zen,
     /// Use a Netscape cookies file on disk.
     File {
@@ -71,6 +98,30 @@ enum AuthCommand {
     },
     /// Clear auth settings.
     Clear,
+    /// Show where cookies come from, when they expire and who is signed in.
+    Status,
+    /// List channels (own and brand accounts) of the signed-in account.
+    Channels,
+    /// List named auth profiles.
+    Profiles,
+    /// Add or update a named auth profile.
+    Profile {
+        name: String,
+        /// Browser to read cookies from (defaults to the top-level setting).
+        #[arg(long)]
+        browser: Option<String>,
+        /// Netscape cookie file (defaults to the top-level setting).
+        #[arg(long)]
+        cookies: Option<std::path::PathBuf>,
+        /// Google account index in a multi-login session (X-Goog-AuthUser).
+        #[arg(long)]
+        auth_user: Option<u32>,
+        /// Brand account page id, as shown by `void auth channels`.
+        #[arg(long)]
+        page_id: Option<String>,
+    },
+    /// Switch the active profile ("default" for the top-level settings).
+    Use { name: String },
 }
 
 #[derive(Debug, Subcommand)]
@@ -92,6 +143,12 @@ async fn main() -> anyhow::Result<()> {
 
     let cli = Cli::parse();
     let cfg = config::load(cli.config.as_deref()).context("load config")?;
+    // Kept apart from `cfg` so commands that save it don't persist them
+    let overrides = config::ClientOverrides {
+        hl: cli.hl.clone(),
+        gl: cli.gl.as_ref().map(|gl| gl.to_uppercase()),
+        base_url: cli.base_url.clone(),
+    };
     let cfg_path = match cli.config.clone() {
         Some(p) => p,
         None => config::default_config_path().context("default config path")?,
@@ -100,34 +157,89 @@ async fn main() -> anyhow::Result<()> {
     match cli.command.unwrap_or(Command::Tui) {
         Command::Tui => {
             let mut terminal = tui::TerminalGuard::enter().context("init terminal")?;
-            let mut app = app::App::new(cfg, cfg_path)?;
+            let mut app = app::App::new(cfg, cfg_path, overrides)?;
             app.run(terminal.terminal_mut()).await?;
         }
         Command::Home => {
-            let ytm = make_client(&cfg).await?;
-            let tracks = ytm.browse_home_tracks().await?;
-            print_tracks(&tracks);
+            let ytm = make_client(&cfg, &overrides).await?;
+            let home = ytm.browse_home().await?;
+            print_shelves(&home.shelves);
         }
         Command::Search { query } => {
-            let ytm = make_client(&cfg).await?;
+            let ytm = make_client(&cfg, &overrides).await?;
             let tracks = ytm.search_tracks(&query).await?;
             print_tracks(&tracks);
         }
         Command::Playlist { playlist_id } => {
-            let ytm = make_client(&cfg).await?;
+            let ytm = make_client(&cfg, &overrides).await?;
             let tracks = ytm.browse_playlist_tracks(&playlist_id).await?;
             print_tracks(&tracks);
         }
+        Command::Radio { video_id } => {
+            let ytm = make_client(&cfg, &overrides).await?;
+            let tracks = ytm.get_radio_tracks(&video_id).await?;
+            print_tracks(&tracks);
+        }
+        Command::Lyrics {
+            query,
+            plain,
+            output,
+        } => {
+            let ytm = make_client(&cfg, &overrides).await?;
+            let track = resolve_track(&ytm, &query).await?;
+            let storage = storage::Storage::open(&cfg.paths.data_dir.join("cache.sqlite3"))?;
+            let Some(lyrics) = load_lyrics(&cfg, &ytm, &storage, &track).await? else {
+                anyhow::bail!("no lyrics found for {} ({})", track.title, track.video_id);
+            };
+            if plain {
+                for line in &lyrics.lines {
+                    println!("{}", line.text);
+                }
+            } else {
+                println!("{}", lyrics.to_lrc());
+            }
+            if let Some(path) = output {
+                std::fs::write(&path, lyrics::lrc_file(&track, &lyrics))
+                    .with_context(|| format!("write {}", path.display()))?;
+                eprintln!("Wrote {}", path.display());
+            }
+        }
         Command::SearchJson { query } => {
-            let ytm = make_client(&cfg).await?;
+            let ytm = make_client(&cfg, &overrides).await?;
             let v = ytm.search_raw(&query).await?;
             println!("{}", serde_json::to_string_pretty(&v)?);
         }
         Command::HomeJson => {
-            let ytm = make_client(&cfg).await?;
+            let ytm = make_client(&cfg, &overrides).await?;
             let v = ytm.browse_home_raw().await?;
             println!("{}", serde_json::to_string_pretty(&v)?);
         }
+        Command::Auth {
+            method: AuthCommand::Status,
+        } => {
+            print_auth_status(&cfg, &overrides).await?;
+        }
+        Command::Auth {
+            method: AuthCommand::Channels,
+        } => {
+            let ytm = make_client(&cfg, &overrides).await?;
+            for c in ytm.get_channels().await? {
+                let marker = if c.selected { "*" } else { " " };
+                let handle = c.handle.map(|h| format!(" ({h})")).unwrap_or_default();
+                let page_id = c.page_id.unwrap_or_else(|| "-".to_string());
+                println!("{marker} {}{handle}\tpage id: {page_id}", c.name);
+            }
+        }
+        Command::Auth {
+            method: AuthCommand::Profiles,
+        } => {
+            let active = cfg.ytm.profile_name();
+            let marker = |name: &str| if name == active { "*" } else { " " };
+            println!("{} default", marker("default"));
+            for p in &cfg.ytm.profiles {
+                println!("{} {}", marker(&p.name), p.name);
+            }
+        }
         Command::Auth { method } => {
             let mut cfg = cfg;
             match method {
@@ -136,22 +248,9 @@ async fn main() -> anyhow::Result<()> {
                     cfg.ytm.cookies = None;
                 }
                 AuthCommand::Zen => {
-                    let base_dirs = directories::BaseDirs::new().context("BaseDirs unavailable")?;
-                    let base = base_dirs
-                        .home_dir()
-                        .join("Library")
-                        .join("Application Support")
-                        .join("zen");
-                    // Try to auto-detect the default profile from profiles.ini.
-                    let ini_path = base.join("profiles.ini");
-                    let raw = std::fs::read_to_string(&ini_path)
-                        .with_context(|| format!("read {}", ini_path.display()))?;
-                    let default_rel = raw
-                        .lines()
-                        .find_map(|l| l.strip_prefix("Default="))
-                        .map(|s| s.trim().to_string())
-                        .context("no Default=... in zen profiles.ini")?;
-                    let profile_dir = base.join(default_rel);
+                    // Zen is Firefox-based; yt-dlp takes the profile as "firefox:{path}".
+                    let profile_dir = ytm::auth::browser::find_profile("zen")
+                        .context("detect zen profile")?;
                     cfg.ytm.cookies_from_browser =
                         Some(format!("firefox:{}", profile_dir.display()));
                     cfg.ytm.cookies = None;
@@ -164,6 +263,40 @@ async fn main() -> anyhow::Result<()> {
                     cfg.ytm.cookies = None;
                     cfg.ytm.cookies_from_browser = None;
                 }
+                AuthCommand::Profile {
+                    name,
+                    browser,
+                    cookies,
+                    auth_user,
+                    page_id,
+                } => {
+                    if name == "default" {
+                        anyhow::bail!("\"default\" is reserved for the top-level auth settings");
+                    }
+                    let profile = config::AuthProfile {
+                        name: name.clone(),
+                        cookies,
+                        cookies_from_browser: browser,
+                        auth_user,
+                        on_behalf_of_user: page_id,
+                    };
+                    match cfg.ytm.profiles.iter_mut().find(|p| p.name == name) {
+                        Some(p) => *p = profile,
+                        None => cfg.ytm.profiles.push(profile),
+                    }
+                }
+                AuthCommand::Use { name } => {
+                    if name == "default" {
+                        cfg.ytm.active_profile = None;
+                    } else if cfg.ytm.profiles.iter().any(|p| p.name == name) {
+                        cfg.ytm.active_profile = Some(name);
+                    } else {
+                        anyhow::bail!("no auth profile named {name}; see `void auth profiles`");
+                    }
+                }
+                AuthCommand::Status | AuthCommand::Channels | AuthCommand::Profiles => {
+                    unreachable!("handled above")
+                }
             }
             config::save(&cfg, cli.config.as_deref()).context("save config")?;
             println!("Updated config auth settings.");
@@ -197,12 +330,120 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn make_client(cfg: &config::Config) -> anyhow::Result<ytm::api::YtmClient> {
-    let auth = match cfg.ytm.cookies.as_deref() {
-        Some(p) if p.exists() => Some(ytm::auth::load_netscape_cookies(p)?),
-        _ => None,
+async fn make_client(
+    cfg: &config::Config,
+    overrides: &config::ClientOverrides,
+) -> anyhow::Result<ytm::api::YtmClient> {
+    let (cookies, browser) = cfg.ytm.cookie_sources();
+    let auth = ytm::auth::load_auth(cookies, browser)?;
+    ytm::api::YtmClient::new(auth, cfg.client_options(overrides))
+}
+
+/// A track from a video id, or the top search result for anything else
+async fn resolve_track(
+    ytm: &ytm::api::YtmClient,
+    query: &str,
+) -> anyhow::Result<ytm::models::Track> {
+    let is_video_id = query.len() == 11
+        && query.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if is_video_id {
+        // The radio seeded from a video starts with the video itself
+        let tracks = ytm.get_radio_tracks(query).await.context("look up video")?;
+        if let Some(track) = tracks.into_iter().find(|t| t.video_id == query) {
+            return Ok(track);
+        }
+    }
+    ytm.search_tracks(query)
+        .await?
+        .into_iter()
+        .next()
+        .with_context(|| format!("no track matches {query:?}"))
+}
+
+/// Cached lyrics for `track`, else the first provider's, which are cached for the TUI
+async fn load_lyrics(
+    cfg: &config::Config,
+    ytm: &ytm::api::YtmClient,
+    storage: &storage::Storage,
+    track: &ytm::models::Track,
+) -> anyhow::Result<Option<lyrics::ParsedLyrics>> {
+    if let Some((content, synced, provider)) = storage.get_lyrics(&track.video_id)? {
+        let mut lyrics = lyrics::ParsedLyrics::parse(&content, synced);
+        lyrics.source = provider;
+        return Ok(Some(lyrics));
+    }
+
+    // A pinned LRCLIB entry beats asking the providers
+    if let Some(id) = storage.get_pinned_lyrics(&track.video_id)?
+        && let Some(found) = lyrics::LrclibClient::new().get_by_id(id).await?
+        && let Some(lyrics) = found.to_lyrics()
+    {
+        return Ok(Some(lyrics));
+    }
+
+    let providers = lyrics::build_providers(&cfg.lyrics, ytm);
+    let Some(lyrics) = lyrics::fetch_lyrics(&providers, track).await? else {
+        return Ok(None);
     };
-    ytm::api::YtmClient::new(auth)
+    let now = ytm::auth::unix_now();
+    storage.cache_lyrics(
+        &track.video_id,
+        &lyrics.to_lrc(),
+        lyrics.synced,
+        lyrics.source.as_deref(),
+        now,
+    )?;
+    Ok(Some(lyrics))
+}
+
+async fn print_auth_status(cfg: &config::Config, overrides: &config::ClientOverrides) -> anyhow::Result<()> {
+    let (cookies, browser) = cfg.ytm.cookie_sources();
+    let auth = match ytm::auth::load_auth(cookies, browser) {
+        Ok(Some(auth)) => auth,
+        Ok(None) => {
+            println!("Source:   none (not configured)");
+            println!("Run `void auth browser <name>` or `void auth file <path>` to sign in.");
+            return Ok(());
+        }
+        Err(e) => {
+            println!("Source:   error: {e:#}");
+            return Ok(());
+        }
+    };
+
+    println!("Profile:  {}", cfg.ytm.profile_name());
+    println!("Source:   {}", auth.source);
+    println!(
+        "SAPISID:  {}",
+        if auth.has_sapisid() { "present" } else { "missing (requests will not be signed)" }
+    );
+
+    let now = ytm::auth::unix_now();
+    match auth.login_expiry() {
+        Some(t) if t <= now => {
+            println!("Expires:  expired {}", ytm::auth::describe_expiry(t, now));
+        }
+        Some(t) => {
+            let warn = if t - now < ytm::auth::EXPIRY_WARNING_SECS {
+                " (sign in again soon)"
+            } else {
+                ""
+            };
+            println!("Expires:  {}{warn}", ytm::auth::describe_expiry(t, now));
+        }
+        None => println!("Expires:  session only"),
+    }
+
+    let ytm = ytm::api::YtmClient::new(Some(auth), cfg.client_options(overrides))?;
+    match ytm.get_account_info().await {
+        Ok(Some(info)) => match info.handle {
+            Some(handle) => println!("Account:  {} ({handle})", info.name),
+            None => println!("Account:  {}", info.name),
+        },
+        Ok(None) => println!("Account:  not signed in (YouTube rejected the cookies)"),
+        Err(e) => println!("Account:  check failed: {e:#}"),
+    }
+    Ok(())
 }
 
 fn print_tracks(tracks: &[ytm::models::Track]) {
@@ -221,3 +462,26 @@ fn print_tracks(tracks: &[ytm::models::Track]) {
         );
     }
 }
+
+fn print_shelves(shelves: &[ytm::models::Shelf]) {
+    use ytm::models::ShelfItem;
+    for shelf in shelves {
+        println!("== {} ==", shelf.title);
+        for (i, item) in shelf.items.iter().enumerate() {
+            let subtitle = item.subtitle();
+            let subtitle = if subtitle.is_empty() {
+                "".to_string()
+            } else {
+                format!(" — {}", subtitle)
+            };
+            let id = match item {
+                ShelfItem::Track(t) => format!("video_id={}", t.video_id),
+                ShelfItem::Playlist(p) => format!("playlist_id={}", p.id),
+                ShelfItem::Album(p) => format!("album_id={}", p.id),
+                ShelfItem::Artist(p) => format!("artist_id={}", p.id),
+            };
+            println!("{:02}. {}{}  ({})", i + 1, item.title(), subtitle, id);
+        }
+        println!();
+    }
+}
@@ -92,11 +92,21 @@ impl MpvHandle {
         self.command(json!({"command":["cycle", "pause"]})).await
     }
 
+    pub async fn set_pause(&self, paused: bool) -> anyhow::Result<()> {
+        self.command(json!({"command":["set_property", "pause", paused]}))
+            .await
+    }
+
     pub async fn seek_relative(&self, seconds: f64) -> anyhow::Result<()> {
         self.command(json!({"command":["seek", seconds, "relative"]}))
             .await
     }
 
+    pub async fn seek_absolute(&self, seconds: f64) -> anyhow::Result<()> {
+        self.command(json!({"command":["seek", seconds, "absolute"]}))
+            .await
+    }
+
     pub async fn set_volume(&self, volume_0_100: u8) -> anyhow::Result<()> {
         self.command(json!({"command":["set_property", "volume", volume_0_100]}))
             .await
@@ -0,0 +1,138 @@
+//! Undo/redo log for queue edits
+//!
+//! Each edit keeps the change to the track list, which can be inverted, plus the
+//! current index and shuffle order before and after it.
+
+use crate::ytm::models::Track;
+use std::collections::VecDeque;
+
+/// Edits kept for undo; older ones are forgotten
+pub const UNDO_LIMIT: usize = 100;
+
+/// A change to the track list
+#[derive(Debug, Clone)]
+pub enum Change {
+    /// `up_next` puts the tracks in the up-next segment
+    Insert { index: usize, tracks: Vec<Track>, up_next: bool },
+    Remove { index: usize, tracks: Vec<Track>, up_next: bool },
+    Move { from: usize, to: usize },
+    Replace { old: Vec<Track>, new: Vec<Track> },
+    /// Shuffle toggled; the track list is untouched
+    Shuffle,
+    /// Several changes made as one, in order
+    Batch(Vec<Change>),
+}
+
+impl Change {
+    /// The change that takes the track list back
+    pub fn inverse(&self) -> Change {
+        match self {
+            Change::Insert { index, tracks, up_next } => Change::Remove {
+                index: *index,
+                tracks: tracks.clone(),
+                up_next: *up_next,
+            },
+            Change::Remove { index, tracks, up_next } => Change::Insert {
+                index: *index,
+                tracks: tracks.clone(),
+                up_next: *up_next,
+            },
+            Change::Move { from, to } => Change::Move { from: *to, to: *from },
+            Change::Replace { old, new } => Change::Replace {
+                old: new.clone(),
+                new: old.clone(),
+            },
+            Change::Shuffle => Change::Shuffle,
+            Change::Batch(changes) => Change::Batch(changes.iter().rev().map(Change::inverse).collect()),
+        }
+    }
+}
+
+/// Playback position and shuffle state around an edit
+#[derive(Debug, Clone, Default)]
+pub struct Cursor {
+    pub current_index: Option<usize>,
+    pub up_next: usize,
+    pub shuffle_enabled: bool,
+    pub shuffle_order: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Edit {
+    pub change: Change,
+    pub before: Cursor,
+    pub after: Cursor,
+}
+
+impl Edit {
+    /// What the edit did, for "Undid: ..." messages
+    pub fn describe(&self) -> String {
+        let track_label = |tracks: &[Track], verb: &str| match tracks {
+            [one] => format!("{verb} \"{}\"", one.title),
+            many => format!("{verb} {} tracks", many.len()),
+        };
+        match &self.change {
+            Change::Insert { tracks, up_next: true, .. } => track_label(tracks, "play next"),
+            Change::Insert { tracks, .. } => track_label(tracks, "add"),
+            Change::Remove { tracks, .. } => track_label(tracks, "remove"),
+            Change::Move { .. } => "move track".to_string(),
+            Change::Replace { new, .. } if new.is_empty() => "clear queue".to_string(),
+            Change::Replace { .. } => "replace queue".to_string(),
+            Change::Shuffle if self.after.shuffle_enabled => "shuffle on".to_string(),
+            Change::Shuffle => "shuffle off".to_string(),
+            Change::Batch(changes) if changes.iter().all(|c| matches!(c, Change::Remove { .. })) => {
+                let tracks: Vec<Track> = changes
+                    .iter()
+                    .flat_map(|c| match c {
+                        Change::Remove { tracks, .. } => tracks.clone(),
+                        _ => Vec::new(),
+                    })
+                    .collect();
+                track_label(&tracks, "remove")
+            }
+            Change::Batch(_) => "move tracks".to_string(),
+        }
+    }
+}
+
+/// Bounded undo and redo stacks
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Edit>,
+    redo: Vec<Edit>,
+}
+
+impl History {
+    /// Record a fresh edit, which makes anything undone unreachable
+    pub fn record(&mut self, edit: Edit) {
+        self.redo.clear();
+        self.undo.push_back(edit);
+        if self.undo.len() > UNDO_LIMIT {
+            self.undo.pop_front();
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn pop_undo(&mut self) -> Option<Edit> {
+        self.undo.pop_back()
+    }
+
+    pub fn push_redo(&mut self, edit: Edit) {
+        self.redo.push(edit);
+    }
+
+    pub fn pop_redo(&mut self) -> Option<Edit> {
+        self.redo.pop()
+    }
+
+    /// Put back an edit that was redone, keeping the redo stack
+    pub fn push_undo(&mut self, edit: Edit) {
+        self.undo.push_back(edit);
+        if self.undo.len() > UNDO_LIMIT {
+            self.undo.pop_front();
+        }
+    }
+}
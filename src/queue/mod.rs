@@ -1,12 +1,25 @@
+mod history;
+mod shuffle;
+
 use crate::ytm::models::Track;
-use rand::seq::SliceRandom;
+use history::{Change, Cursor, Edit, History};
 
+/// The play queue. Tracks picked with "play next" form an up-next segment right
+/// after the current track, which plays before the rest of the queue, shuffled or not.
+///
+/// While shuffling, the part of the shuffle order up to the current track is what
+/// has played and stays put; edits only touch the part still to come.
 #[derive(Debug, Clone, Default)]
 pub struct Queue {
     tracks: Vec<Track>,
     current_index: Option<usize>,
     shuffle_enabled: bool,
     shuffle_order: Vec<usize>,
+    /// Keep tracks by the same artist or from the same album apart when shuffling
+    smart_shuffle: bool,
+    /// Length of the up-next segment, which starts at `up_next_start`
+    up_next: usize,
+    history: History,
 }
 
 impl Queue {
@@ -16,54 +29,90 @@ impl Queue {
 
     /// Add a single track to the end of the queue
     pub fn add(&mut self, track: Track) {
-        self.tracks.push(track);
-        self.rebuild_shuffle_order();
+        self.add_many(vec![track]);
     }
 
     /// Add multiple tracks to the end of the queue
     pub fn add_many(&mut self, tracks: Vec<Track>) {
-        self.tracks.extend(tracks);
-        self.rebuild_shuffle_order();
+        if tracks.is_empty() {
+            return;
+        }
+        let index = self.tracks.len();
+        self.edit(Change::Insert {
+            index,
+            tracks,
+            up_next: false,
+        });
+    }
+
+    /// Queue a track to play after the current one and anything already picked to play next
+    #[allow(dead_code)]
+    pub fn play_next(&mut self, track: Track) {
+        self.play_next_many(vec![track]);
+    }
+
+    /// Queue tracks to play next, in the order given
+    pub fn play_next_many(&mut self, tracks: Vec<Track>) {
+        if tracks.is_empty() {
+            return;
+        }
+        let index = self.up_next_start() + self.up_next;
+        self.edit(Change::Insert {
+            index,
+            tracks,
+            up_next: true,
+        });
     }
 
     /// Replace the entire queue with new tracks and start playing from the beginning
     pub fn replace(&mut self, tracks: Vec<Track>) {
-        self.tracks = tracks;
-        self.current_index = if self.tracks.is_empty() { None } else { Some(0) };
-        self.rebuild_shuffle_order();
+        let old = self.tracks.clone();
+        self.edit(Change::Replace { old, new: tracks });
     }
 
     /// Remove a track at the given index
     pub fn remove(&mut self, index: usize) -> Option<Track> {
-        if index >= self.tracks.len() {
-            return None;
-        }
-
-        let track = self.tracks.remove(index);
+        let track = self.tracks.get(index)?.clone();
+        let up_next = self.up_next_range().contains(&index);
+        self.edit(Change::Remove {
+            index,
+            tracks: vec![track.clone()],
+            up_next,
+        });
+        Some(track)
+    }
 
-        // Adjust current_index if needed
-        if let Some(current) = self.current_index {
-            if index < current {
-                self.current_index = Some(current - 1);
-            } else if index == current {
-                // Current track was removed
-                if self.tracks.is_empty() {
-                    self.current_index = None;
-                } else if current >= self.tracks.len() {
-                    self.current_index = Some(self.tracks.len() - 1);
-                }
-            }
+    /// Remove the tracks at `indices` as a single edit. Returns how many were removed.
+    pub fn remove_many(&mut self, indices: &[usize]) -> usize {
+        let segment = self.up_next_range();
+        let mut indices: Vec<usize> =
+            indices.iter().copied().filter(|&i| i < self.tracks.len()).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        // From the back, so each index still points at its track
+        let changes: Vec<Change> = indices
+            .iter()
+            .rev()
+            .map(|&index| Change::Remove {
+                index,
+                tracks: vec![self.tracks[index].clone()],
+                up_next: segment.contains(&index),
+            })
+            .collect();
+        match changes.len() {
+            0 => {}
+            1 => self.edit(changes.into_iter().next().unwrap()),
+            _ => self.edit(Change::Batch(changes)),
         }
-
-        self.rebuild_shuffle_order();
-        Some(track)
+        indices.len()
     }
 
     /// Clear the entire queue
     pub fn clear(&mut self) {
-        self.tracks.clear();
-        self.current_index = None;
-        self.shuffle_order.clear();
+        if self.tracks.is_empty() {
+            return;
+        }
+        self.replace(Vec::new());
     }
 
     /// Move a track from one position to another
@@ -71,41 +120,367 @@ impl Queue {
         if from >= self.tracks.len() || to >= self.tracks.len() || from == to {
             return;
         }
+        self.edit(Change::Move { from, to });
+    }
 
-        let track = self.tracks.remove(from);
-        self.tracks.insert(to, track);
-
-        // Adjust current_index if needed
-        if let Some(current) = self.current_index {
-            if from == current {
-                self.current_index = Some(to);
-            } else if from < current && to >= current {
-                self.current_index = Some(current - 1);
-            } else if from > current && to <= current {
-                self.current_index = Some(current + 1);
+    /// Move the tracks at `indices` one row up or down as a single edit, closing
+    /// up against the top or bottom. Returns where the tracks ended up.
+    pub fn move_many(&mut self, indices: &[usize], up: bool) -> Vec<usize> {
+        let len = self.tracks.len();
+        let mut marked = vec![false; len];
+        for &i in indices.iter().filter(|&&i| i < len) {
+            marked[i] = true;
+        }
+        let mut changes = Vec::new();
+        let mut step = |from: usize, to: usize, marked: &mut Vec<bool>| {
+            if marked[from] && !marked[to] {
+                changes.push(Change::Move { from, to });
+                marked.swap(from, to);
+            }
+        };
+        if up {
+            for i in 1..len {
+                step(i, i - 1, &mut marked);
+            }
+        } else {
+            for i in (0..len.saturating_sub(1)).rev() {
+                step(i, i + 1, &mut marked);
             }
         }
-
-        self.rebuild_shuffle_order();
+        match changes.len() {
+            0 => {}
+            1 => self.edit(changes.into_iter().next().unwrap()),
+            _ => self.edit(Change::Batch(changes)),
+        }
+        (0..len).filter(|&i| marked[i]).collect()
     }
 
     /// Toggle shuffle mode
     pub fn toggle_shuffle(&mut self) {
-        self.shuffle_enabled = !self.shuffle_enabled;
+        self.edit(Change::Shuffle);
+    }
+
+    /// Whether there is an edit to undo
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Undo the latest edit. Returns what it did, or `None` when there is nothing to undo.
+    pub fn undo(&mut self) -> Option<String> {
+        let edit = self.history.pop_undo()?;
+        self.revisit(&edit.change.inverse(), &edit.after, &edit.before);
+        let label = edit.describe();
+        self.history.push_redo(edit);
+        Some(label)
+    }
+
+    /// Redo the latest undone edit. Returns what it did, or `None` when there is nothing to redo.
+    pub fn redo(&mut self) -> Option<String> {
+        let edit = self.history.pop_redo()?;
+        self.revisit(&edit.change, &edit.before, &edit.after);
+        let label = edit.describe();
+        self.history.push_undo(edit);
+        Some(label)
+    }
+
+    /// Use smart shuffle from now on; the tracks still to play are reshuffled
+    pub fn set_smart_shuffle(&mut self, smart: bool) {
+        if self.smart_shuffle == smart {
+            return;
+        }
+        self.smart_shuffle = smart;
         if self.shuffle_enabled {
+            let rest = self.shuffle_order.split_off(self.played_len());
+            let after = self.shuffle_order.last().copied();
+            let rest = shuffle::shuffled(&self.tracks, rest, after, smart);
+            self.shuffle_order.extend(rest);
+        }
+    }
+
+    /// Whether smart shuffle is on
+    pub fn is_smart_shuffle(&self) -> bool {
+        self.smart_shuffle
+    }
+
+    /// Apply `change` as a new edit. Only toggling shuffle or replacing the whole
+    /// queue deals a new shuffle order; `apply` keeps it up to date otherwise.
+    fn edit(&mut self, change: Change) {
+        let before = self.cursor();
+        self.apply(&change);
+        match change {
+            Change::Shuffle => {
+                self.shuffle_enabled = !self.shuffle_enabled;
+                self.rebuild_shuffle_order();
+            }
+            Change::Replace { .. } => self.rebuild_shuffle_order(),
+            _ => {}
+        }
+        let after = self.cursor();
+        self.history.record(Edit { change, before, after });
+    }
+
+    /// Replay `change` while undoing or redoing, going from cursor `from` to `to`
+    fn revisit(&mut self, change: &Change, from: &Cursor, to: &Cursor) {
+        // Playback that moved on since the edit keeps its track; otherwise the
+        // cursor goes back exactly to where it was
+        let untouched = self.current_index == from.current_index;
+        self.apply(change);
+        if untouched || matches!(change, Change::Replace { .. }) {
+            self.current_index = to.current_index.filter(|&i| i < self.tracks.len());
+            self.up_next = to.up_next.min(self.tracks.len().saturating_sub(self.up_next_start()));
+        }
+
+        self.shuffle_enabled = to.shuffle_enabled;
+        let mut sorted = to.shuffle_order.clone();
+        sorted.sort_unstable();
+        if sorted.iter().copied().eq(0..self.tracks.len()) {
+            self.shuffle_order = to.shuffle_order.clone();
+        } else {
             self.rebuild_shuffle_order();
         }
     }
 
+    /// Change the track list, keeping `current_index` on the same track, the
+    /// up-next segment right after it and, while shuffling, the played part of the
+    /// shuffle order as it was
+    fn apply(&mut self, change: &Change) {
+        let played = self.played_len();
+        match change {
+            Change::Insert {
+                index,
+                tracks,
+                up_next,
+            } => {
+                let index = (*index).min(self.tracks.len());
+                let added = tracks.len();
+                let segment = self.up_next_range();
+                // Landing inside the segment joins it
+                if *up_next || (segment.start..segment.end).contains(&index) {
+                    self.up_next += tracks.len();
+                }
+                self.tracks.splice(index..index, tracks.iter().cloned());
+                if let Some(current) = self.current_index
+                    && current >= index
+                {
+                    self.current_index = Some(current + added);
+                }
+                if self.shuffle_enabled {
+                    // New tracks go somewhere among the ones still to play
+                    for i in &mut self.shuffle_order {
+                        if *i >= index {
+                            *i += added;
+                        }
+                    }
+                    for new in index..index + added {
+                        shuffle::insert_randomly(
+                            &self.tracks,
+                            &mut self.shuffle_order,
+                            played,
+                            new,
+                            self.smart_shuffle,
+                        );
+                    }
+                }
+            }
+            Change::Remove { index, tracks, .. } => {
+                let end = (index + tracks.len()).min(self.tracks.len());
+                let segment = self.up_next_range();
+                let overlap = end.min(segment.end).saturating_sub((*index).max(segment.start));
+                self.up_next -= overlap;
+                self.tracks.drain(*index..end);
+                let removed = end - index;
+                let had_up_next = self.up_next > 0;
+                let mut takeover = false;
+                if let Some(current) = self.current_index {
+                    if current >= end {
+                        self.current_index = Some(current - removed);
+                    } else if current >= *index {
+                        // The current track was removed; the one after it takes over,
+                        // which is the first of up next if there is one
+                        self.current_index = if self.tracks.is_empty() {
+                            None
+                        } else {
+                            Some((*index).min(self.tracks.len() - 1))
+                        };
+                        self.up_next = self.up_next.saturating_sub(1);
+                        takeover = true;
+                    }
+                }
+                if self.shuffle_enabled {
+                    let kept = |i: usize| i < *index || i >= end;
+                    let still_played =
+                        self.shuffle_order[..played].iter().filter(|&&i| kept(i)).count();
+                    self.shuffle_order.retain(|&i| kept(i));
+                    for i in &mut self.shuffle_order {
+                        if *i >= end {
+                            *i -= removed;
+                        }
+                    }
+                    // Up next takes over in its own order; otherwise the shuffle order
+                    // says which track does, the one before if none is left to play
+                    if takeover && let Some(current) = self.current_index {
+                        if had_up_next {
+                            self.move_in_shuffle_order(current, still_played);
+                        } else {
+                            let next = self.shuffle_order.get(still_played).or_else(|| {
+                                self.shuffle_order.get(still_played.checked_sub(1)?)
+                            });
+                            self.current_index = next.copied();
+                        }
+                    }
+                }
+            }
+            Change::Move { from, to } => {
+                let (from, to) = (*from, *to);
+                // Follow the segment by marking its tracks; a track dropped between
+                // two of them joins it
+                let segment = self.up_next_range();
+                let mut marked: Vec<bool> =
+                    (0..self.tracks.len()).map(|i| segment.contains(&i)).collect();
+                let track = self.tracks.remove(from);
+                marked.remove(from);
+                self.tracks.insert(to, track);
+                let joins = to > 0
+                    && marked.get(to).copied().unwrap_or(false)
+                    && (marked[to - 1] || self.current_index == Some(to - 1));
+                marked.insert(to, joins);
+
+                let moved = |i: usize| {
+                    if i == from {
+                        to
+                    } else if from < i && i <= to {
+                        i - 1
+                    } else if to <= i && i < from {
+                        i + 1
+                    } else {
+                        i
+                    }
+                };
+                self.current_index = self.current_index.map(moved);
+                for i in &mut self.shuffle_order {
+                    *i = moved(*i);
+                }
+                let start = self.up_next_start();
+                self.up_next = marked.iter().skip(start).take_while(|&&m| m).count();
+            }
+            Change::Replace { new, .. } => {
+                self.tracks = new.clone();
+                self.current_index = if self.tracks.is_empty() { None } else { Some(0) };
+                self.up_next = 0;
+            }
+            Change::Shuffle => {}
+            Change::Batch(changes) => {
+                for change in changes {
+                    self.apply(change);
+                }
+            }
+        }
+    }
+
+    fn cursor(&self) -> Cursor {
+        Cursor {
+            current_index: self.current_index,
+            up_next: self.up_next,
+            shuffle_enabled: self.shuffle_enabled,
+            shuffle_order: self.shuffle_order.clone(),
+        }
+    }
+
+    /// Index where the up-next segment starts: right after the current track
+    fn up_next_start(&self) -> usize {
+        self.current_index.map_or(0, |c| c + 1)
+    }
+
+    /// Indices of the up-next segment
+    pub fn up_next_range(&self) -> std::ops::Range<usize> {
+        let start = self.up_next_start();
+        start..start + self.up_next
+    }
+
     /// Get shuffle state
     pub fn is_shuffle_enabled(&self) -> bool {
         self.shuffle_enabled
     }
 
-    /// Set the current playing index
+    /// Play order of track indices while shuffling; empty otherwise
+    pub fn shuffle_order(&self) -> &[usize] {
+        &self.shuffle_order
+    }
+
+    /// Rebuild a queue saved from `tracks`, `current_index`, `up_next_range().len()`,
+    /// `is_shuffle_enabled` and `shuffle_order`. Anything that no longer fits the
+    /// tracks is dropped or reshuffled.
+    pub fn restore(
+        tracks: Vec<Track>,
+        current_index: Option<usize>,
+        up_next: usize,
+        shuffle_enabled: bool,
+        shuffle_order: Vec<usize>,
+    ) -> Self {
+        let mut queue = Self {
+            current_index: current_index.filter(|&i| i < tracks.len()),
+            tracks,
+            shuffle_enabled,
+            shuffle_order,
+            smart_shuffle: false,
+            up_next: 0,
+            history: History::default(),
+        };
+        queue.up_next = up_next.min(queue.tracks.len().saturating_sub(queue.up_next_start()));
+        let mut sorted = queue.shuffle_order.clone();
+        sorted.sort_unstable();
+        if !shuffle_enabled || !sorted.iter().copied().eq(0..queue.tracks.len()) {
+            queue.rebuild_shuffle_order();
+        }
+        queue
+    }
+
+    /// Set the current playing index. Up next stays up next: picking one of its
+    /// tracks skips those before it, and picking any other track takes the
+    /// segment along to follow it. While shuffling, a track that has not played
+    /// yet plays next in the shuffle order; one that has is gone back to.
     pub fn set_current(&mut self, index: usize) {
-        if index < self.tracks.len() {
+        if index >= self.tracks.len() {
+            return;
+        }
+        if self.shuffle_enabled {
+            let played = self.played_len();
+            if self.shuffle_order[played..].contains(&index) {
+                self.move_in_shuffle_order(index, played);
+            }
+        }
+        let segment = self.up_next_range();
+        if segment.contains(&index) {
+            self.up_next = segment.end - index - 1;
             self.current_index = Some(index);
+            return;
+        }
+        if self.up_next == 0 || self.current_index == Some(index) {
+            self.current_index = Some(index);
+            return;
+        }
+
+        // New order of old indices: the segment moved to just after `index`
+        let mut order: Vec<usize> =
+            (0..self.tracks.len()).filter(|i| !segment.contains(i)).collect();
+        let pos = order.iter().position(|&i| i == index).unwrap_or(0);
+        order.splice(pos + 1..pos + 1, segment);
+        self.permute(&order);
+        self.current_index = Some(pos);
+    }
+
+    /// Reorder tracks so new index `i` holds the track from old index `order[i]`,
+    /// keeping the shuffle order on the same tracks
+    fn permute(&mut self, order: &[usize]) {
+        let mut new_index = vec![0; order.len()];
+        for (new, &old) in order.iter().enumerate() {
+            new_index[old] = new;
+        }
+        let mut old: Vec<Option<Track>> =
+            std::mem::take(&mut self.tracks).into_iter().map(Some).collect();
+        self.tracks = order.iter().filter_map(|&i| old[i].take()).collect();
+        for i in &mut self.shuffle_order {
+            *i = new_index[*i];
         }
     }
 
@@ -119,7 +494,7 @@ impl Queue {
         self.current_index
     }
 
-    /// Get the next track (respecting shuffle)
+    /// Get the next track (respecting up next and shuffle)
     #[allow(dead_code)]
     pub fn next_track(&self) -> Option<(usize, &Track)> {
         let current = self.current_index?;
@@ -137,8 +512,21 @@ impl Queue {
 
     /// Advance to the next track, returns the new current track
     pub fn advance(&mut self) -> Option<&Track> {
-        let current = self.current_index?;
+        // Nothing played from the queue yet: up next still goes first
+        let Some(current) = self.current_index else {
+            if self.up_next == 0 {
+                return None;
+            }
+            self.set_current(0);
+            return self.current_track();
+        };
         let next_index = self.next_index(current)?;
+        if self.up_next > 0 {
+            self.up_next -= 1;
+            // Shuffle carries on from here once up next has played
+            let played = self.played_len();
+            self.move_in_shuffle_order(next_index, played);
+        }
         self.current_index = Some(next_index);
         self.tracks.get(next_index)
     }
@@ -147,8 +535,8 @@ impl Queue {
     pub fn go_back(&mut self) -> Option<&Track> {
         let current = self.current_index?;
         let prev_index = self.prev_index(current)?;
-        self.current_index = Some(prev_index);
-        self.tracks.get(prev_index)
+        self.set_current(prev_index);
+        self.current_track()
     }
 
     /// Get all tracks in the queue
@@ -166,20 +554,36 @@ impl Queue {
         self.tracks.is_empty()
     }
 
+    /// Check whether a track is already in the queue
+    pub fn contains(&self, video_id: &str) -> bool {
+        self.tracks.iter().any(|t| t.video_id == video_id)
+    }
+
+    /// Number of tracks left to play after the current one (respecting up next and shuffle)
+    pub fn remaining(&self) -> usize {
+        let Some(current) = self.current_index else {
+            return self.tracks.len();
+        };
+        if self.shuffle_enabled && !self.shuffle_order.is_empty() {
+            let position = |track: usize| self.shuffle_order.iter().position(|&i| i == track);
+            let Some(pos) = position(current) else {
+                return 0;
+            };
+            // Up next plays first even where the shuffle order had already passed it
+            let up_next_behind = self
+                .up_next_range()
+                .filter(|&i| position(i).is_some_and(|p| p < pos))
+                .count();
+            self.shuffle_order.len() - pos - 1 + up_next_behind
+        } else {
+            self.tracks.len().saturating_sub(current + 1)
+        }
+    }
+
     /// Check if we're at the end of the queue
     #[allow(dead_code)]
     pub fn is_at_end(&self) -> bool {
-        match self.current_index {
-            Some(i) => {
-                if self.shuffle_enabled && !self.shuffle_order.is_empty() {
-                    self.shuffle_order.iter().position(|&x| x == i)
-                        == Some(self.shuffle_order.len() - 1)
-                } else {
-                    i >= self.tracks.len().saturating_sub(1)
-                }
-            }
-            None => true,
-        }
+        self.current_index.is_none() || self.remaining() == 0
     }
 
     /// Check if we're at the beginning of the queue
@@ -201,6 +605,9 @@ impl Queue {
         if self.tracks.is_empty() {
             return None;
         }
+        if self.up_next > 0 {
+            return Some(current + 1);
+        }
 
         if self.shuffle_enabled && !self.shuffle_order.is_empty() {
             let pos = self.shuffle_order.iter().position(|&x| x == current)?;
@@ -239,21 +646,32 @@ impl Queue {
         }
     }
 
+    /// Deal a whole new shuffle order, starting from the current track
     fn rebuild_shuffle_order(&mut self) {
         if !self.shuffle_enabled || self.tracks.is_empty() {
             self.shuffle_order.clear();
             return;
         }
 
-        let mut rng = rand::rng();
-        self.shuffle_order = (0..self.tracks.len()).collect();
-        self.shuffle_order.shuffle(&mut rng);
+        let current = self.current_index;
+        let rest = (0..self.tracks.len()).filter(|&i| Some(i) != current).collect();
+        let rest = shuffle::shuffled(&self.tracks, rest, current, self.smart_shuffle);
+        self.shuffle_order = current.into_iter().chain(rest).collect();
+    }
 
-        // If we have a current track, make sure it's at the front of shuffle order
-        if let Some(current) = self.current_index {
-            if let Some(pos) = self.shuffle_order.iter().position(|&x| x == current) {
-                self.shuffle_order.swap(0, pos);
-            }
+    /// How much of the shuffle order has played: everything up to the current track
+    fn played_len(&self) -> usize {
+        self.current_index
+            .and_then(|c| self.shuffle_order.iter().position(|&i| i == c))
+            .map_or(0, |p| p + 1)
+    }
+
+    /// Move `track` in the shuffle order to just before what is now at position `at`
+    fn move_in_shuffle_order(&mut self, track: usize, at: usize) {
+        if let Some(from) = self.shuffle_order.iter().position(|&i| i == track) {
+            self.shuffle_order.remove(from);
+            let at = if from < at { at - 1 } else { at };
+            self.shuffle_order.insert(at.min(self.shuffle_order.len()), track);
         }
     }
 }
@@ -261,14 +679,15 @@ impl Queue {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     fn make_track(id: &str) -> Track {
         Track {
             video_id: id.to_string(),
             title: format!("Track {}", id),
             artists: vec!["Artist".to_string()],
-            album: None,
             duration_seconds: Some(180),
+            ..Track::default()
         }
     }
 
@@ -318,6 +737,175 @@ mod tests {
         assert_eq!(queue.current_track().unwrap().video_id, "2");
     }
 
+    #[test]
+    fn test_remaining() {
+        let mut queue = Queue::new();
+        assert_eq!(queue.remaining(), 0);
+        queue.replace(vec![make_track("1"), make_track("2"), make_track("3")]);
+        assert_eq!(queue.remaining(), 2);
+        queue.advance();
+        queue.advance();
+        assert_eq!(queue.remaining(), 0);
+        assert!(queue.contains("2"));
+        assert!(!queue.contains("4"));
+    }
+
+    #[test]
+    fn test_restore() {
+        let tracks = vec![make_track("1"), make_track("2"), make_track("3")];
+        let mut queue = Queue::restore(tracks.clone(), Some(2), 0, true, vec![2, 0, 1]);
+        assert_eq!(queue.current_track().unwrap().video_id, "3");
+        assert_eq!(queue.shuffle_order(), &[2, 0, 1]);
+        assert_eq!(queue.advance().unwrap().video_id, "1");
+
+        // A stale order is replaced and a stale index dropped
+        let queue = Queue::restore(tracks, Some(5), 3, true, vec![0, 7]);
+        assert!(queue.current_index().is_none());
+        let mut order = queue.shuffle_order().to_vec();
+        order.sort_unstable();
+        assert_eq!(order, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut queue = Queue::new();
+        queue.replace(vec![make_track("1"), make_track("2"), make_track("3")]);
+        queue.set_current(1);
+
+        queue.remove(1);
+        assert_eq!(queue.current_track().unwrap().video_id, "3");
+        assert_eq!(queue.undo().as_deref(), Some("remove \"Track 2\""));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.current_track().unwrap().video_id, "2");
+
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.undo().as_deref(), Some("clear queue"));
+        assert_eq!(queue.current_track().unwrap().video_id, "2");
+        assert_eq!(queue.redo().as_deref(), Some("clear queue"));
+        assert!(queue.current_index().is_none());
+        queue.undo();
+
+        // Playback that moved on keeps its track through an undo
+        queue.move_track(0, 2);
+        queue.advance();
+        assert_eq!(queue.current_track().unwrap().video_id, "3");
+        queue.undo();
+        assert_eq!(queue.current_track().unwrap().video_id, "3");
+        assert_eq!(queue.tracks()[0].video_id, "1");
+
+        queue.toggle_shuffle();
+        let order = queue.shuffle_order().to_vec();
+        queue.undo();
+        assert!(!queue.is_shuffle_enabled());
+        queue.redo();
+        assert!(queue.is_shuffle_enabled());
+        assert_eq!(queue.shuffle_order(), order.as_slice());
+
+        // A new edit drops what was undone
+        queue.undo();
+        queue.add(make_track("4"));
+        assert!(queue.redo().is_none());
+    }
+
+    #[test]
+    fn test_undo_is_bounded() {
+        let mut queue = Queue::new();
+        for i in 0..history::UNDO_LIMIT + 5 {
+            queue.add(make_track(&i.to_string()));
+        }
+        let mut undone = 0;
+        while queue.undo().is_some() {
+            undone += 1;
+        }
+        assert_eq!(undone, history::UNDO_LIMIT);
+        assert_eq!(queue.len(), 5);
+    }
+
+    fn ids(queue: &Queue) -> Vec<&str> {
+        queue.tracks().iter().map(|t| t.video_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_play_next() {
+        let mut queue = Queue::new();
+        queue.replace(vec![make_track("1"), make_track("2"), make_track("3")]);
+        queue.play_next(make_track("a"));
+        queue.play_next(make_track("b"));
+        assert_eq!(ids(&queue), ["1", "a", "b", "2", "3"]);
+        assert_eq!(queue.up_next_range(), 1..3);
+
+        queue.advance();
+        assert_eq!(queue.current_track().unwrap().video_id, "a");
+        assert_eq!(queue.up_next_range(), 2..3);
+
+        // Jumping elsewhere takes up next along
+        queue.set_current(4);
+        assert_eq!(ids(&queue), ["1", "a", "2", "3", "b"]);
+        assert_eq!(queue.current_track().unwrap().video_id, "3");
+        assert_eq!(queue.advance().unwrap().video_id, "b");
+        assert!(queue.up_next_range().is_empty());
+
+        // Undoing a removal from up next puts the track back in it
+        queue.play_next(make_track("c"));
+        queue.remove(5);
+        assert!(queue.up_next_range().is_empty());
+        queue.undo();
+        assert_eq!(queue.up_next_range(), 5..6);
+    }
+
+    #[test]
+    fn test_play_next_under_shuffle() {
+        let mut queue = Queue::new();
+        queue.replace((1..=8).map(|i| make_track(&i.to_string())).collect());
+        queue.toggle_shuffle();
+        queue.play_next(make_track("a"));
+        queue.play_next(make_track("b"));
+
+        let mut played = vec![queue.current_track().unwrap().video_id.clone()];
+        while let Some(track) = queue.advance() {
+            played.push(track.video_id.clone());
+        }
+        assert_eq!(&played[1..3], ["a", "b"]);
+        // Everything plays exactly once
+        played.sort();
+        let mut all: Vec<String> = (1..=8).map(|i| i.to_string()).collect();
+        all.extend(["a".to_string(), "b".to_string()]);
+        all.sort();
+        assert_eq!(played, all);
+    }
+
+    #[test]
+    fn test_play_next_before_queue_starts() {
+        let mut queue = Queue::new();
+        queue.play_next(make_track("a"));
+        queue.add(make_track("1"));
+        assert!(queue.current_index().is_none());
+        assert_eq!(queue.advance().unwrap().video_id, "a");
+        assert_eq!(queue.advance().unwrap().video_id, "1");
+    }
+
+    #[test]
+    fn test_bulk_edits_undo_as_one() {
+        let mut queue = Queue::new();
+        queue.replace(["1", "2", "3", "4", "5"].map(make_track).to_vec());
+
+        assert_eq!(queue.move_many(&[1, 3], true), vec![0, 2]);
+        assert_eq!(ids(&queue), ["2", "1", "4", "3", "5"]);
+        // Against the top the block closes up
+        assert_eq!(queue.move_many(&[0, 2], true), vec![0, 1]);
+        assert_eq!(ids(&queue), ["2", "4", "1", "3", "5"]);
+
+        assert_eq!(queue.remove_many(&[4, 1, 2]), 3);
+        assert_eq!(ids(&queue), ["2", "3"]);
+        assert_eq!(queue.undo().as_deref(), Some("remove 3 tracks"));
+        assert_eq!(ids(&queue), ["2", "4", "1", "3", "5"]);
+        assert_eq!(queue.undo().as_deref(), Some("move track"));
+        assert_eq!(ids(&queue), ["2", "1", "4", "3", "5"]);
+        assert_eq!(queue.undo().as_deref(), Some("move tracks"));
+        assert_eq!(ids(&queue), ["1", "2", "3", "4", "5"]);
+    }
+
     #[test]
     fn test_clear() {
         let mut queue = Queue::new();
@@ -327,4 +915,76 @@ mod tests {
         assert!(queue.is_empty());
         assert!(queue.current_index().is_none());
     }
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Add,
+        Remove(usize),
+        Move(usize, usize),
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            Just(Op::Add),
+            any::<usize>().prop_map(Op::Remove),
+            (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Op::Move(a, b)),
+        ]
+    }
+
+    /// Ids of the shuffle order up to the current track
+    fn played(queue: &Queue) -> Vec<String> {
+        queue.shuffle_order()[..queue.played_len()]
+            .iter()
+            .map(|&i| queue.tracks()[i].video_id.clone())
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_shuffle_keeps_played_order(
+            len in 2usize..20,
+            plays in 0usize..20,
+            ops in proptest::collection::vec(op(), 0..20),
+        ) {
+            let mut queue = Queue::new();
+            queue.replace((0..len).map(|i| make_track(&i.to_string())).collect());
+            queue.toggle_shuffle();
+            for _ in 0..plays {
+                queue.advance();
+            }
+            let mut next_id = len;
+            for op in ops {
+                let mut expected = played(&queue);
+                match op {
+                    Op::Add => {
+                        queue.add(make_track(&next_id.to_string()));
+                        next_id += 1;
+                    }
+                    Op::Remove(i) => {
+                        let i = i % queue.len();
+                        if queue.current_index() == Some(i) || queue.len() == 1 {
+                            continue;
+                        }
+                        let id = queue.tracks()[i].video_id.clone();
+                        expected.retain(|e| *e != id);
+                        queue.remove(i);
+                    }
+                    Op::Move(a, b) => queue.move_track(a % queue.len(), b % queue.len()),
+                }
+                let mut sorted = queue.shuffle_order().to_vec();
+                sorted.sort_unstable();
+                prop_assert!(sorted.into_iter().eq(0..queue.len()));
+                prop_assert_eq!(played(&queue), expected);
+            }
+
+            // Going back retraces what played
+            let mut expected = played(&queue);
+            expected.reverse();
+            let mut retraced = vec![queue.current_track().unwrap().video_id.clone()];
+            while let Some(track) = queue.go_back() {
+                retraced.push(track.video_id.clone());
+            }
+            prop_assert_eq!(retraced, expected);
+        }
+    }
 }
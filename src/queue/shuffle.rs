@@ -0,0 +1,208 @@
+//! Shuffle orders. Plain shuffle is uniform; smart shuffle also keeps tracks by
+//! the same artist or from the same album apart wherever the mix allows it.
+
+use crate::ytm::models::Track;
+use rand::Rng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use std::collections::HashMap;
+
+/// Whether `a` and `b` should not play back to back under smart shuffle
+pub fn clashes(a: &Track, b: &Track) -> bool {
+    let same_artist = matches!(
+        (artist_key(a), artist_key(b)),
+        (Some(x), Some(y)) if x == y
+    );
+    let same_album = matches!(
+        (album_key(a), album_key(b)),
+        (Some(x), Some(y)) if x == y
+    );
+    same_artist || same_album
+}
+
+fn artist_key(track: &Track) -> Option<String> {
+    track.artists.first().map(|a| a.to_lowercase())
+}
+
+fn album_key(track: &Track) -> Option<&str> {
+    track.album_id.as_deref().or(track.album.as_deref())
+}
+
+/// A random play order of `items` (indices into `tracks`) to follow track `after`
+pub fn shuffled(tracks: &[Track], mut items: Vec<usize>, after: Option<usize>, smart: bool) -> Vec<usize> {
+    let mut rng = rand::rng();
+    items.shuffle(&mut rng);
+    if !smart {
+        return items;
+    }
+
+    // Build the order a track at a time from tracks grouped by artist. Any group
+    // but the last one played may come next, unless one holds so many of the
+    // remaining tracks that it has to come next to be spread out at all. Albums
+    // can span artists (compilations), so the same rule is applied to them too.
+    let key = |i: usize| artist_key(&tracks[i]).unwrap_or_else(|| format!("\0{i}"));
+    let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+    let mut albums: HashMap<&str, usize> = HashMap::new();
+    for &i in &items {
+        groups.entry(key(i)).or_default().push(i);
+        if let Some(album) = album_key(&tracks[i]) {
+            *albums.entry(album).or_default() += 1;
+        }
+    }
+
+    let mut order = Vec::with_capacity(items.len());
+    let mut prev = after;
+    while order.len() < items.len() {
+        let remaining = items.len() - order.len();
+        let prev_key = prev.map(key);
+        let open: Vec<&String> = groups
+            .iter()
+            .filter(|(k, v)| !v.is_empty() && Some(*k) != prev_key.as_ref())
+            .map(|(k, _)| k)
+            .collect();
+
+        // A group or album holding more than half of what is left must be drawn from now
+        let forced = open
+            .iter()
+            .copied()
+            .find(|k| groups[*k].len() * 2 > remaining);
+        let prev_album = prev.and_then(|p| album_key(&tracks[p]));
+        let forced_album = albums
+            .iter()
+            .find(|(a, n)| **n * 2 > remaining && Some(**a) != prev_album)
+            .map(|(a, _)| *a);
+        let fits = |i: usize| match forced_album {
+            Some(_) => album_key(&tracks[i]) == forced_album,
+            None => prev.is_none_or(|p| !clashes(&tracks[p], &tracks[i])),
+        };
+
+        let pick_key = match forced {
+            Some(k) => Some(k.clone()),
+            None => {
+                // Skip groups that could only repeat the album that just played
+                let fitting: Vec<&String> = open
+                    .iter()
+                    .copied()
+                    .filter(|k| groups[*k].iter().any(|&i| fits(i)))
+                    .collect();
+                let choices = if fitting.is_empty() { &open } else { &fitting };
+                // Weighted by group size, so big groups are not all pushed to the end
+                choices
+                    .choose_weighted(&mut rng, |k| groups[*k].len())
+                    .ok()
+                    .map(|k| (*k).clone())
+            }
+        };
+
+        let next = match pick_key {
+            Some(k) => {
+                let group = groups.get_mut(&k).unwrap();
+                // Within the artist, dodge the album that just played where possible
+                let slot = group.iter().position(|&i| fits(i)).unwrap_or(0);
+                group.remove(slot)
+            }
+            // Only the last artist is left
+            None => groups.values_mut().find(|v| !v.is_empty()).unwrap().remove(0),
+        };
+        if let Some(album) = album_key(&tracks[next]) {
+            *albums.get_mut(album).unwrap() -= 1;
+        }
+        order.push(next);
+        prev = Some(next);
+    }
+    order
+}
+
+/// Insert `index` into `order` at a random position at or after `from`. Smart
+/// shuffle prefers a spot where it clashes with neither neighbour.
+pub fn insert_randomly(tracks: &[Track], order: &mut Vec<usize>, from: usize, index: usize, smart: bool) {
+    let mut rng = rand::rng();
+    let from = from.min(order.len());
+    let mut slots: Vec<usize> = (from..=order.len()).collect();
+    if smart {
+        let fits = |slot: usize| {
+            let before = slot.checked_sub(1).map(|s| order[s]);
+            let after = order.get(slot).copied();
+            [before, after]
+                .into_iter()
+                .flatten()
+                .all(|n| !clashes(&tracks[n], &tracks[index]))
+        };
+        let fitting: Vec<usize> = slots.iter().copied().filter(|&s| fits(s)).collect();
+        if !fitting.is_empty() {
+            slots = fitting;
+        }
+    }
+    let slot = slots[rng.random_range(0..slots.len())];
+    order.insert(slot, index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn make_track(i: usize, artist: usize) -> Track {
+        Track {
+            video_id: i.to_string(),
+            artists: vec![format!("Artist {artist}")],
+            album: Some(format!("Album {}", artist * 10 + i % 2)),
+            ..Track::default()
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_smart_shuffle_keeps_artists_apart(
+            artists in proptest::collection::vec(0usize..5, 1..30),
+        ) {
+            let mut counts = [0; 5];
+            for &a in &artists {
+                counts[a] += 1;
+            }
+            // Spreading out is only possible when no artist has more than every other track
+            prop_assume!(counts.iter().all(|&c| c <= artists.len().div_ceil(2)));
+
+            let tracks: Vec<Track> =
+                artists.iter().enumerate().map(|(i, &a)| make_track(i, a)).collect();
+            let order = shuffled(&tracks, (0..tracks.len()).collect(), None, true);
+
+            let mut sorted = order.clone();
+            sorted.sort_unstable();
+            prop_assert!(sorted.into_iter().eq(0..tracks.len()));
+            for pair in order.windows(2) {
+                prop_assert_ne!(&tracks[pair[0]].artists, &tracks[pair[1]].artists);
+            }
+        }
+
+        #[test]
+        fn prop_smart_shuffle_keeps_compilation_albums_apart(
+            albums in proptest::collection::vec(0usize..4, 1..30),
+        ) {
+            let mut counts = [0; 4];
+            for &a in &albums {
+                counts[a] += 1;
+            }
+            prop_assume!(counts.iter().all(|&c| c <= albums.len().div_ceil(2)));
+
+            // Various-artists albums: every track has its own artist
+            let tracks: Vec<Track> = albums
+                .iter()
+                .enumerate()
+                .map(|(i, &a)| Track {
+                    video_id: i.to_string(),
+                    artists: vec![format!("Artist {i}")],
+                    album: Some(format!("Compilation {a}")),
+                    ..Track::default()
+                })
+                .collect();
+            let order = shuffled(&tracks, (0..tracks.len()).collect(), None, true);
+
+            let mut sorted = order.clone();
+            sorted.sort_unstable();
+            prop_assert!(sorted.into_iter().eq(0..tracks.len()));
+            for pair in order.windows(2) {
+                prop_assert_ne!(&tracks[pair[0]].album, &tracks[pair[1]].album);
+            }
+        }
+    }
+}
@@ -1,7 +1,42 @@
+use crate::ytm::models::{LikeStatus, Playlist, Track, VideoType};
 use anyhow::Context;
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension, Row};
 use std::path::Path;
 
+/// Schema changes on top of `init_schema`, applied in order. `PRAGMA user_version`
+/// records how many have run, so only append to this list.
+const MIGRATIONS: &[&str] = &[
+    // 1: rich track metadata
+    r#"
+ALTER TABLE tracks ADD COLUMN album_id TEXT;
+ALTER TABLE tracks ADD COLUMN explicit INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE tracks ADD COLUMN year INTEGER;
+ALTER TABLE tracks ADD COLUMN like_status TEXT;
+ALTER TABLE tracks ADD COLUMN video_type TEXT;
+ALTER TABLE tracks ADD COLUMN thumbnails_json TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE tracks ADD COLUMN artist_ids_json TEXT NOT NULL DEFAULT '[]';
+
+ALTER TABLE play_history ADD COLUMN album_id TEXT;
+ALTER TABLE play_history ADD COLUMN explicit INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE play_history ADD COLUMN year INTEGER;
+ALTER TABLE play_history ADD COLUMN like_status TEXT;
+ALTER TABLE play_history ADD COLUMN video_type TEXT;
+ALTER TABLE play_history ADD COLUMN thumbnails_json TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE play_history ADD COLUMN artist_ids_json TEXT NOT NULL DEFAULT '[]';
+"#,
+    // 2: lyrics provider
+    "ALTER TABLE lyrics_cache ADD COLUMN provider TEXT;",
+    // 3: up-next segment of the saved queue
+    "ALTER TABLE playback_state ADD COLUMN up_next INTEGER NOT NULL DEFAULT 0;",
+];
+
+/// `queue_tracks` position of the playing track, which need not be in the queue
+const PLAYING_POSITION: i64 = -1;
+
+/// Track columns shared by `tracks` and `play_history`, in `track_from_row` order
+const TRACK_COLUMNS: &str = "video_id, title, artists_json, album, duration_seconds, album_id, \
+explicit, year, like_status, video_type, thumbnails_json, artist_ids_json";
+
 pub struct Storage {
     conn: Connection,
 }
@@ -16,9 +51,26 @@ impl Storage {
         let conn = Connection::open(path).with_context(|| format!("open {}", path.display()))?;
         let s = Self { conn };
         s.init_schema()?;
+        s.migrate()?;
         Ok(s)
     }
 
+    fn migrate(&self) -> anyhow::Result<()> {
+        let version: i64 = self
+            .conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .context("read schema version")?;
+
+        for (i, sql) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+            let tx = self.conn.unchecked_transaction()?;
+            tx.execute_batch(sql)
+                .with_context(|| format!("migrate schema to version {}", i + 1))?;
+            tx.pragma_update(None, "user_version", (i + 1) as i64)?;
+            tx.commit()?;
+        }
+        Ok(())
+    }
+
     fn init_schema(&self) -> anyhow::Result<()> {
         self.conn
             .execute_batch(
@@ -60,12 +112,87 @@ CREATE TABLE IF NOT EXISTS play_history (
 CREATE INDEX IF NOT EXISTS idx_history_played_at ON play_history(played_at DESC);
 CREATE INDEX IF NOT EXISTS idx_history_video_id ON play_history(video_id);
 
+CREATE TABLE IF NOT EXISTS bootstrap_cache (
+  base_url TEXT PRIMARY KEY,
+  api_key TEXT NOT NULL,
+  client_version TEXT NOT NULL,
+  visitor_data TEXT,
+  fetched_at INTEGER NOT NULL
+);
+
 CREATE TABLE IF NOT EXISTS lyrics_cache (
   video_id TEXT PRIMARY KEY,
   lrc_content TEXT,
   synced INTEGER DEFAULT 0,
   fetched_at INTEGER NOT NULL
 );
+
+CREATE TABLE IF NOT EXISTS lyrics_offsets (
+  video_id TEXT PRIMARY KEY,
+  offset_ms INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS lyrics_pins (
+  video_id TEXT PRIMARY KEY,
+  lrclib_id INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS lyrics_misses (
+  video_id TEXT PRIMARY KEY,
+  checked_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS queue_tracks (
+  position INTEGER PRIMARY KEY,
+  video_id TEXT NOT NULL,
+  title TEXT NOT NULL,
+  artists_json TEXT NOT NULL,
+  album TEXT,
+  duration_seconds INTEGER,
+  album_id TEXT,
+  explicit INTEGER NOT NULL DEFAULT 0,
+  year INTEGER,
+  like_status TEXT,
+  video_type TEXT,
+  thumbnails_json TEXT NOT NULL DEFAULT '[]',
+  artist_ids_json TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE TABLE IF NOT EXISTS playback_state (
+  id INTEGER PRIMARY KEY CHECK (id = 0),
+  current_index INTEGER,
+  shuffle_enabled INTEGER NOT NULL DEFAULT 0,
+  shuffle_order_json TEXT NOT NULL DEFAULT '[]',
+  repeat_mode TEXT NOT NULL DEFAULT 'off',
+  playing_from_queue INTEGER NOT NULL DEFAULT 0,
+  video_id TEXT,
+  position_secs REAL NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS local_playlists (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL,
+  created_at INTEGER NOT NULL,
+  updated_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS local_playlist_tracks (
+  playlist_id INTEGER NOT NULL,
+  position INTEGER NOT NULL,
+  video_id TEXT NOT NULL,
+  title TEXT NOT NULL,
+  artists_json TEXT NOT NULL,
+  album TEXT,
+  duration_seconds INTEGER,
+  album_id TEXT,
+  explicit INTEGER NOT NULL DEFAULT 0,
+  year INTEGER,
+  like_status TEXT,
+  video_type TEXT,
+  thumbnails_json TEXT NOT NULL DEFAULT '[]',
+  artist_ids_json TEXT NOT NULL DEFAULT '[]',
+  PRIMARY KEY (playlist_id, position)
+);
 "#,
             )
             .context("init schema")?;
@@ -147,63 +274,110 @@ ON CONFLICT(video_id) DO UPDATE SET
         Ok(())
     }
 
+    /// Insert or refresh a track's metadata
+    pub fn upsert_track(&self, track: &Track, now_unix: i64) -> anyhow::Result<()> {
+        let c = TrackColumns::from(track);
+        self.conn
+            .execute(
+                &format!(
+                    r#"
+INSERT INTO tracks({TRACK_COLUMNS}, updated_at)
+VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+ON CONFLICT(video_id) DO UPDATE SET
+  title=excluded.title,
+  artists_json=excluded.artists_json,
+  album=excluded.album,
+  duration_seconds=excluded.duration_seconds,
+  album_id=excluded.album_id,
+  explicit=excluded.explicit,
+  year=excluded.year,
+  like_status=excluded.like_status,
+  video_type=excluded.video_type,
+  thumbnails_json=excluded.thumbnails_json,
+  artist_ids_json=excluded.artist_ids_json,
+  updated_at=excluded.updated_at
+"#
+                ),
+                params![
+                    track.video_id,
+                    track.title,
+                    c.artists_json,
+                    track.album,
+                    track.duration_seconds,
+                    track.album_id,
+                    track.explicit,
+                    track.year,
+                    c.like_status,
+                    c.video_type,
+                    c.thumbnails_json,
+                    c.artist_ids_json,
+                    now_unix
+                ],
+            )
+            .context("upsert track")?;
+        Ok(())
+    }
+
+    /// Stored metadata for a track
+    #[allow(dead_code)]
+    pub fn get_track(&self, video_id: &str) -> anyhow::Result<Option<Track>> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!("SELECT {TRACK_COLUMNS} FROM tracks WHERE video_id=?1"))
+            .context("prepare track")?;
+        let mut rows = stmt.query(params![video_id]).context("query track")?;
+        match rows.next().context("read track row")? {
+            Some(row) => Ok(Some(track_from_row(row)?)),
+            None => Ok(None),
+        }
+    }
+
     /// Add a track to play history
-    pub fn add_to_history(
-        &self,
-        track: &crate::ytm::models::Track,
-        played_at: i64,
-    ) -> anyhow::Result<()> {
-        let artists_json = serde_json::to_string(&track.artists).unwrap_or_else(|_| "[]".into());
+    pub fn add_to_history(&self, track: &Track, played_at: i64) -> anyhow::Result<()> {
+        let c = TrackColumns::from(track);
         self.conn
             .execute(
-                r#"
-INSERT INTO play_history(video_id, title, artists_json, album, duration_seconds, played_at)
-VALUES(?1, ?2, ?3, ?4, ?5, ?6)
-"#,
+                &format!(
+                    r#"
+INSERT INTO play_history({TRACK_COLUMNS}, played_at)
+VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+"#
+                ),
                 params![
                     track.video_id,
                     track.title,
-                    artists_json,
+                    c.artists_json,
                     track.album,
                     track.duration_seconds,
+                    track.album_id,
+                    track.explicit,
+                    track.year,
+                    c.like_status,
+                    c.video_type,
+                    c.thumbnails_json,
+                    c.artist_ids_json,
                     played_at
                 ],
             )
             .context("add to history")?;
-        Ok(())
+        self.upsert_track(track, played_at)
     }
 
     /// Get play history (most recent first, unique tracks only)
-    pub fn get_history(&self, limit: usize) -> anyhow::Result<Vec<crate::ytm::models::Track>> {
-        let mut stmt = self.conn.prepare(
+    pub fn get_history(&self, limit: usize) -> anyhow::Result<Vec<Track>> {
+        // SQLite takes the bare columns from the row holding MAX(played_at)
+        let mut stmt = self.conn.prepare(&format!(
             r#"
-SELECT video_id, title, artists_json, album, duration_seconds, MAX(played_at) as last_played
+SELECT {TRACK_COLUMNS}, MAX(played_at) as last_played
 FROM play_history
 GROUP BY video_id
 ORDER BY last_played DESC
 LIMIT ?1
-"#,
-        )?;
+"#
+        ))?;
 
         let tracks = stmt
-            .query_map(params![limit as i64], |row| {
-                let video_id: String = row.get(0)?;
-                let title: String = row.get(1)?;
-                let artists_json: String = row.get(2)?;
-                let album: Option<String> = row.get(3)?;
-                let duration_seconds: Option<u32> = row.get(4)?;
-
-                let artists: Vec<String> =
-                    serde_json::from_str(&artists_json).unwrap_or_default();
-
-                Ok(crate::ytm::models::Track {
-                    video_id,
-                    title,
-                    artists,
-                    album,
-                    duration_seconds,
-                })
-            })?
+            .query_map(params![limit as i64], track_from_row)?
             .filter_map(|r| r.ok())
             .collect();
 
@@ -216,38 +390,756 @@ LIMIT ?1
         video_id: &str,
         lrc_content: &str,
         synced: bool,
+        provider: Option<&str>,
         now_unix: i64,
     ) -> anyhow::Result<()> {
         self.conn
             .execute(
                 r#"
-INSERT INTO lyrics_cache(video_id, lrc_content, synced, fetched_at)
-VALUES(?1, ?2, ?3, ?4)
+INSERT INTO lyrics_cache(video_id, lrc_content, synced, provider, fetched_at)
+VALUES(?1, ?2, ?3, ?4, ?5)
 ON CONFLICT(video_id) DO UPDATE SET
   lrc_content=excluded.lrc_content,
   synced=excluded.synced,
+  provider=excluded.provider,
   fetched_at=excluded.fetched_at
 "#,
-                params![video_id, lrc_content, synced as i32, now_unix],
+                params![video_id, lrc_content, synced as i32, provider, now_unix],
             )
             .context("cache lyrics")?;
         Ok(())
     }
 
-    /// Get cached lyrics
-    pub fn get_lyrics(&self, video_id: &str) -> anyhow::Result<Option<(String, bool)>> {
+    /// Get cached lyrics: content, whether synced, and the provider they came from
+    pub fn get_lyrics(&self, video_id: &str) -> anyhow::Result<Option<(String, bool, Option<String>)>> {
         let mut stmt = self
             .conn
-            .prepare("SELECT lrc_content, synced FROM lyrics_cache WHERE video_id=?1")?;
+            .prepare("SELECT lrc_content, synced, provider FROM lyrics_cache WHERE video_id=?1")?;
         let mut rows = stmt.query(params![video_id])?;
         if let Some(row) = rows.next()? {
             let content: String = row.get(0)?;
             let synced: i32 = row.get(1)?;
-            Ok(Some((content, synced != 0)))
+            Ok(Some((content, synced != 0, row.get(2)?)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Remember that no provider had lyrics for a track
+    pub fn mark_lyrics_missing(&self, video_id: &str, now_unix: i64) -> anyhow::Result<()> {
+        self.conn
+            .execute(
+                r#"
+INSERT INTO lyrics_misses(video_id, checked_at)
+VALUES(?1, ?2)
+ON CONFLICT(video_id) DO UPDATE SET checked_at=excluded.checked_at
+"#,
+                params![video_id, now_unix],
+            )
+            .context("mark lyrics missing")?;
+        Ok(())
+    }
+
+    /// Whether a lookup for the track came up empty after `min_checked_at`
+    pub fn lyrics_missing_since(&self, video_id: &str, min_checked_at: i64) -> anyhow::Result<bool> {
+        let found = self
+            .conn
+            .query_row(
+                "SELECT 1 FROM lyrics_misses WHERE video_id=?1 AND checked_at>?2",
+                params![video_id, min_checked_at],
+                |_| Ok(()),
+            )
+            .optional()
+            .context("check lyrics miss")?;
+        Ok(found.is_some())
+    }
+
+    /// Use this LRCLIB entry for the track from now on
+    pub fn pin_lyrics(&self, video_id: &str, lrclib_id: i64) -> anyhow::Result<()> {
+        self.conn
+            .execute(
+                r#"
+INSERT INTO lyrics_pins(video_id, lrclib_id)
+VALUES(?1, ?2)
+ON CONFLICT(video_id) DO UPDATE SET lrclib_id=excluded.lrclib_id
+"#,
+                params![video_id, lrclib_id],
+            )
+            .context("pin lyrics")?;
+        self.conn
+            .execute("DELETE FROM lyrics_misses WHERE video_id=?1", params![video_id])
+            .context("clear lyrics miss")?;
+        Ok(())
+    }
+
+    pub fn get_pinned_lyrics(&self, video_id: &str) -> anyhow::Result<Option<i64>> {
+        self.conn
+            .query_row(
+                "SELECT lrclib_id FROM lyrics_pins WHERE video_id=?1",
+                params![video_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .context("get pinned lyrics")
+    }
+
+    /// Saved lyrics nudge for a track in ms, 0 if never adjusted
+    pub fn get_lyrics_offset(&self, video_id: &str) -> anyhow::Result<i64> {
+        let offset = self
+            .conn
+            .query_row(
+                "SELECT offset_ms FROM lyrics_offsets WHERE video_id=?1",
+                params![video_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .context("get lyrics offset")?;
+        Ok(offset.unwrap_or(0))
+    }
+
+    pub fn set_lyrics_offset(&self, video_id: &str, offset_ms: i64) -> anyhow::Result<()> {
+        self.conn
+            .execute(
+                r#"
+INSERT INTO lyrics_offsets(video_id, offset_ms)
+VALUES(?1, ?2)
+ON CONFLICT(video_id) DO UPDATE SET offset_ms=excluded.offset_ms
+"#,
+                params![video_id, offset_ms],
+            )
+            .context("set lyrics offset")?;
+        Ok(())
+    }
+
+    /// Bootstrap for `base_url`, if fetched after `min_fetched_at`
+    pub fn get_bootstrap(
+        &self,
+        base_url: &str,
+        min_fetched_at: i64,
+    ) -> anyhow::Result<Option<crate::ytm::api::Bootstrap>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT api_key, client_version, visitor_data FROM bootstrap_cache WHERE base_url=?1 AND fetched_at>?2",
+        )?;
+        let mut rows = stmt.query(params![base_url, min_fetched_at])?;
+        if let Some(row) = rows.next()? {
+            Ok(Some(crate::ytm::api::Bootstrap {
+                api_key: row.get(0)?,
+                client_version: row.get(1)?,
+                visitor_data: row.get(2)?,
+            }))
         } else {
             Ok(None)
         }
     }
+
+    pub fn cache_bootstrap(
+        &self,
+        base_url: &str,
+        b: &crate::ytm::api::Bootstrap,
+        now_unix: i64,
+    ) -> anyhow::Result<()> {
+        self.conn
+            .execute(
+                r#"
+INSERT INTO bootstrap_cache(base_url, api_key, client_version, visitor_data, fetched_at)
+VALUES(?1, ?2, ?3, ?4, ?5)
+ON CONFLICT(base_url) DO UPDATE SET
+  api_key=excluded.api_key,
+  client_version=excluded.client_version,
+  visitor_data=excluded.visitor_data,
+  fetched_at=excluded.fetched_at
+"#,
+                params![base_url, b.api_key, b.client_version, b.visitor_data, now_unix],
+            )
+            .context("cache bootstrap")?;
+        Ok(())
+    }
+
+    /// Replace the saved queue and playback position
+    pub fn save_playback(&self, saved: &SavedPlayback) -> anyhow::Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM queue_tracks", [])
+            .context("clear saved queue")?;
+        // The playing track need not be in the queue, so it is kept beside it
+        let rows = saved.queue.iter().enumerate().map(|(i, t)| (i as i64, t));
+        let playing = saved.track.iter().map(|t| (PLAYING_POSITION, t));
+        for (position, track) in rows.chain(playing) {
+            let c = TrackColumns::from(track);
+            tx.execute(
+                &format!(
+                    r#"
+INSERT INTO queue_tracks({TRACK_COLUMNS}, position)
+VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+"#
+                ),
+                params![
+                    track.video_id,
+                    track.title,
+                    c.artists_json,
+                    track.album,
+                    track.duration_seconds,
+                    track.album_id,
+                    track.explicit,
+                    track.year,
+                    c.like_status,
+                    c.video_type,
+                    c.thumbnails_json,
+                    c.artist_ids_json,
+                    position
+                ],
+            )
+            .context("save queue track")?;
+        }
+
+        let shuffle_order_json =
+            serde_json::to_string(&saved.shuffle_order).unwrap_or_else(|_| "[]".into());
+        tx.execute(
+            r#"
+INSERT INTO playback_state(id, current_index, shuffle_enabled, shuffle_order_json, repeat_mode,
+  playing_from_queue, video_id, position_secs, up_next)
+VALUES(0, ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+ON CONFLICT(id) DO UPDATE SET
+  current_index=excluded.current_index,
+  up_next=excluded.up_next,
+  shuffle_enabled=excluded.shuffle_enabled,
+  shuffle_order_json=excluded.shuffle_order_json,
+  repeat_mode=excluded.repeat_mode,
+  playing_from_queue=excluded.playing_from_queue,
+  video_id=excluded.video_id,
+  position_secs=excluded.position_secs
+"#,
+            params![
+                saved.current_index.map(|i| i as i64),
+                saved.shuffle_enabled,
+                shuffle_order_json,
+                saved.repeat_mode,
+                saved.playing_from_queue,
+                saved.track.as_ref().map(|t| t.video_id.as_str()),
+                saved.position_secs,
+                saved.up_next as i64
+            ],
+        )
+        .context("save playback state")?;
+        tx.commit().context("commit playback state")?;
+        Ok(())
+    }
+
+    /// The queue and position saved by `save_playback`, if any
+    pub fn load_playback(&self) -> anyhow::Result<Option<SavedPlayback>> {
+        let saved = self
+            .conn
+            .query_row(
+                "SELECT current_index, shuffle_enabled, shuffle_order_json, repeat_mode, \
+playing_from_queue, video_id, position_secs, up_next FROM playback_state WHERE id=0",
+                [],
+                |row| {
+                    let current_index: Option<i64> = row.get(0)?;
+                    let shuffle_order_json: String = row.get(2)?;
+                    let video_id: Option<String> = row.get(5)?;
+                    let up_next: i64 = row.get(7)?;
+                    let saved = SavedPlayback {
+                        current_index: current_index.map(|i| i as usize),
+                        up_next: up_next as usize,
+                        shuffle_enabled: row.get(1)?,
+                        shuffle_order: serde_json::from_str(&shuffle_order_json).unwrap_or_default(),
+                        repeat_mode: row.get(3)?,
+                        playing_from_queue: row.get(4)?,
+                        position_secs: row.get(6)?,
+                        ..SavedPlayback::default()
+                    };
+                    Ok((saved, video_id))
+                },
+            )
+            .optional()
+            .context("load playback state")?;
+        let Some((mut saved, video_id)) = saved else {
+            return Ok(None);
+        };
+
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "SELECT {TRACK_COLUMNS} FROM queue_tracks WHERE position >= 0 ORDER BY position"
+            ))
+            .context("prepare saved queue")?;
+        saved.queue = stmt
+            .query_map([], track_from_row)
+            .context("query saved queue")?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("read saved queue")?;
+        if let Some(id) = video_id {
+            saved.track = self
+                .conn
+                .query_row(
+                    &format!("SELECT {TRACK_COLUMNS} FROM queue_tracks WHERE position=?1"),
+                    params![PLAYING_POSITION],
+                    track_from_row,
+                )
+                .optional()
+                .context("load playing track")?;
+            // Saved before the playing track was kept with the queue
+            if saved.track.is_none() {
+                saved.track = self.get_track(&id)?;
+            }
+        }
+        Ok(Some(saved))
+    }
+
+    /// Playlists kept only in void, oldest first
+    pub fn local_playlists(&self) -> anyhow::Result<Vec<LocalPlaylist>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                r#"
+SELECT p.id, p.name, COUNT(t.position)
+FROM local_playlists p
+LEFT JOIN local_playlist_tracks t ON t.playlist_id = p.id
+GROUP BY p.id
+ORDER BY p.id
+"#,
+            )
+            .context("prepare local playlists")?;
+        let playlists = stmt
+            .query_map([], |row| {
+                let track_count: i64 = row.get(2)?;
+                Ok(LocalPlaylist {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    track_count: track_count as usize,
+                })
+            })
+            .context("query local playlists")?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("read local playlists")?;
+        Ok(playlists)
+    }
+
+    /// Create a local playlist holding `tracks`; returns its id
+    pub fn create_local_playlist(&self, name: &str, tracks: &[Track], now_unix: i64) -> anyhow::Result<i64> {
+        self.conn
+            .execute(
+                "INSERT INTO local_playlists(name, created_at, updated_at) VALUES(?1, ?2, ?2)",
+                params![name, now_unix],
+            )
+            .context("create local playlist")?;
+        let id = self.conn.last_insert_rowid();
+        self.set_local_playlist_tracks(id, tracks, now_unix)?;
+        Ok(id)
+    }
+
+    pub fn rename_local_playlist(&self, id: i64, name: &str, now_unix: i64) -> anyhow::Result<()> {
+        self.conn
+            .execute(
+                "UPDATE local_playlists SET name=?2, updated_at=?3 WHERE id=?1",
+                params![id, name, now_unix],
+            )
+            .context("rename local playlist")?;
+        Ok(())
+    }
+
+    pub fn delete_local_playlist(&self, id: i64) -> anyhow::Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM local_playlist_tracks WHERE playlist_id=?1", params![id])
+            .context("delete local playlist tracks")?;
+        tx.execute("DELETE FROM local_playlists WHERE id=?1", params![id])
+            .context("delete local playlist")?;
+        tx.commit().context("commit local playlist delete")?;
+        Ok(())
+    }
+
+    pub fn local_playlist_tracks(&self, id: i64) -> anyhow::Result<Vec<Track>> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "SELECT {TRACK_COLUMNS} FROM local_playlist_tracks WHERE playlist_id=?1 ORDER BY position"
+            ))
+            .context("prepare local playlist tracks")?;
+        let tracks = stmt
+            .query_map(params![id], track_from_row)
+            .context("query local playlist tracks")?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("read local playlist tracks")?;
+        Ok(tracks)
+    }
+
+    /// Append tracks to the end of a local playlist
+    pub fn add_local_playlist_tracks(&self, id: i64, tracks: &[Track], now_unix: i64) -> anyhow::Result<()> {
+        let mut all = self.local_playlist_tracks(id)?;
+        all.extend_from_slice(tracks);
+        self.set_local_playlist_tracks(id, &all, now_unix)
+    }
+
+    /// Replace a local playlist's tracks, e.g. after reordering or removing some
+    pub fn set_local_playlist_tracks(&self, id: i64, tracks: &[Track], now_unix: i64) -> anyhow::Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM local_playlist_tracks WHERE playlist_id=?1", params![id])
+            .context("clear local playlist")?;
+        for (position, track) in tracks.iter().enumerate() {
+            let c = TrackColumns::from(track);
+            tx.execute(
+                &format!(
+                    r#"
+INSERT INTO local_playlist_tracks({TRACK_COLUMNS}, playlist_id, position)
+VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+"#
+                ),
+                params![
+                    track.video_id,
+                    track.title,
+                    c.artists_json,
+                    track.album,
+                    track.duration_seconds,
+                    track.album_id,
+                    track.explicit,
+                    track.year,
+                    c.like_status,
+                    c.video_type,
+                    c.thumbnails_json,
+                    c.artist_ids_json,
+                    id,
+                    position as i64
+                ],
+            )
+            .context("save local playlist track")?;
+        }
+        tx.execute(
+            "UPDATE local_playlists SET updated_at=?2 WHERE id=?1",
+            params![id, now_unix],
+        )
+        .context("touch local playlist")?;
+        tx.commit().context("commit local playlist tracks")?;
+        Ok(())
+    }
+
+    /// Empty the tables that only hold fetched data. Lyric offsets, pinned LRCLIB
+    /// choices and the saved queue are the user's own and survive.
+    pub fn clear_cache(&self) -> anyhow::Result<()> {
+        self.conn
+            .execute_batch(
+                r#"
+DELETE FROM tracks;
+DELETE FROM last_searches;
+DELETE FROM stream_cache;
+DELETE FROM play_history;
+DELETE FROM bootstrap_cache;
+DELETE FROM lyrics_cache;
+DELETE FROM lyrics_misses;
+"#,
+            )
+            .context("clear cache")?;
+        self.conn.execute_batch("VACUUM").context("vacuum cache")?;
+        Ok(())
+    }
+
+    pub fn clear_bootstrap(&self, base_url: &str) -> anyhow::Result<()> {
+        self.conn
+            .execute("DELETE FROM bootstrap_cache WHERE base_url=?1", params![base_url])
+            .context("clear bootstrap")?;
+        Ok(())
+    }
+}
+
+/// The queue and what was playing, kept across restarts
+#[derive(Debug, Clone, Default)]
+pub struct SavedPlayback {
+    pub queue: Vec<Track>,
+    pub current_index: Option<usize>,
+    /// Tracks after the current one picked to play next
+    pub up_next: usize,
+    pub shuffle_enabled: bool,
+    pub shuffle_order: Vec<usize>,
+    /// `RepeatMode::as_str`
+    pub repeat_mode: String,
+    pub playing_from_queue: bool,
+    pub track: Option<Track>,
+    pub position_secs: f64,
+}
+
+/// Prefix of the `Playlist::id` a local playlist is listed under
+const LOCAL_PLAYLIST_PREFIX: &str = "local:";
+
+/// A playlist kept only in void, independent of any account
+#[derive(Debug, Clone)]
+pub struct LocalPlaylist {
+    pub id: i64,
+    pub name: String,
+    pub track_count: usize,
+}
+
+impl LocalPlaylist {
+    /// As a playlist the library views can show and open
+    pub fn to_playlist(&self) -> Playlist {
+        Playlist {
+            id: format!("{LOCAL_PLAYLIST_PREFIX}{}", self.id),
+            title: self.name.clone(),
+            author: None,
+            track_count: Some(self.track_count as u32),
+            thumbnail_url: None,
+        }
+    }
+}
+
+/// The local playlist id behind a `Playlist::id` from `LocalPlaylist::to_playlist`
+pub fn local_playlist_id(playlist_id: &str) -> Option<i64> {
+    playlist_id.strip_prefix(LOCAL_PLAYLIST_PREFIX)?.parse().ok()
+}
+
+/// Track fields stored as JSON or text codes
+struct TrackColumns {
+    artists_json: String,
+    artist_ids_json: String,
+    thumbnails_json: String,
+    like_status: Option<&'static str>,
+    video_type: Option<&'static str>,
+}
+
+impl From<&Track> for TrackColumns {
+    fn from(track: &Track) -> Self {
+        let json = |r: serde_json::Result<String>| r.unwrap_or_else(|_| "[]".into());
+        Self {
+            artists_json: json(serde_json::to_string(&track.artists)),
+            artist_ids_json: json(serde_json::to_string(&track.artist_ids)),
+            thumbnails_json: json(serde_json::to_string(&track.thumbnails)),
+            like_status: track.like_status.map(LikeStatus::as_str),
+            video_type: track.video_type.map(VideoType::as_api),
+        }
+    }
+}
+
+/// Read a row selected with `TRACK_COLUMNS`
+fn track_from_row(row: &Row) -> rusqlite::Result<Track> {
+    let artists_json: String = row.get(2)?;
+    let thumbnails_json: String = row.get(10)?;
+    let artist_ids_json: String = row.get(11)?;
+    let like_status: Option<String> = row.get(8)?;
+    let video_type: Option<String> = row.get(9)?;
+
+    Ok(Track {
+        video_id: row.get(0)?,
+        title: row.get(1)?,
+        artists: serde_json::from_str(&artists_json).unwrap_or_default(),
+        album: row.get(3)?,
+        duration_seconds: row.get(4)?,
+        album_id: row.get(5)?,
+        explicit: row.get(6)?,
+        year: row.get(7)?,
+        like_status: like_status.as_deref().and_then(LikeStatus::from_api),
+        video_type: video_type.as_deref().and_then(VideoType::from_api),
+        thumbnails: serde_json::from_str(&thumbnails_json).unwrap_or_default(),
+        artist_ids: serde_json::from_str(&artist_ids_json).unwrap_or_default(),
+    })
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ytm::models::Thumbnail;
+
+    #[test]
+    fn test_migration_keeps_old_history_and_stores_metadata() {
+        let path = std::env::temp_dir().join(format!("void-migrate-test-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        // A database from before the metadata columns existed
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                r#"
+CREATE TABLE tracks (
+  video_id TEXT PRIMARY KEY, title TEXT NOT NULL, artists_json TEXT NOT NULL,
+  album TEXT, duration_seconds INTEGER, updated_at INTEGER NOT NULL
+);
+CREATE TABLE play_history (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, video_id TEXT NOT NULL, title TEXT NOT NULL,
+  artists_json TEXT NOT NULL, album TEXT, duration_seconds INTEGER, played_at INTEGER NOT NULL,
+  duration_listened INTEGER, completed INTEGER DEFAULT 0
+);
+INSERT INTO play_history(video_id, title, artists_json, played_at) VALUES('old', 'Old', '["A"]', 1);
+"#,
+            )
+            .unwrap();
+        }
+
+        let storage = Storage::open(&path).unwrap();
+        let track = Track {
+            video_id: "new".into(),
+            title: "New".into(),
+            artists: vec!["B".into(), "C".into()],
+            artist_ids: vec![Some("UCb".into()), None],
+            album_id: Some("MPREx".into()),
+            explicit: true,
+            year: Some(2021),
+            like_status: Some(LikeStatus::Like),
+            video_type: Some(VideoType::Song),
+            thumbnails: vec![Thumbnail { url: "https://t/60".into(), width: 60, height: 60 }],
+            ..Track::default()
+        };
+        storage.add_to_history(&track, 2).unwrap();
+
+        let history = storage.get_history(10).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].artist_ids, track.artist_ids);
+        assert_eq!(history[0].year, Some(2021));
+        assert!(history[0].explicit);
+        assert_eq!(history[0].video_type, Some(VideoType::Song));
+        assert_eq!(history[0].thumbnail_url(0), Some("https://t/60"));
+        assert_eq!(history[1].artists, vec!["A"]);
+        assert!(!history[1].explicit);
+
+        let stored = storage.get_track("new").unwrap().unwrap();
+        assert_eq!(stored.like_status, Some(LikeStatus::Like));
+        assert_eq!(stored.album_id.as_deref(), Some("MPREx"));
+
+        // Reopening must not re-run migrations
+        drop(storage);
+        Storage::open(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_lyrics_misses_expire_and_pins_clear_them() {
+        let path = std::env::temp_dir().join(format!("void-lyrics-test-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let storage = Storage::open(&path).unwrap();
+
+        storage.mark_lyrics_missing("vid", 100).unwrap();
+        assert!(storage.lyrics_missing_since("vid", 50).unwrap());
+        assert!(!storage.lyrics_missing_since("vid", 100).unwrap());
+
+        storage.pin_lyrics("vid", 42).unwrap();
+        assert_eq!(storage.get_pinned_lyrics("vid").unwrap(), Some(42));
+        assert!(!storage.lyrics_missing_since("vid", 50).unwrap());
+
+        drop(storage);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_clear_cache_keeps_user_data() {
+        let path = std::env::temp_dir().join(format!("void-clear-test-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let storage = Storage::open(&path).unwrap();
+
+        let track = Track {
+            video_id: "vid".into(),
+            title: "Song".into(),
+            ..Track::default()
+        };
+        storage.add_to_history(&track, 1).unwrap();
+        storage.cache_lyrics("vid", "[00:01.00]hi", true, None, 1).unwrap();
+        storage.set_lyrics_offset("vid", 250).unwrap();
+        storage.pin_lyrics("vid", 42).unwrap();
+        let saved = SavedPlayback {
+            queue: vec![track.clone()],
+            current_index: Some(0),
+            repeat_mode: "off".into(),
+            track: Some(track),
+            ..SavedPlayback::default()
+        };
+        storage.save_playback(&saved).unwrap();
+
+        storage.clear_cache().unwrap();
 
+        assert!(storage.get_history(10).unwrap().is_empty());
+        assert!(storage.get_lyrics("vid").unwrap().is_none());
+        assert_eq!(storage.get_lyrics_offset("vid").unwrap(), 250);
+        assert_eq!(storage.get_pinned_lyrics("vid").unwrap(), Some(42));
+        let loaded = storage.load_playback().unwrap().unwrap();
+        assert_eq!(loaded.queue.len(), 1);
+        assert_eq!(loaded.track.unwrap().title, "Song");
+
+        drop(storage);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_playback_round_trip() {
+        let path = std::env::temp_dir().join(format!("void-playback-test-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let storage = Storage::open(&path).unwrap();
+        assert!(storage.load_playback().unwrap().is_none());
+
+        let track = |id: &str| Track {
+            video_id: id.into(),
+            title: format!("Track {id}"),
+            artists: vec!["A".into()],
+            ..Track::default()
+        };
+        let saved = SavedPlayback {
+            queue: vec![track("a"), track("b"), track("c")],
+            current_index: Some(1),
+            up_next: 1,
+            shuffle_enabled: true,
+            shuffle_order: vec![1, 2, 0],
+            repeat_mode: "all".into(),
+            playing_from_queue: false,
+            track: Some(track("elsewhere")),
+            position_secs: 42.5,
+        };
+        storage.save_playback(&saved).unwrap();
+        // Saving again replaces rather than appends
+        let shorter = SavedPlayback {
+            queue: saved.queue[..2].to_vec(),
+            ..saved.clone()
+        };
+        storage.save_playback(&shorter).unwrap();
+
+        let loaded = storage.load_playback().unwrap().unwrap();
+        let ids: Vec<_> = loaded.queue.iter().map(|t| t.video_id.as_str()).collect();
+        assert_eq!(ids, ["a", "b"]);
+        assert_eq!(loaded.current_index, Some(1));
+        assert_eq!(loaded.up_next, 1);
+        assert!(loaded.shuffle_enabled);
+        assert_eq!(loaded.shuffle_order, vec![1, 2, 0]);
+        assert_eq!(loaded.repeat_mode, "all");
+        assert!(!loaded.playing_from_queue);
+        assert_eq!(loaded.track.unwrap().title, "Track elsewhere");
+        assert_eq!(loaded.position_secs, 42.5);
+
+        drop(storage);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_local_playlists_round_trip() {
+        let path = std::env::temp_dir().join(format!("void-local-playlists-test-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let storage = Storage::open(&path).unwrap();
+
+        let track = |id: &str| Track {
+            video_id: id.into(),
+            title: format!("Track {id}"),
+            ..Track::default()
+        };
+        let mix = storage.create_local_playlist("Mix", &[track("a"), track("b")], 1).unwrap();
+        let empty = storage.create_local_playlist("Empty", &[], 1).unwrap();
+        storage.add_local_playlist_tracks(mix, &[track("c")], 2).unwrap();
+        storage.rename_local_playlist(empty, "Later", 3).unwrap();
+
+        let playlists = storage.local_playlists().unwrap();
+        let summary: Vec<_> = playlists.iter().map(|p| (p.name.as_str(), p.track_count)).collect();
+        assert_eq!(summary, [("Mix", 3), ("Later", 0)]);
+        let listed = playlists[0].to_playlist();
+        assert_eq!(local_playlist_id(&listed.id), Some(mix));
+        assert_eq!(local_playlist_id("PL123"), None);
+
+        // Reorder
+        let mut tracks = storage.local_playlist_tracks(mix).unwrap();
+        tracks.swap(0, 2);
+        storage.set_local_playlist_tracks(mix, &tracks, 4).unwrap();
+        let ids: Vec<_> = storage
+            .local_playlist_tracks(mix)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.video_id)
+            .collect();
+        assert_eq!(ids, ["c", "b", "a"]);
+
+        storage.delete_local_playlist(mix).unwrap();
+        assert!(storage.local_playlist_tracks(mix).unwrap().is_empty());
+        assert_eq!(storage.local_playlists().unwrap().len(), 1);
+
+        drop(storage);
+        let _ = std::fs::remove_file(&path);
+    }
+}
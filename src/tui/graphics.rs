@@ -0,0 +1,452 @@
+//! Inline images: picks a terminal graphics protocol and encodes album art for it.
+//!
+//! Half-blocks render through the ratatui buffer like any other text. The pixel
+//! protocols (kitty, sixel, iTerm2) bypass it: widgets reserve an area and record
+//! a `Placement`, and `flush` writes the escape sequences after the frame is drawn.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use image::imageops::{self, FilterType};
+use image::RgbaImage;
+use lru::LruCache;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+};
+use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+/// Decoded images kept in memory
+const IMAGE_CACHE_SIZE: usize = 16;
+
+/// Cell size to assume when the terminal does not report pixel dimensions
+const DEFAULT_CELL_PX: (u16, u16) = (8, 16);
+
+/// Kitty payloads are sent in chunks of at most this many base64 bytes
+const KITTY_CHUNK: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Kitty,
+    Sixel,
+    Iterm2,
+    /// Unicode `▀` cells with truecolor foreground/background
+    HalfBlocks,
+    Off,
+}
+
+impl Protocol {
+    /// Protocol from the `ui.image_protocol` setting; "auto" (or anything
+    /// unrecognised) detects from the environment
+    pub fn from_config(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "kitty" => Self::Kitty,
+            "sixel" => Self::Sixel,
+            "iterm2" | "iterm" => Self::Iterm2,
+            "halfblocks" | "blocks" => Self::HalfBlocks,
+            "off" | "none" => Self::Off,
+            _ => Self::detect(|key| std::env::var(key).ok()),
+        }
+    }
+
+    /// Guess what the terminal supports from its environment variables
+    pub fn detect(env: impl Fn(&str) -> Option<String>) -> Self {
+        let term = env("TERM").unwrap_or_default();
+        let program = env("TERM_PROGRAM").unwrap_or_default();
+
+        if term == "dumb" || term == "linux" {
+            return Self::Off;
+        }
+        // Multiplexers swallow graphics escapes unless specially configured
+        if env("TMUX").is_some() || env("STY").is_some() {
+            return Self::HalfBlocks;
+        }
+        if env("KITTY_WINDOW_ID").is_some() || term.contains("kitty") || program == "ghostty" {
+            return Self::Kitty;
+        }
+        if program == "iTerm.app"
+            || program == "WezTerm"
+            || env("LC_TERMINAL").as_deref() == Some("iTerm2")
+        {
+            return Self::Iterm2;
+        }
+        if term.starts_with("foot") || term.starts_with("mlterm") || term.contains("contour") {
+            return Self::Sixel;
+        }
+        Self::HalfBlocks
+    }
+
+    /// Whether images are drawn with escape sequences outside the ratatui buffer
+    pub fn is_pixel(self) -> bool {
+        matches!(self, Self::Kitty | Self::Sixel | Self::Iterm2)
+    }
+}
+
+/// An image requested at a screen area during the current frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub url: String,
+    pub area: Rect,
+}
+
+/// Loaded album art and what is currently on screen
+pub struct ArtState {
+    pub protocol: Protocol,
+    /// Terminal cell size in pixels, used to keep images square
+    pub cell_px: (u16, u16),
+    pub images: LruCache<String, Arc<RgbaImage>>,
+    /// URLs being fetched
+    pub pending: HashSet<String>,
+    /// URLs that failed to load; not retried this session
+    pub failed: HashSet<String>,
+    /// Requested by widgets during the frame being drawn
+    placements: Vec<Placement>,
+    /// Drawn by the last `flush`
+    shown: Vec<Placement>,
+    screen: Rect,
+}
+
+impl Default for ArtState {
+    fn default() -> Self {
+        Self {
+            protocol: Protocol::Off,
+            cell_px: DEFAULT_CELL_PX,
+            images: LruCache::new(NonZeroUsize::new(IMAGE_CACHE_SIZE).unwrap()),
+            pending: HashSet::new(),
+            failed: HashSet::new(),
+            placements: Vec::new(),
+            shown: Vec::new(),
+            screen: Rect::default(),
+        }
+    }
+}
+
+impl ArtState {
+    pub fn new(protocol: Protocol) -> Self {
+        Self {
+            protocol,
+            cell_px: if protocol.is_pixel() { query_cell_px() } else { DEFAULT_CELL_PX },
+            ..Self::default()
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.protocol != Protocol::Off
+    }
+
+    /// Whether `url` still needs fetching
+    pub fn wants(&self, url: &str) -> bool {
+        self.enabled()
+            && !self.images.contains(url)
+            && !self.pending.contains(url)
+            && !self.failed.contains(url)
+    }
+
+    /// Queue an image to be written at `area` by the next `flush`
+    pub fn place(&mut self, url: &str, area: Rect) {
+        self.placements.push(Placement {
+            url: url.to_string(),
+            area,
+        });
+    }
+
+    /// Drop placements something was drawn over after the art widget reserved
+    /// them (a popup), so the image is sent again once they are uncovered
+    pub fn drop_covered(&mut self, buf: &Buffer) {
+        self.placements.retain(|p| {
+            p.area
+                .positions()
+                .all(|pos| buf.cell(pos).is_none_or(|cell| cell.skip))
+        });
+    }
+}
+
+/// Largest area inside `area` with the image's aspect ratio, centered
+pub fn fit(image: &RgbaImage, area: Rect, cell_px: (u16, u16)) -> Rect {
+    let (cw, ch) = (cell_px.0.max(1) as f64, cell_px.1.max(1) as f64);
+    let (iw, ih) = (image.width().max(1) as f64, image.height().max(1) as f64);
+    let scale = (area.width as f64 * cw / iw).min(area.height as f64 * ch / ih);
+
+    let cols = ((iw * scale / cw).round() as u16).clamp(1, area.width.max(1));
+    let rows = ((ih * scale / ch).round() as u16).clamp(1, area.height.max(1));
+    Rect::new(
+        area.x + (area.width - cols) / 2,
+        area.y + (area.height - rows) / 2,
+        cols,
+        rows,
+    )
+}
+
+/// Render `image` as `▀` cells: the foreground is the upper pixel, the background the lower one
+pub fn half_blocks(image: &RgbaImage, cols: u16, rows: u16) -> Vec<Line<'static>> {
+    let scaled = imageops::resize(image, cols as u32, rows as u32 * 2, FilterType::Triangle);
+    (0..rows as u32)
+        .map(|row| {
+            let spans: Vec<Span> = (0..cols as u32)
+                .map(|x| {
+                    let top = scaled.get_pixel(x, row * 2);
+                    let bottom = scaled.get_pixel(x, row * 2 + 1);
+                    Span::styled(
+                        "▀",
+                        Style::default()
+                            .fg(Color::Rgb(top[0], top[1], top[2]))
+                            .bg(Color::Rgb(bottom[0], bottom[1], bottom[2])),
+                    )
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Write the placements requested this frame. Images are only re-sent when
+/// the placements or the screen size changed, since the terminal keeps them.
+///
+/// Returns true when the screen must be cleared and the frame drawn again: sixel
+/// and iTerm2 images have no delete command, and ratatui will not repaint the
+/// cells an old image covered as their buffer contents did not change.
+pub fn flush(out: &mut impl Write, art: &mut ArtState, screen: Rect) -> std::io::Result<bool> {
+    let placements = std::mem::take(&mut art.placements);
+    if !art.protocol.is_pixel() || (placements == art.shown && screen == art.screen) {
+        art.shown = placements;
+        art.screen = screen;
+        return Ok(false);
+    }
+
+    if screen != art.screen {
+        art.cell_px = query_cell_px();
+    }
+    if art.protocol == Protocol::Kitty && !art.shown.is_empty() {
+        out.write_all(b"\x1b_Ga=d,d=A,q=2\x1b\\")?;
+    } else if art.shown.iter().any(|old| !placements.contains(old)) {
+        // Nothing is on screen once cleared, so the redraw sends every image
+        art.shown.clear();
+        art.screen = screen;
+        return Ok(true);
+    }
+
+    for p in &placements {
+        let Some(image) = art.images.peek(&p.url) else {
+            continue;
+        };
+        let encoded = match art.protocol {
+            Protocol::Kitty => encode_kitty(image, p.area.width, p.area.height),
+            Protocol::Iterm2 => encode_iterm2(image, p.area.width, p.area.height),
+            Protocol::Sixel => {
+                let (cw, ch) = art.cell_px;
+                let scaled = imageops::resize(
+                    image.as_ref(),
+                    (p.area.width * cw) as u32,
+                    (p.area.height * ch) as u32,
+                    FilterType::Triangle,
+                );
+                encode_sixel(&scaled)
+            }
+            Protocol::HalfBlocks | Protocol::Off => continue,
+        };
+        write!(out, "\x1b[{};{}H", p.area.y + 1, p.area.x + 1)?;
+        out.write_all(encoded.as_bytes())?;
+    }
+    out.flush()?;
+
+    art.shown = placements;
+    art.screen = screen;
+    Ok(false)
+}
+
+/// Cell size in pixels as reported by the terminal
+pub fn query_cell_px() -> (u16, u16) {
+    match crossterm::terminal::window_size() {
+        Ok(size) if size.width > 0 && size.height > 0 && size.columns > 0 && size.rows > 0 => {
+            (size.width / size.columns, size.height / size.rows)
+        }
+        _ => DEFAULT_CELL_PX,
+    }
+}
+
+fn png_base64(image: &RgbaImage) -> String {
+    let mut png = Vec::new();
+    // Encoding an in-memory RGBA buffer cannot fail
+    let _ = image.write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png);
+    STANDARD.encode(png)
+}
+
+/// Kitty graphics protocol: transmit and display a PNG scaled to `cols`x`rows` cells
+fn encode_kitty(image: &RgbaImage, cols: u16, rows: u16) -> String {
+    let payload = png_base64(image);
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK).collect();
+    let mut out = String::with_capacity(payload.len() + chunks.len() * 32);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+        if i == 0 {
+            out.push_str(&format!("\x1b_Ga=T,f=100,q=2,C=1,c={cols},r={rows},m={more};{chunk}\x1b\\"));
+        } else {
+            out.push_str(&format!("\x1b_Gm={more};{chunk}\x1b\\"));
+        }
+    }
+    out
+}
+
+/// iTerm2 inline image (also understood by WezTerm)
+fn encode_iterm2(image: &RgbaImage, cols: u16, rows: u16) -> String {
+    let payload = png_base64(image);
+    format!(
+        "\x1b]1337;File=inline=1;width={cols};height={rows};preserveAspectRatio=1:{payload}\x07"
+    )
+}
+
+/// Sixel with colors quantized to a 6x6x6 cube
+fn encode_sixel(image: &RgbaImage) -> String {
+    let (width, height) = image.dimensions();
+    let level = |c: u8| (c as usize * 5 + 127) / 255;
+    let color = |x: u32, y: u32| {
+        let p = image.get_pixel(x, y);
+        level(p[0]) * 36 + level(p[1]) * 6 + level(p[2])
+    };
+
+    let mut out = format!("\x1bPq\"1;1;{width};{height}");
+
+    let mut used = [false; 216];
+    for (x, y, _) in image.enumerate_pixels() {
+        used[color(x, y)] = true;
+    }
+    for (i, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+        let pct = |v: usize| v * 100 / 5;
+        out.push_str(&format!("#{i};2;{};{};{}", pct(i / 36), pct(i / 6 % 6), pct(i % 6)));
+    }
+
+    for top in (0..height).step_by(6) {
+        // One row of sixels per color present in this band
+        let mut bands: BTreeMap<usize, Vec<u8>> = BTreeMap::new();
+        for dy in 0..6.min(height - top) {
+            for x in 0..width {
+                let bits = bands
+                    .entry(color(x, top + dy))
+                    .or_insert_with(|| vec![0; width as usize]);
+                bits[x as usize] |= 1 << dy;
+            }
+        }
+        for (c, bits) in bands {
+            out.push_str(&format!("#{c}"));
+            push_sixel_runs(&mut out, &bits);
+            out.push('$');
+        }
+        out.push('-');
+    }
+
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Append sixel data with run-length encoding (`!<count><char>`)
+fn push_sixel_runs(out: &mut String, bits: &[u8]) {
+    let mut i = 0;
+    while i < bits.len() {
+        let run = bits[i..].iter().take_while(|b| **b == bits[i]).count();
+        let ch = (bits[i] + 63) as char;
+        if run > 3 {
+            out.push_str(&format!("!{run}{ch}"));
+        } else {
+            out.extend(std::iter::repeat_n(ch, run));
+        }
+        i += run;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_detect_protocol() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |key: &str| vars.iter().find(|(k, _)| *k == key).map(|(_, v)| v.to_string())
+        };
+        assert_eq!(Protocol::detect(env(&[("TERM", "xterm-kitty")])), Protocol::Kitty);
+        assert_eq!(Protocol::detect(env(&[("TERM_PROGRAM", "iTerm.app")])), Protocol::Iterm2);
+        assert_eq!(Protocol::detect(env(&[("TERM", "foot")])), Protocol::Sixel);
+        assert_eq!(
+            Protocol::detect(env(&[("TERM", "xterm-kitty"), ("TMUX", "/tmp/tmux")])),
+            Protocol::HalfBlocks
+        );
+        assert_eq!(Protocol::detect(env(&[("TERM", "xterm-256color")])), Protocol::HalfBlocks);
+        assert_eq!(Protocol::detect(env(&[("TERM", "dumb")])), Protocol::Off);
+    }
+
+    #[test]
+    fn test_fit_keeps_square_art_square() {
+        let image = RgbaImage::new(100, 100);
+        // 8x16 cells: a square needs twice as many columns as rows
+        let area = fit(&image, Rect::new(0, 0, 30, 5), (8, 16));
+        assert_eq!((area.width, area.height), (10, 5));
+        assert_eq!(area.x, 10);
+    }
+
+    #[test]
+    fn test_encode_sixel() {
+        let mut image = RgbaImage::from_pixel(8, 7, Rgba([255, 0, 0, 255]));
+        image.put_pixel(0, 6, Rgba([0, 0, 255, 255]));
+        let sixel = encode_sixel(&image);
+
+        assert!(sixel.starts_with("\x1bPq\"1;1;8;7"));
+        assert!(sixel.ends_with("\x1b\\"));
+        // Red (index 180) and blue (index 5) are the only colors defined
+        assert!(sixel.contains("#180;2;100;0;0"));
+        assert!(sixel.contains("#5;2;0;0;100"));
+        // First band: all six rows set in red for all 8 columns
+        assert!(sixel.contains("#180!8~$"));
+        // Two bands
+        assert_eq!(sixel.matches('-').count(), 2);
+    }
+
+    #[test]
+    fn test_flush_sends_images_only_on_change() {
+        let mut art = ArtState::new(Protocol::Iterm2);
+        art.images.put("u".into(), Arc::new(RgbaImage::new(2, 2)));
+        let screen = Rect::new(0, 0, 80, 24);
+
+        let mut out = Vec::new();
+        art.place("u", Rect::new(3, 4, 10, 5));
+        flush(&mut out, &mut art, screen).unwrap();
+        let first = String::from_utf8(out).unwrap();
+        assert!(first.starts_with("\x1b[5;4H\x1b]1337;File=inline=1;width=10;height=5"));
+
+        let mut out = Vec::new();
+        art.place("u", Rect::new(3, 4, 10, 5));
+        assert!(!flush(&mut out, &mut art, screen).unwrap());
+        assert!(out.is_empty());
+
+        // Moving the image asks for a full repaint, which then sends it again
+        let mut out = Vec::new();
+        art.place("u", Rect::new(3, 6, 10, 5));
+        assert!(flush(&mut out, &mut art, screen).unwrap());
+        assert!(out.is_empty());
+        art.place("u", Rect::new(3, 6, 10, 5));
+        assert!(!flush(&mut out, &mut art, screen).unwrap());
+        assert!(String::from_utf8(out).unwrap().starts_with("\x1b[7;4H"));
+    }
+
+    #[test]
+    fn test_covered_placements_are_dropped() {
+        let mut art = ArtState::new(Protocol::Sixel);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+        let area = Rect::new(2, 2, 4, 3);
+        for pos in area.positions() {
+            buf[pos].set_skip(true);
+        }
+        art.place("u", area);
+        art.drop_covered(&buf);
+        assert_eq!(art.placements.len(), 1);
+
+        // A popup reset one of the reserved cells
+        buf[(3, 3)].reset();
+        art.drop_covered(&buf);
+        assert!(art.placements.is_empty());
+    }
+}
@@ -9,6 +9,7 @@ use crossterm::{
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io::{self, Stdout};
 
+pub mod graphics;
 pub mod theme;
 pub mod widgets;
 
@@ -53,11 +54,23 @@ pub fn draw(terminal: &mut TuiTerminal, cfg: &Config, state: &mut AppState) -> a
             state.toast = None;
         }
 
-    terminal
-        .draw(|f| {
-            widgets::root::render(f, cfg, state);
-        })
-        .context("terminal draw")?;
+    // A second pass only happens after a full clear, which leaves no stale images
+    for _ in 0..2 {
+        let frame = terminal
+            .draw(|f| {
+                widgets::root::render(f, cfg, state);
+            })
+            .context("terminal draw")?;
+        let screen = frame.area;
+        state.art.drop_covered(frame.buffer);
+
+        let repaint =
+            graphics::flush(terminal.backend_mut(), &mut state.art, screen).context("draw album art")?;
+        if !repaint {
+            break;
+        }
+        terminal.clear().context("clear terminal")?;
+    }
     Ok(())
 }
 
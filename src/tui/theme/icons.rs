@@ -25,6 +25,7 @@ pub struct Icons {
 
     // Navigation
     pub home: &'static str,
+    pub explore: &'static str,
     pub search: &'static str,
     pub library: &'static str,
     pub queue: &'static str,
@@ -93,6 +94,7 @@ impl Icons {
 
             // Navigation - mixed nf-*
             home: "\u{f015}",           // nf-fa-home
+            explore: "\u{f14e}",        // nf-fa-compass
             search: "\u{f002}",         // nf-fa-search
             library: "\u{f02d}",        // nf-fa-book
             queue: "\u{f03a}",          // nf-fa-list
@@ -0,0 +1,49 @@
+//! Album art widget - draws a cached image with the active graphics protocol
+
+use crate::tui::graphics::{self, ArtState, Protocol};
+use crate::tui::theme::get_theme;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::Line,
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Draw the image for `url` in `area`, or a placeholder while it loads
+pub fn render(frame: &mut Frame, art: &mut ArtState, url: Option<&str>, area: Rect) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let Some((url, image)) = url.and_then(|u| Some((u, art.images.get(u)?.clone()))) else {
+        let theme = get_theme();
+        let placeholder = Paragraph::new(Line::from(theme.icons.album))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        let middle = Rect::new(area.x, area.y + area.height / 2, area.width, 1);
+        frame.render_widget(placeholder, middle);
+        return;
+    };
+
+    match art.protocol {
+        Protocol::Off => {}
+        Protocol::HalfBlocks => {
+            // Each cell holds two roughly square pixels, one above the other
+            let fitted = graphics::fit(&image, area, (1, 2));
+            let lines = graphics::half_blocks(&image, fitted.width, fitted.height);
+            frame.render_widget(Paragraph::new(lines), fitted);
+        }
+        Protocol::Kitty | Protocol::Sixel | Protocol::Iterm2 => {
+            let fitted = graphics::fit(&image, area, art.cell_px);
+            // Keep ratatui from painting over the image
+            let buf = frame.buffer_mut();
+            for y in fitted.top()..fitted.bottom() {
+                for x in fitted.left()..fitted.right() {
+                    buf[(x, y)].set_skip(true);
+                }
+            }
+            art.place(url, fitted);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Explore screen widget - tabs for Explore, New releases, Charts and Moods & genres
+
+use crate::app::state::{AppState, ExploreTab};
+use crate::tui::theme::{get_theme, LoadingSpinner};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+pub fn render(frame: &mut Frame, state: &mut AppState, area: Rect) {
+    // An album/playlist opened from a shelf takes over the content area
+    if state.explore.page.view.is_open() {
+        super::root::render_playlist_tracks_view(frame, &state.explore.page.view, &mut state.art, state.tick, area);
+        return;
+    }
+
+    let explore = &state.explore;
+
+    // Add padding
+    let padded = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(area)[1];
+
+    // Split into tabs bar and content
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(3)])
+        .split(padded);
+
+    render_tabs(frame, state, layout[0]);
+
+    if explore.showing_moods() {
+        render_moods(frame, state, layout[1]);
+    } else {
+        render_page(frame, state, layout[1]);
+    }
+}
+
+fn render_tabs(frame: &mut Frame, state: &AppState, area: Rect) {
+    let theme = get_theme();
+    let explore = &state.explore;
+
+    let mut spans: Vec<Span> = Vec::new();
+    for (i, tab) in ExploreTab::ALL.iter().enumerate() {
+        let is_selected = explore.tab == *tab;
+        let style = if is_selected {
+            Style::default()
+                .fg(theme.palette.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.palette.fg_secondary)
+        };
+        let bracket_style = if is_selected {
+            Style::default().fg(theme.palette.accent)
+        } else {
+            Style::default().fg(theme.palette.fg_secondary)
+        };
+
+        spans.push(Span::styled("[", bracket_style));
+        spans.push(Span::styled(tab.label(), style));
+        spans.push(Span::styled("]", bracket_style));
+        if i < ExploreTab::ALL.len() - 1 {
+            spans.push(Span::raw("  "));
+        }
+    }
+
+    // Where we are within the tab: chart country or opened mood category
+    let context = match explore.tab {
+        ExploreTab::Charts => Some(format!("{} (c to change)", explore.country_name())),
+        ExploreTab::MoodsGenres => explore
+            .category
+            .as_ref()
+            .map(|c| format!("{} (Esc to go back)", c.title)),
+        _ => None,
+    };
+    if let Some(context) = context {
+        spans.push(Span::styled(
+            format!("   {} ", theme.icons.bullet),
+            Style::default().fg(theme.palette.fg_secondary),
+        ));
+        spans.push(Span::styled(context, Style::default().fg(theme.palette.fg_primary)));
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_page(frame: &mut Frame, state: &AppState, area: Rect) {
+    let theme = get_theme();
+    let page = &state.explore.page;
+
+    if page.loading {
+        let spinner = LoadingSpinner::frame(state.tick);
+        let loading = Paragraph::new(Line::from(format!(
+            "{} Loading {}...",
+            spinner,
+            state.explore.tab.label()
+        )))
+        .style(Style::default().fg(theme.palette.fg_secondary));
+        frame.render_widget(loading, area);
+        return;
+    }
+
+    if page.shelves.is_empty() {
+        let msg = if page.loaded {
+            "Nothing here. Press Ctrl+r to refresh"
+        } else {
+            "Press Ctrl+r to load"
+        };
+        let empty = Paragraph::new(Line::from(msg))
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    super::shelves::render(frame, page, state.tick, area);
+}
+
+fn render_moods(frame: &mut Frame, state: &AppState, area: Rect) {
+    let theme = get_theme();
+    let explore = &state.explore;
+
+    if explore.moods_loading {
+        let spinner = LoadingSpinner::frame(state.tick);
+        let loading = Paragraph::new(Line::from(format!("{} Loading moods & genres...", spinner)))
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        frame.render_widget(loading, area);
+        return;
+    }
+
+    if explore.moods.is_empty() {
+        let msg = if explore.moods_loaded {
+            "No categories found. Press Ctrl+r to refresh"
+        } else {
+            "Press Ctrl+r to load Moods & genres"
+        };
+        let empty = Paragraph::new(Line::from(msg))
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let available = area.height as usize;
+    let max_width = (area.width as usize).saturating_sub(2);
+    let mut lines: Vec<Line> = Vec::new();
+    let mut last_section: Option<&str> = None;
+
+    for (i, category) in explore.moods.iter().enumerate().skip(explore.moods_scroll) {
+        if lines.len() >= available {
+            break;
+        }
+
+        // Section heading whenever the grouping changes
+        if last_section != Some(category.section.as_str()) {
+            if last_section.is_some() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::from(Span::styled(
+                format!("━━ {} ━━", category.section),
+                Style::default().fg(theme.palette.fg_secondary),
+            )));
+            last_section = Some(category.section.as_str());
+        }
+
+        let is_selected = i == explore.moods_selected;
+        let style = if is_selected {
+            Style::default()
+                .fg(theme.palette.bg_primary)
+                .bg(theme.palette.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.palette.fg_primary)
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled(
+                if is_selected { "\u{f054} " } else { "  " },
+                Style::default().fg(theme.palette.accent),
+            ),
+            Span::styled(truncate_str(&category.title, max_width), style),
+        ]));
+    }
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+fn truncate_str(s: &str, max_len: usize) -> String {
+    if max_len == 0 {
+        return String::new();
+    }
+    let char_count: usize = s.chars().count();
+    if char_count <= max_len {
+        s.to_string()
+    } else if max_len > 3 {
+        let truncated: String = s.chars().take(max_len - 3).collect();
+        format!("{}...", truncated)
+    } else {
+        s.chars().take(max_len).collect()
+    }
+}
@@ -43,7 +43,7 @@ pub fn render(frame: &mut Frame, _state: &AppState, area: Rect) {
         keybind("h / Left", "Previous screen", &theme),
         keybind("l / Right", "Next screen", &theme),
         keybind("Tab", "Next screen / Focus search", &theme),
-        keybind("1-5", "Go to screen", &theme),
+        keybind("1-9", "Go to screen", &theme),
         Line::default(),
         section_header("Playback", &theme),
         keybind("Space", "Toggle pause", &theme),
@@ -52,7 +52,26 @@ pub fn render(frame: &mut Frame, _state: &AppState, area: Rect) {
         keybind("- / _", "Volume down", &theme),
         keybind("]", "Seek forward 10s", &theme),
         keybind("[", "Seek back 10s", &theme),
+        keybind("{ / }", "Lyrics 100ms earlier / later", &theme),
+        keybind("S", "Sync plain lyrics (Enter stamps, w saves)", &theme),
+        keybind("L", "Pick LRCLIB lyrics for this track", &theme),
+        keybind("T / Y", "Toggle translations / romanization", &theme),
         keybind("R", "Toggle repeat mode", &theme),
+        keybind("r", "Start radio from selection", &theme),
+        keybind("e", "Play selected track next", &theme),
+        Line::default(),
+        section_header("Queue", &theme),
+        keybind("d / c", "Remove track / clear queue", &theme),
+        keybind("K / J", "Move track up / down", &theme),
+        keybind("u / Ctrl+r", "Undo / redo queue edits", &theme),
+        keybind("w", "Save queue as a local playlist", &theme),
+        Line::default(),
+        section_header("Selection", &theme),
+        keybind("v", "Start / stop selecting tracks", &theme),
+        keybind("Space / V", "Mark track / mark range", &theme),
+        keybind("a / e", "Add to queue / play next", &theme),
+        keybind("f / P", "Like / add to playlist", &theme),
+        keybind("K / J / d", "Move / remove marked in queue", &theme),
     ];
 
     let left_para = Paragraph::new(left_content).wrap(Wrap { trim: false });
@@ -68,6 +87,24 @@ pub fn render(frame: &mut Frame, _state: &AppState, area: Rect) {
         keybind("Ctrl+u", "Clear input", &theme),
         keybind("Down", "Focus results", &theme),
         Line::default(),
+        section_header("Home / Explore", &theme),
+        keybind("j / k", "Next / previous shelf", &theme),
+        keybind("h / l", "Scroll shelf left / right", &theme),
+        keybind("Enter", "Play / open item", &theme),
+        keybind("a", "Add track to queue", &theme),
+        keybind("Tab", "Next Explore tab", &theme),
+        keybind("c", "Change chart country", &theme),
+        Line::default(),
+        section_header("Local playlists", &theme),
+        keybind("N / E / D", "New / rename / delete (Library)", &theme),
+        keybind("K / J / d", "Move / remove tracks when open", &theme),
+        Line::default(),
+        section_header("Lyrics", &theme),
+        keybind("j / k", "Move the line cursor", &theme),
+        keybind("Enter/click", "Seek to the line", &theme),
+        keybind("f", "Follow playback again", &theme),
+        keybind("/", "Search lyrics, n / N for matches", &theme),
+        Line::default(),
         section_header("General", &theme),
         keybind("q", "Quit application", &theme),
         keybind("Ctrl+r", "Refresh current screen", &theme),
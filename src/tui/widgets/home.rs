@@ -0,0 +1,54 @@
+//! Home screen widget - renders YTM home shelves as titled sections
+
+use crate::app::state::AppState;
+use crate::tui::theme::{get_theme, LoadingSpinner};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::Paragraph,
+    Frame,
+};
+
+pub fn render(frame: &mut Frame, state: &mut AppState, area: Rect) {
+    // An album/playlist opened from a shelf takes over the content area
+    if state.home.view.is_open() {
+        super::root::render_playlist_tracks_view(frame, &state.home.view, &mut state.art, state.tick, area);
+        return;
+    }
+
+    let theme = get_theme();
+    let home = &state.home;
+
+    // Add padding
+    let padded = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(area)[1];
+
+    if home.loading {
+        let spinner = LoadingSpinner::frame(state.tick);
+        let loading = Paragraph::new(Line::from(format!("{} Loading home...", spinner)))
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        frame.render_widget(loading, padded);
+        return;
+    }
+
+    if home.shelves.is_empty() {
+        let msg = if home.loaded {
+            "Nothing on Home yet. Press Ctrl+r to refresh"
+        } else {
+            "Press Ctrl+r to load Home"
+        };
+        let empty = Paragraph::new(Line::from(msg))
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        frame.render_widget(empty, padded);
+        return;
+    }
+
+    super::shelves::render(frame, home, state.tick, padded);
+}
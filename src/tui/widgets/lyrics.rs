@@ -0,0 +1,150 @@
+//! Full-screen lyrics - the sung line centered, a free cursor and search
+
+use crate::app::state::AppState;
+use crate::lyrics::parser::LrcLine;
+use crate::lyrics::romanize::romanize;
+use crate::tui::theme::get_theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+pub fn render(frame: &mut Frame, state: &mut AppState, area: Rect) {
+    let theme = get_theme();
+
+    // Search bar at the bottom while typing or while a query is active
+    let show_search = state.lyrics_view.searching || !state.lyrics_view.search_query.is_empty();
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(if show_search { 1 } else { 0 }),
+        ])
+        .split(area);
+    let lines_area = layout[0];
+
+    if show_search {
+        let view = &state.lyrics_view;
+        let hint = if view.searching { "" } else { "  n/N next/prev · Esc clear" };
+        let bar = Line::from(vec![
+            Span::styled("/", Style::default().fg(theme.palette.accent)),
+            Span::styled(view.search_query.clone(), Style::default().fg(theme.palette.fg_primary)),
+            Span::styled(
+                if view.searching { "▏" } else { "" },
+                Style::default().fg(theme.palette.accent),
+            ),
+            Span::styled(hint, Style::default().fg(theme.palette.fg_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(bar), layout[1]);
+    }
+
+    let Some(lyrics) = &state.lyrics else {
+        let msg = if state.lyrics_loading { "Loading..." } else { "No lyrics available" };
+        let placeholder = Paragraph::new(Line::from(msg))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        let middle = Rect::new(lines_area.x, lines_area.y + lines_area.height / 2, lines_area.width, 1);
+        frame.render_widget(placeholder, middle);
+        return;
+    };
+
+    let position_ms = state.lyrics_position_ms();
+    let active = lyrics.line_at(position_ms);
+    let cursor = state.lyrics_cursor();
+    let browsing = state.lyrics_view.browsing;
+    let query = state.lyrics_view.search_query.to_lowercase();
+
+    let max_width = lines_area.width.saturating_sub(2) as usize;
+    let secondary_style = Style::default()
+        .fg(theme.palette.fg_secondary)
+        .add_modifier(Modifier::ITALIC);
+
+    // Every row with the lyrics line it belongs to; translations take rows of their own
+    let mut rows: Vec<(usize, Line)> = Vec::new();
+    for (i, line) in lyrics.lines.iter().enumerate() {
+        let is_active = lyrics.synced && i == active;
+        let mut style = if is_active {
+            Style::default().fg(theme.palette.accent).add_modifier(Modifier::BOLD)
+        } else if lyrics.synced && i < active {
+            Style::default().fg(theme.palette.fg_secondary)
+        } else {
+            Style::default().fg(theme.palette.fg_primary)
+        };
+        if !query.is_empty() && line.text.to_lowercase().contains(&query) {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        if browsing && i == cursor {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+
+        let primary = if is_active && !line.words.is_empty() {
+            let upcoming = Style::default().fg(theme.palette.fg_primary);
+            Line::from(karaoke_spans(line, position_ms, max_width, style, upcoming))
+        } else {
+            let text: String = line.text.chars().take(max_width).collect();
+            Line::from(Span::styled(text, style))
+        };
+        rows.push((i, primary));
+        for extra in secondary_lines(state, line) {
+            let text: String = extra.chars().take(max_width).collect();
+            rows.push((i, Line::from(Span::styled(text, secondary_style))));
+        }
+    }
+
+    // Keep the cursor (the active line unless browsing) in the middle
+    let height = lines_area.height as usize;
+    let cursor_row = rows.iter().position(|(i, _)| *i == cursor).unwrap_or(0);
+    let top = cursor_row
+        .saturating_sub(height / 2)
+        .min(rows.len().saturating_sub(height));
+    let (row_lines, display_lines): (Vec<usize>, Vec<Line>) =
+        rows.into_iter().skip(top).take(height).unzip();
+
+    frame.render_widget(Paragraph::new(display_lines).alignment(Alignment::Center), lines_area);
+
+    // Remember the layout so mouse clicks can find their line
+    state.lyrics_view.rows = row_lines;
+    state.lyrics_view.area = lines_area;
+}
+
+/// Rows shown under `line`: its romanization, when enabled and there is
+/// kana or hangul, then its translations
+pub fn secondary_lines(state: &AppState, line: &LrcLine) -> Vec<String> {
+    let mut extra = Vec::new();
+    if state.lyrics_romanize
+        && let Some(romanized) = romanize(&line.text)
+    {
+        extra.push(romanized);
+    }
+    if state.lyrics_show_secondary {
+        extra.extend(line.secondary.iter().cloned());
+    }
+    extra
+}
+
+/// Words of `line` styled `sung` up to `position_ms` and `upcoming` after,
+/// cut off at `max_width` characters
+pub fn karaoke_spans(
+    line: &LrcLine,
+    position_ms: u64,
+    max_width: usize,
+    sung: Style,
+    upcoming: Style,
+) -> Vec<Span<'_>> {
+    let mut spans = Vec::new();
+    let mut width = 0;
+    for word in &line.words {
+        let remaining = max_width.saturating_sub(width);
+        if remaining == 0 {
+            break;
+        }
+        let style = if word.time_ms <= position_ms { sung } else { upcoming };
+        let text: String = word.text.chars().take(remaining).collect();
+        width += text.chars().count();
+        spans.push(Span::styled(text, style));
+    }
+    spans
+}
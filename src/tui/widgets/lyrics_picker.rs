@@ -0,0 +1,95 @@
+//! LRCLIB candidate picker - lists search results so the right lyrics can be pinned
+
+use crate::app::state::LyricsPickerState;
+use crate::tui::theme::get_theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+pub fn render(frame: &mut Frame, picker: &LyricsPickerState, area: Rect) {
+    let theme = get_theme();
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(area);
+
+    let header = Line::from(vec![
+        Span::styled(
+            format!("{} ", picker.title),
+            Style::default().fg(theme.palette.fg_primary).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            "Enter pins · Esc closes",
+            Style::default().fg(theme.palette.fg_secondary),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(header), layout[0]);
+
+    let status = if picker.loading {
+        Some("Searching LRCLIB...")
+    } else if picker.candidates.is_empty() {
+        Some("LRCLIB has nothing for this track")
+    } else {
+        None
+    };
+    if let Some(status) = status {
+        let msg = Paragraph::new(Line::from(status))
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        frame.render_widget(msg, layout[1]);
+        return;
+    }
+
+    let items: Vec<ListItem> = picker
+        .candidates
+        .iter()
+        .map(|(candidate, score)| {
+            let kind = if candidate.synced_lyrics.as_deref().is_some_and(|s| !s.is_empty()) {
+                "synced"
+            } else {
+                "plain"
+            };
+            let duration = candidate
+                .duration
+                .map(|d| format!(" · {}:{:02}", d as u32 / 60, d as u32 % 60))
+                .unwrap_or_default();
+            let album = candidate
+                .album_name
+                .as_deref()
+                .map(|a| format!(" · {a}"))
+                .unwrap_or_default();
+
+            // Results scored as other songs stay listed, but dimmed
+            let style = if score.is_some() {
+                Style::default().fg(theme.palette.fg_primary)
+            } else {
+                Style::default().fg(theme.palette.fg_secondary)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} - {}{}{}", candidate.track_name, candidate.artist_name, album, duration),
+                    style,
+                ),
+                Span::styled(format!("  [{kind}]"), Style::default().fg(theme.palette.fg_secondary)),
+            ]))
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(picker.selected));
+
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .fg(theme.palette.bg_primary)
+                .bg(theme.palette.accent)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{f054} ");
+
+    frame.render_stateful_widget(list, layout[1], &mut list_state);
+}
@@ -1,7 +1,15 @@
+pub mod art;
+pub mod explore;
 pub mod help;
+pub mod home;
+pub mod lyrics;
+pub mod lyrics_picker;
+pub mod name_prompt;
 pub mod now_playing;
+pub mod playlist_picker;
 pub mod queue;
 pub mod root;
 pub mod settings;
+pub mod shelves;
 pub mod sidebar;
 pub mod track_list;
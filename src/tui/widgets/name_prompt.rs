@@ -0,0 +1,48 @@
+//! Name prompt - types the name of a new or renamed local playlist
+
+use crate::app::state::{NamePromptPurpose, NamePromptState};
+use crate::tui::theme::get_theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+pub fn render(frame: &mut Frame, prompt: &NamePromptState, area: Rect) {
+    let theme = get_theme();
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let what = match prompt.purpose {
+        NamePromptPurpose::NewPlaylist => "Name the new playlist",
+        NamePromptPurpose::SaveQueue => "Save the queue as a playlist named",
+        NamePromptPurpose::Rename(_) => "Rename the playlist to",
+    };
+    let header = Line::from(vec![
+        Span::styled(
+            format!("{what} "),
+            Style::default().fg(theme.palette.fg_primary).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            "Enter saves · Esc cancels",
+            Style::default().fg(theme.palette.fg_secondary),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(header), layout[0]);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(theme.border_set())
+        .border_style(Style::default().fg(theme.palette.accent))
+        .title(" Name ")
+        .title_style(Style::default().fg(theme.palette.accent));
+    let input = Paragraph::new(Line::from(format!("{}▏", prompt.input)))
+        .style(Style::default().fg(theme.palette.fg_primary))
+        .block(block);
+    frame.render_widget(input, layout[1]);
+}
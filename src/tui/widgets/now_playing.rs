@@ -1,4 +1,4 @@
-//! Now Playing widget - compact text-only player for bottom bar
+//! Now Playing widget - compact player for bottom bar, with album art when available
 
 use crate::app::state::{AppState, RepeatMode, ToastKind};
 use crate::tui::theme::get_theme;
@@ -23,17 +23,33 @@ pub fn render(frame: &mut Frame, state: &mut AppState, area: Rect) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
+    // Album art on the left: a square is about twice as many columns as rows
+    let art_url = state
+        .current_track
+        .as_ref()
+        .and_then(|t| t.thumbnail_url(crate::art::ART_MIN_WIDTH))
+        .filter(|_| state.art.enabled())
+        .map(str::to_string);
+    let art_width = if art_url.is_some() { inner.height * 2 } else { 0 };
+
     // Add horizontal padding
-    let padded = Layout::default()
+    let cols = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length(1),  // Left padding
-            Constraint::Min(1),     // Content
-            Constraint::Length(1),  // Right padding
+            Constraint::Length(1),         // Left padding
+            Constraint::Length(art_width), // Album art
+            Constraint::Length(u16::from(art_width > 0)),
+            Constraint::Min(1),            // Content
+            Constraint::Length(1),         // Right padding
         ])
-        .split(inner)[1];
+        .split(inner);
+    let padded = cols[3];
+
+    if art_url.is_some() {
+        super::art::render(frame, &mut state.art, art_url.as_deref(), cols[1]);
+    }
 
-    // Simple vertical layout for text-only player
+    // Simple vertical layout for the player text
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -141,6 +157,17 @@ pub fn render(frame: &mut Frame, state: &mut AppState, area: Rect) {
         }
     }
 
+    // Rows marked in the list in front
+    if let Some((selection, _)) = state.active_selection()
+        && selection.is_active()
+    {
+        controls_spans.push(Span::raw("  "));
+        controls_spans.push(Span::styled(
+            format!("{} selected", selection.len()),
+            Style::default().fg(theme.palette.accent),
+        ));
+    }
+
     frame.render_widget(Paragraph::new(Line::from(controls_spans)), rows[4]);
 
     // Toast messages if any (row 5)
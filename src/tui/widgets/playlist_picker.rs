@@ -0,0 +1,87 @@
+//! Playlist picker - chooses the playlist the selected tracks are added to
+
+use crate::app::state::{PlaylistListState, PlaylistPickerState};
+use crate::tui::theme::get_theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+pub fn render(frame: &mut Frame, picker: &PlaylistPickerState, playlists: &PlaylistListState, area: Rect) {
+    let theme = get_theme();
+    let icons = &theme.icons;
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(area);
+
+    let what = match picker.tracks.as_slice() {
+        [one] => format!("\"{}\"", one.title),
+        many => format!("{} tracks", many.len()),
+    };
+    let header = Line::from(vec![
+        Span::styled(
+            format!("Add {what} to... "),
+            Style::default().fg(theme.palette.fg_primary).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            "Enter adds · Esc closes",
+            Style::default().fg(theme.palette.fg_secondary),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(header), layout[0]);
+
+    // Local playlists can be picked while the account's are still loading
+    let status = if !playlists.is_empty() {
+        None
+    } else if playlists.loading {
+        Some("Loading playlists...")
+    } else {
+        Some("No playlists yet (N in Library > Playlists creates a local one)")
+    };
+    if let Some(status) = status {
+        let msg = Paragraph::new(Line::from(status))
+            .style(Style::default().fg(theme.palette.fg_secondary));
+        frame.render_widget(msg, layout[1]);
+        return;
+    }
+
+    let items: Vec<ListItem> = playlists
+        .local
+        .iter()
+        .map(|playlist| (playlist, true))
+        .chain(playlists.playlists.iter().map(|playlist| (playlist, false)))
+        .map(|(playlist, local)| {
+            let track_count = playlist
+                .track_count
+                .map(|c| format!(" ({} tracks)", c))
+                .unwrap_or_default();
+            let mut spans = vec![Span::styled(
+                format!("{} {}{}", icons.playlist, playlist.title, track_count),
+                Style::default().fg(theme.palette.fg_primary),
+            )];
+            if local {
+                spans.push(Span::styled(" · local", Style::default().fg(theme.palette.fg_secondary)));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(picker.selected));
+
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .fg(theme.palette.bg_primary)
+                .bg(theme.palette.accent)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{f054} ");
+
+    frame.render_stateful_widget(list, layout[1], &mut list_state);
+}
@@ -27,7 +27,7 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
     let queue = &state.queue;
 
     if queue.is_empty() {
-        let empty_msg = Line::from(vec![
+        let mut empty_msg = Line::from(vec![
             Span::styled(
                 "Queue is empty. ",
                 Style::default().fg(theme.palette.fg_secondary),
@@ -37,21 +37,28 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
                 Style::default().fg(theme.palette.fg_secondary),
             ),
         ]);
+        if queue.can_undo() {
+            empty_msg.push_span(Span::styled(
+                " u: Undo",
+                Style::default().fg(theme.palette.accent),
+            ));
+        }
         let paragraph = Paragraph::new(empty_msg);
         frame.render_widget(paragraph, padded);
         return;
     }
 
-    // Header line with shuffle status
-    let header = Line::from(vec![
+    // Header line with shuffle and radio status
+    let mut header_spans = vec![
         Span::styled(
             format!("{} tracks", queue.len()),
             Style::default().fg(theme.palette.fg_secondary),
         ),
         Span::raw("  "),
         if queue.is_shuffle_enabled() {
+            let label = if queue.is_smart_shuffle() { "Smart shuffle ON" } else { "Shuffle ON" };
             Span::styled(
-                format!("{} Shuffle ON", icons.shuffle),
+                format!("{} {label}", icons.shuffle),
                 Style::default().fg(theme.palette.accent),
             )
         } else {
@@ -60,7 +67,15 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
                 Style::default().fg(theme.palette.fg_secondary),
             )
         },
-    ]);
+    ];
+    if let Some(seed) = &state.radio.seed_title {
+        header_spans.push(Span::raw("  "));
+        header_spans.push(Span::styled(
+            format!("{} Radio: {}", icons.radio, seed),
+            Style::default().fg(theme.palette.accent),
+        ));
+    }
+    let header = Line::from(header_spans);
 
     // Track list
     let tracks = queue.tracks();
@@ -70,10 +85,24 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
 
     let visible_height = padded.height.saturating_sub(2) as usize; // -2 for header and hints
     let max_width = padded.width.saturating_sub(6) as usize; // -6 for index and icons
+    let up_next = queue.up_next_range();
+
+    // Every row, with the track it shows; up next gets its own section
+    let section = |title: &str| {
+        Line::from(Span::styled(
+            title.to_string(),
+            Style::default().fg(theme.palette.accent).add_modifier(Modifier::BOLD),
+        ))
+    };
+    let mut rows: Vec<(Option<usize>, Line)> = Vec::with_capacity(tracks.len() + 2);
+    for (i, track) in tracks.iter().enumerate() {
+        if !up_next.is_empty() && i == up_next.start {
+            rows.push((None, section("Up next")));
+        }
+        if !up_next.is_empty() && i == up_next.end {
+            rows.push((None, section("Later")));
+        }
 
-    let mut lines: Vec<Line> = vec![header, Line::default()];
-
-    for (i, track) in tracks.iter().enumerate().skip(scroll_offset).take(visible_height) {
         let is_current = current_idx == Some(i);
         let is_selected = i == selected_idx;
 
@@ -97,6 +126,10 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
                 .fg(theme.palette.fg_primary)
                 .bg(theme.palette.bg_highlight)
                 .add_modifier(Modifier::BOLD)
+        } else if state.queue_list.selection.contains(i) {
+            Style::default()
+                .fg(theme.palette.accent_alt)
+                .bg(theme.palette.bg_highlight)
         } else if is_current {
             Style::default()
                 .fg(theme.palette.accent)
@@ -111,13 +144,30 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
             Style::default().fg(theme.palette.fg_secondary)
         };
 
-        lines.push(Line::from(vec![
-            Span::styled(prefix, prefix_style),
-            Span::styled(index_str, Style::default().fg(theme.palette.fg_secondary)),
-            Span::styled(display, style),
-        ]));
+        rows.push((
+            Some(i),
+            Line::from(vec![
+                Span::styled(prefix, prefix_style),
+                Span::styled(index_str, Style::default().fg(theme.palette.fg_secondary)),
+                Span::styled(display, style),
+            ]),
+        ));
+    }
+
+    // Start at the scrolled-to track, but section rows must not push the selection out of view
+    let row_of = |track: usize| rows.iter().position(|(i, _)| *i == Some(track)).unwrap_or(0);
+    let mut top = row_of(scroll_offset);
+    if top > 0 && rows[top - 1].0.is_none() {
+        top -= 1;
+    }
+    let selected_row = row_of(selected_idx);
+    if selected_row >= top + visible_height {
+        top = selected_row + 1 - visible_height;
     }
 
+    let mut lines: Vec<Line> = vec![header, Line::default()];
+    lines.extend(rows.into_iter().skip(top).take(visible_height).map(|(_, line)| line));
+
     // Hints at the bottom
     if lines.len() < (padded.height as usize) {
         let remaining = (padded.height as usize) - lines.len();
@@ -126,7 +176,7 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
         }
         lines.push(Line::from(vec![
             Span::styled(
-                "Enter: Play  d: Remove  c: Clear  s: Shuffle  K/J: Move",
+                "Enter: Play  d: Remove  c: Clear  s: Shuffle  K/J: Move  v: Select  w: Save  u/^R: Undo/Redo  r: Radio",
                 Style::default().fg(theme.palette.fg_secondary),
             ),
         ]));
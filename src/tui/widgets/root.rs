@@ -1,7 +1,8 @@
 //! Root layout widget - orchestrates main layout structure
 
-use crate::app::state::{AppState, LibraryTab, Screen};
+use crate::app::state::{AppState, LibraryTab, PlaylistViewState, Screen};
 use crate::config::Config;
+use crate::tui::graphics::ArtState;
 use crate::tui::theme::get_theme;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -11,13 +12,17 @@ use ratatui::{
     Frame,
 };
 
-use super::{help, now_playing, queue, settings, sidebar, track_list};
+use super::{
+    art, explore, help, home, lyrics, lyrics_picker, name_prompt, now_playing, playlist_picker,
+    queue, settings, sidebar, track_list,
+};
 
 /// Main layout structure:
 /// ┌──────────┬─────────────────────────────────────────┐
 /// │  Menu    │           Main Content                  │
-/// │          │         (History/Search/                │
-/// │  History │          Library/etc)                   │
+/// │  Home    │       (Home/History/Search/             │
+/// │  Explore │          Library/etc)                   │
+/// │  History │                                         │
 /// │  Search  │                                         │
 /// │  Library │                                         │
 /// │  Settings│                                         │
@@ -66,11 +71,25 @@ fn render_lyrics_section(frame: &mut Frame, state: &AppState, area: Rect) {
     let theme = get_theme();
     let icons = &theme.icons;
 
+    // Credit where the lyrics came from
+    let mut source = state
+        .lyrics
+        .as_ref()
+        .and_then(|l| l.source.as_deref())
+        .map(|s| format!("· {} ", crate::lyrics::provider_label(s)))
+        .unwrap_or_default();
+    if state.lyrics.is_some() && state.lyrics_offset_ms != 0 {
+        source.push_str(&format!("· {:+}ms ", state.lyrics_offset_ms));
+    }
+    if let Some(session) = &state.lyrics_sync {
+        source = format!("· Sync {}/{} ", session.next_index(), session.lines().len());
+    }
+
     let block = Block::default()
         .borders(Borders::ALL)
         .border_set(theme.border_set())
         .border_style(Style::default().fg(theme.palette.border))
-        .title(format!(" {} Lyrics ", icons.lyrics))
+        .title(format!(" {} Lyrics {}", icons.lyrics, source))
         .title_style(Style::default().fg(theme.palette.accent));
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -87,6 +106,11 @@ fn render_lyrics_section(frame: &mut Frame, state: &AppState, area: Rect) {
 
     use ratatui::layout::Alignment;
 
+    if let Some(session) = &state.lyrics_sync {
+        render_sync_lines(frame, session, padded);
+        return;
+    }
+
     let Some(lyrics) = &state.lyrics else {
         let content = Line::from(Span::styled(
             if state.lyrics_loading { "Loading..." } else { "No lyrics available" },
@@ -108,19 +132,8 @@ fn render_lyrics_section(frame: &mut Frame, state: &AppState, area: Rect) {
     }
 
     // Find current line based on position
-    let position_ms = (state.position_secs * 1000.0) as u64;
-    let current_idx = if lyrics.synced {
-        lyrics
-            .lines
-            .iter()
-            .enumerate()
-            .filter(|(_, l)| l.time_ms <= position_ms)
-            .map(|(i, _)| i)
-            .next_back()
-            .unwrap_or(0)
-    } else {
-        0
-    };
+    let position_ms = state.lyrics_position_ms();
+    let current_idx = lyrics.line_at(position_ms);
 
     let max_width = padded.width.saturating_sub(4) as usize;
 
@@ -134,7 +147,9 @@ fn render_lyrics_section(frame: &mut Frame, state: &AppState, area: Rect) {
     let mut display_lines: Vec<Line> = Vec::new();
 
     for i in start_idx..end_idx {
-        let line_text = lyrics.lines.get(i).map(|l| l.text.as_str()).unwrap_or("");
+        let Some(line) = lyrics.lines.get(i) else {
+            continue;
+        };
         let is_current = i == current_idx;
 
         let style = if is_current {
@@ -147,10 +162,28 @@ fn render_lyrics_section(frame: &mut Frame, state: &AppState, area: Rect) {
 
         let prefix = if is_current { "♪ " } else { "  " };
 
-        display_lines.push(Line::from(vec![
-            Span::styled(prefix, style),
-            Span::styled(truncate_str(line_text, max_width), style),
-        ]));
+        let mut spans = vec![Span::styled(prefix, style)];
+        if is_current && lyrics.synced && !line.words.is_empty() {
+            // Karaoke: words light up as they are sung
+            let upcoming = Style::default().fg(theme.palette.fg_primary);
+            spans.extend(lyrics::karaoke_spans(line, position_ms, max_width, style, upcoming));
+        } else {
+            spans.push(Span::styled(truncate_str(&line.text, max_width), style));
+        }
+        display_lines.push(Line::from(spans));
+
+        // Translation and romanization of the line being sung
+        if is_current {
+            let secondary_style = Style::default()
+                .fg(theme.palette.fg_secondary)
+                .add_modifier(Modifier::ITALIC);
+            for extra in lyrics::secondary_lines(state, line) {
+                display_lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(truncate_str(&extra, max_width), secondary_style),
+                ]));
+            }
+        }
     }
 
     // Center vertically
@@ -166,6 +199,45 @@ fn render_lyrics_section(frame: &mut Frame, state: &AppState, area: Rect) {
     frame.render_widget(paragraph, padded);
 }
 
+/// Sync mode: stamped lines with their times around the next line to stamp
+fn render_sync_lines(frame: &mut Frame, session: &crate::lyrics::SyncSession, area: Rect) {
+    let theme = get_theme();
+    let next = session.next_index();
+    let max_width = area.width.saturating_sub(12) as usize;
+
+    let start = next.saturating_sub(1);
+    let end = (next + 2).min(session.lines().len());
+    let mut display_lines: Vec<Line> = Vec::new();
+    for (i, text) in session.lines().iter().enumerate().take(end).skip(start) {
+        let (stamp, style) = match session.stamp_at(i) {
+            Some(ms) => (
+                format!("{} ", crate::lyrics::parser::format_timestamp(ms)),
+                Style::default().fg(theme.palette.fg_secondary),
+            ),
+            None if i == next => (
+                "▶        ".to_string(),
+                Style::default().fg(theme.palette.accent).add_modifier(Modifier::BOLD),
+            ),
+            None => ("         ".to_string(), Style::default().fg(theme.palette.fg_primary)),
+        };
+        display_lines.push(Line::from(vec![
+            Span::styled(stamp, Style::default().fg(theme.palette.fg_secondary)),
+            Span::styled(truncate_str(text, max_width), style),
+        ]));
+    }
+    if session.is_complete() {
+        display_lines.push(Line::from(Span::styled(
+            "w save · P save & publish · Esc cancel",
+            Style::default().fg(theme.palette.accent),
+        )));
+    }
+
+    let top_padding = (area.height as usize).saturating_sub(display_lines.len()) / 2;
+    let mut centered_lines: Vec<Line> = vec![Line::default(); top_padding];
+    centered_lines.extend(display_lines);
+    frame.render_widget(Paragraph::new(centered_lines), area);
+}
+
 fn truncate_str(s: &str, max_len: usize) -> String {
     if max_len == 0 {
         return String::new();
@@ -187,11 +259,18 @@ fn render_main_content(frame: &mut Frame, cfg: &Config, state: &mut AppState, ar
     let icons = &theme.icons;
 
     // Get title with icon for current screen
+    // The pickers take over the main area while open
     let title = match state.screen {
+        _ if state.lyrics_picker.is_some() => format!(" {} Pick lyrics ", icons.lyrics),
+        _ if state.playlist_picker.is_some() => format!(" {} Add to playlist ", icons.playlist),
+        _ if state.name_prompt.is_some() => format!(" {} Name playlist ", icons.playlist),
+        Screen::Home => format!(" {} Home ", icons.home),
+        Screen::Explore => format!(" {} Explore ", icons.explore),
         Screen::History => format!(" {} History ", icons.history),
         Screen::Search => format!(" {} Search ", icons.search),
         Screen::Queue => format!(" {} Queue ", icons.queue),
         Screen::Library => format!(" {} Library ", icons.library),
+        Screen::Lyrics => format!(" {} Lyrics ", icons.lyrics),
         Screen::Settings => format!(" {} Settings ", icons.settings),
         Screen::Help => format!(" {} Keybinds ", icons.help),
     };
@@ -205,7 +284,26 @@ fn render_main_content(frame: &mut Frame, cfg: &Config, state: &mut AppState, ar
     let inner = main.inner(area);
     frame.render_widget(main, area);
 
+    if let Some(picker) = &state.lyrics_picker {
+        lyrics_picker::render(frame, picker, inner);
+        return;
+    }
+    if let Some(picker) = &state.playlist_picker {
+        playlist_picker::render(frame, picker, &state.playlist_list, inner);
+        return;
+    }
+    if let Some(prompt) = &state.name_prompt {
+        name_prompt::render(frame, prompt, inner);
+        return;
+    }
+
     match state.screen {
+        Screen::Home => {
+            home::render(frame, state, inner);
+        }
+        Screen::Explore => {
+            explore::render(frame, state, inner);
+        }
         Screen::Search => {
             let sub = Layout::default()
                 .direction(Direction::Vertical)
@@ -226,6 +324,9 @@ fn render_main_content(frame: &mut Frame, cfg: &Config, state: &mut AppState, ar
         Screen::Library => {
             render_library_with_tabs(frame, cfg, state, inner);
         }
+        Screen::Lyrics => {
+            lyrics::render(frame, state, inner);
+        }
         Screen::Help => {
             help::render(frame, state, inner);
         }
@@ -300,72 +401,66 @@ fn render_library_with_tabs(frame: &mut Frame, cfg: &Config, state: &mut AppStat
 }
 
 /// Render the playlists list in the Library
-fn render_playlists_list(frame: &mut Frame, state: &AppState, area: Rect) {
+fn render_playlists_list(frame: &mut Frame, state: &mut AppState, area: Rect) {
     // If playlist view is open, render that instead
     if state.playlist_view.is_open() {
-        render_playlist_tracks_view(frame, state, area);
+        render_playlist_tracks_view(frame, &state.playlist_view, &mut state.art, state.tick, area);
         return;
     }
 
     let theme = get_theme();
-    let icons = &theme.icons;
 
     let playlist_state = &state.playlist_list;
-
-    if playlist_state.loading {
-        let spinner = crate::tui::theme::LoadingSpinner::frame(state.tick);
-        let loading = Paragraph::new(Line::from(format!("{} Loading playlists...", spinner)))
-            .style(Style::default().fg(theme.palette.fg_secondary));
-        frame.render_widget(loading, area);
-        return;
+    let header_style = Style::default()
+        .fg(theme.palette.accent_alt)
+        .add_modifier(Modifier::BOLD);
+    let note_style = Style::default().fg(theme.palette.fg_secondary);
+
+    // Local playlists first, then the account's, each under a header; only
+    // playlist rows can be selected
+    let mut items = vec![ListItem::new(Line::from(Span::styled("Local", header_style)))];
+    let mut selected_row = None;
+    if playlist_state.local.is_empty() {
+        items.push(ListItem::new(Line::from(Span::styled(
+            "  N creates one · w in the queue saves it as one",
+            note_style,
+        ))));
     }
-
-    if playlist_state.playlists.is_empty() {
-        let msg = if playlist_state.loaded {
-            "No playlists found. Create some on YouTube Music!"
-        } else {
-            "Press Tab to load playlists (requires authentication)"
-        };
-        let empty = Paragraph::new(Line::from(msg))
-            .style(Style::default().fg(theme.palette.fg_secondary));
-        frame.render_widget(empty, area);
-        return;
+    for (i, playlist) in playlist_state.local.iter().enumerate() {
+        if i == playlist_state.selected {
+            selected_row = Some(items.len());
+        }
+        items.push(playlist_item(playlist, i == playlist_state.selected));
     }
 
-    let visible_height = area.height as usize;
-    let scroll_offset = playlist_state.scroll_offset;
-
-    let items: Vec<ListItem> = playlist_state
-        .playlists
-        .iter()
-        .enumerate()
-        .skip(scroll_offset)
-        .take(visible_height)
-        .map(|(i, playlist)| {
-            let is_selected = i == playlist_state.selected;
-
-            let style = if is_selected {
-                Style::default()
-                    .fg(theme.palette.accent)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(theme.palette.fg_primary)
-            };
-
-            let track_count = playlist
-                .track_count
-                .map(|c| format!(" ({} tracks)", c))
-                .unwrap_or_default();
-
-            let display = format!("{} {}{}", icons.playlist, playlist.title, track_count);
-
-            ListItem::new(Line::from(Span::styled(display, style)))
+    items.push(ListItem::new(Line::default()));
+    items.push(ListItem::new(Line::from(Span::styled("YouTube Music", header_style))));
+    let note = if playlist_state.loading {
+        let spinner = crate::tui::theme::LoadingSpinner::frame(state.tick);
+        Some(format!("  {} Loading playlists...", spinner))
+    } else if playlist_state.playlists.is_empty() {
+        Some(if playlist_state.loaded {
+            "  No playlists found. Create some on YouTube Music!".to_string()
+        } else {
+            "  Press Tab to load playlists (requires authentication)".to_string()
         })
-        .collect();
+    } else {
+        None
+    };
+    if let Some(note) = note {
+        items.push(ListItem::new(Line::from(Span::styled(note, note_style))));
+    }
+    for (i, playlist) in playlist_state.playlists.iter().enumerate() {
+        let index = playlist_state.local.len() + i;
+        if index == playlist_state.selected {
+            selected_row = Some(items.len());
+        }
+        items.push(playlist_item(playlist, index == playlist_state.selected));
+    }
 
-    let adjusted_selected = playlist_state.selected.saturating_sub(scroll_offset);
+    // The list scrolls itself to keep the selected row in view
     let mut list_state = ratatui::widgets::ListState::default();
-    list_state.select(Some(adjusted_selected));
+    list_state.select(selected_row);
 
     let list = List::new(items)
         .highlight_style(
@@ -379,6 +474,27 @@ fn render_playlists_list(frame: &mut Frame, state: &AppState, area: Rect) {
     frame.render_stateful_widget(list, area, &mut list_state);
 }
 
+fn playlist_item(playlist: &crate::ytm::models::Playlist, is_selected: bool) -> ListItem<'static> {
+    let theme = get_theme();
+
+    let style = if is_selected {
+        Style::default()
+            .fg(theme.palette.accent)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.palette.fg_primary)
+    };
+
+    let track_count = playlist
+        .track_count
+        .map(|c| format!(" ({} tracks)", c))
+        .unwrap_or_default();
+
+    let display = format!("{} {}{}", theme.icons.playlist, playlist.title, track_count);
+
+    ListItem::new(Line::from(Span::styled(display, style)))
+}
+
 /// Placeholder for albums list
 fn render_albums_placeholder(frame: &mut Frame, area: Rect) {
     let theme = get_theme();
@@ -388,18 +504,50 @@ fn render_albums_placeholder(frame: &mut Frame, area: Rect) {
     frame.render_widget(placeholder, area);
 }
 
+/// Height of the cover shown above an opened playlist or album
+const ART_HEADER_ROWS: u16 = 5;
+
 /// Render the tracks within an opened playlist
-fn render_playlist_tracks_view(frame: &mut Frame, state: &AppState, area: Rect) {
+pub fn render_playlist_tracks_view(
+    frame: &mut Frame,
+    view: &PlaylistViewState,
+    art: &mut ArtState,
+    tick: u64,
+    area: Rect,
+) {
     let theme = get_theme();
     let icons = &theme.icons;
-    let view = &state.playlist_view;
 
-    // Header with back hint and playlist name
+    let art_url = view
+        .playlist
+        .as_ref()
+        .and_then(|p| p.thumbnail_url.as_deref())
+        .filter(|_| art.enabled());
+
+    // Header with back hint and playlist name, beside the cover when there is one
     let layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(2), Constraint::Min(3)])
+        .constraints([
+            Constraint::Length(if art_url.is_some() { ART_HEADER_ROWS + 1 } else { 2 }),
+            Constraint::Min(3),
+        ])
         .split(area);
 
+    let header_area = if art_url.is_some() {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(ART_HEADER_ROWS * 2),
+                Constraint::Length(2),
+                Constraint::Min(1),
+            ])
+            .split(layout[0]);
+        art::render(frame, art, art_url, Rect { height: ART_HEADER_ROWS, ..cols[0] });
+        cols[2]
+    } else {
+        layout[0]
+    };
+
     let playlist_name = view
         .playlist
         .as_ref()
@@ -408,22 +556,50 @@ fn render_playlist_tracks_view(frame: &mut Frame, state: &AppState, area: Rect)
 
     let track_count = view.tracks.len();
 
-    let header = Line::from(vec![
+    let back_hint = vec![
         Span::styled("← ", Style::default().fg(theme.palette.fg_secondary)),
         Span::styled("Esc/Backspace", Style::default().fg(theme.palette.accent)),
-        Span::styled("  ", Style::default()),
-        Span::styled(
-            format!("\"{}\" ({} tracks)", playlist_name, track_count),
-            Style::default()
-                .fg(theme.palette.fg_primary)
-                .add_modifier(Modifier::BOLD),
-        ),
-    ]);
-    frame.render_widget(Paragraph::new(header), layout[0]);
+    ];
+    let title = Span::styled(
+        format!("\"{}\" ({} tracks)", playlist_name, track_count),
+        Style::default()
+            .fg(theme.palette.fg_primary)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    if art_url.is_some() {
+        let author = view
+            .playlist
+            .as_ref()
+            .and_then(|p| p.author.clone())
+            .unwrap_or_default();
+        let lines = vec![
+            Line::from(back_hint),
+            Line::default(),
+            Line::from(title),
+            Line::from(Span::styled(author, Style::default().fg(theme.palette.fg_secondary))),
+        ];
+        frame.render_widget(Paragraph::new(lines), header_area);
+    } else {
+        let mut spans = back_hint;
+        spans.push(Span::styled("  ", Style::default()));
+        spans.push(title);
+        let local = view
+            .playlist
+            .as_ref()
+            .is_some_and(|p| crate::storage::local_playlist_id(&p.id).is_some());
+        if local {
+            spans.push(Span::styled(
+                "  K/J: Move · d: Remove",
+                Style::default().fg(theme.palette.fg_secondary),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), header_area);
+    }
 
     // Loading state
     if view.loading {
-        let spinner = crate::tui::theme::LoadingSpinner::frame(state.tick);
+        let spinner = crate::tui::theme::LoadingSpinner::frame(tick);
         let loading = Paragraph::new(Line::from(format!("{} Loading tracks...", spinner)))
             .style(Style::default().fg(theme.palette.fg_secondary));
         frame.render_widget(loading, layout[1]);
@@ -455,6 +631,10 @@ fn render_playlist_tracks_view(frame: &mut Frame, state: &AppState, area: Rect)
                 Style::default()
                     .fg(theme.palette.accent)
                     .add_modifier(Modifier::BOLD)
+            } else if view.selection.contains(i) {
+                Style::default()
+                    .fg(theme.palette.accent_alt)
+                    .bg(theme.palette.bg_highlight)
             } else {
                 Style::default().fg(theme.palette.fg_primary)
             };
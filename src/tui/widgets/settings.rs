@@ -1,4 +1,4 @@
-use crate::app::state::{AppState, AudioDevice, SettingsFocus};
+use crate::app::state::{AppState, AudioDevice, SettingsFocus, CHART_COUNTRIES, LANGUAGES};
 use crate::config::Config;
 use crate::tui::theme::get_theme;
 use ratatui::{
@@ -15,7 +15,9 @@ pub fn render(frame: &mut Frame, cfg: &Config, state: &AppState, area: Rect) {
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(10),  // Auth section (with browser list)
+            Constraint::Length(12),  // Auth section (with browser list)
+            Constraint::Length(4),   // Language & region section
+            Constraint::Length(3),   // Playback section
             Constraint::Min(5),      // Audio section
             Constraint::Length(4),   // Lyrics section
             Constraint::Length(6),   // Cache section
@@ -24,10 +26,12 @@ pub fn render(frame: &mut Frame, cfg: &Config, state: &AppState, area: Rect) {
         .split(area);
 
     render_auth_section(frame, cfg, state, &theme, rows[0]);
-    render_audio_devices(frame, cfg, state, &theme, rows[1]);
-    render_lyrics_section(frame, state, &theme, rows[2]);
-    render_cache_section(frame, state, &theme, rows[3]);
-    render_help(frame, state, &theme, rows[4]);
+    render_region_section(frame, cfg, state, &theme, rows[1]);
+    render_playback_section(frame, cfg, state, &theme, rows[2]);
+    render_audio_devices(frame, cfg, state, &theme, rows[3]);
+    render_lyrics_section(frame, state, &theme, rows[4]);
+    render_cache_section(frame, state, &theme, rows[5]);
+    render_help(frame, state, &theme, rows[6]);
 }
 
 fn render_auth_section(frame: &mut Frame, cfg: &Config, state: &AppState, theme: &crate::tui::theme::Theme, area: Rect) {
@@ -49,29 +53,80 @@ fn render_auth_section(frame: &mut Frame, cfg: &Config, state: &AppState, theme:
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1),   // Status
+            Constraint::Length(3),   // Status, account, expiry
             Constraint::Min(1),      // Browser list
         ])
         .split(inner);
 
+    let status = &state.auth_status;
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(theme.palette.fg_secondary));
+
     // Status line
-    let (status_icon, status_text, status_color) = if cfg.ytm.cookies.is_some() {
-        (icons.success, "Authenticated (cookie file)".to_string(), theme.palette.playing)
-    } else if let Some(browser) = cfg.ytm.cookies_from_browser.as_deref() {
-        (icons.success, format!("Browser: {}", browser), theme.palette.playing)
+    let (status_icon, status_text, status_color) = if let Some(source) = &status.source {
+        (icons.success, capitalize(source), theme.palette.playing)
+    } else if let Some(e) = &status.error {
+        (icons.error, e.clone(), theme.palette.error)
+    } else if let Some(browser) = cfg.ytm.cookie_sources().1 {
+        (icons.error, format!("Browser: {} (no cookies)", browser), theme.palette.error)
     } else {
         (icons.error, "Not authenticated".to_string(), theme.palette.error)
     };
 
-    let status_line = Line::from(vec![
-        Span::styled("Status: ", Style::default().fg(theme.palette.fg_secondary)),
+    let mut lines = vec![Line::from(vec![
+        label("Status:  "),
         Span::styled(format!("{} ", status_icon), Style::default().fg(status_color)),
         Span::styled(status_text, Style::default().fg(status_color)),
-    ]);
-    frame.render_widget(Paragraph::new(status_line), rows[0]);
+    ])];
+
+    if status.source.is_some() {
+        // Account line
+        let account = if status.checking {
+            Span::styled("checking...", Style::default().fg(theme.palette.fg_secondary))
+        } else if let Some(a) = &status.account {
+            let text = match &a.handle {
+                Some(handle) => format!("{} ({})", a.name, handle),
+                None => a.name.clone(),
+            };
+            Span::styled(text, Style::default().fg(theme.palette.fg_primary))
+        } else if let Some(e) = &status.error {
+            Span::styled(e.clone(), Style::default().fg(theme.palette.error))
+        } else {
+            Span::styled("unknown", Style::default().fg(theme.palette.fg_secondary))
+        };
+        let mut account_line = vec![label("Account: "), account];
+        if !cfg.ytm.profiles.is_empty() || !status.channels.is_empty() {
+            account_line.push(Span::styled(
+                format!("  [{}] a: switch", cfg.ytm.profile_name()),
+                Style::default().fg(theme.palette.fg_secondary),
+            ));
+        }
+        lines.push(Line::from(account_line));
+
+        // Expiry line, warning ahead of time
+        let now = crate::ytm::auth::unix_now();
+        let mut expiry = vec![label("Expires: ")];
+        match status.expires_utc {
+            Some(t) => {
+                let color = if status.expiring_soon(now) { theme.palette.error } else { theme.palette.fg_primary };
+                let mut text = crate::ytm::auth::describe_expiry(t, now);
+                if t <= now {
+                    text = format!("expired {text}");
+                } else if status.expiring_soon(now) {
+                    text.push_str(" - sign in again soon");
+                }
+                expiry.push(Span::styled(text, Style::default().fg(color)));
+            }
+            None => expiry.push(Span::styled("session only", Style::default().fg(theme.palette.fg_secondary))),
+        }
+        if !status.has_sapisid {
+            expiry.push(Span::styled("  (no SAPISID)", Style::default().fg(theme.palette.error)));
+        }
+        lines.push(Line::from(expiry));
+    }
+    frame.render_widget(Paragraph::new(lines), rows[0]);
 
     // Browser list
-    let current_browser = cfg.ytm.cookies_from_browser.as_deref().unwrap_or("none");
+    let current_browser = cfg.ytm.cookie_sources().1.unwrap_or("none");
     let items: Vec<ListItem> = state
         .auth_browsers
         .iter()
@@ -176,6 +231,89 @@ fn device_item(d: &AudioDevice, current: &str, theme: &crate::tui::theme::Theme)
     )))
 }
 
+fn render_region_section(frame: &mut Frame, cfg: &Config, state: &AppState, theme: &crate::tui::theme::Theme, area: Rect) {
+    let is_focused = state.settings_focus == SettingsFocus::Region;
+    let border_color = if is_focused { theme.palette.accent } else { theme.palette.border };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(theme.border_set())
+        .border_style(Style::default().fg(border_color))
+        .title(format!(" {} Language & region ", theme.icons.explore))
+        .title_style(Style::default().fg(theme.palette.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let describe = |list: &[(&str, &str)], code: Option<&str>| match code {
+        Some(code) => match list.iter().find(|(c, _)| c.eq_ignore_ascii_case(code)) {
+            Some((c, name)) => format!("{} ({})", name, c),
+            None => code.to_string(),
+        },
+        None => "Automatic".to_string(),
+    };
+
+    let rows = [
+        ("Language: ", describe(LANGUAGES, cfg.ytm.hl.as_deref())),
+        ("Country:  ", describe(CHART_COUNTRIES, cfg.ytm.gl.as_deref())),
+    ];
+
+    let lines: Vec<Line> = rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, (label, value))| {
+            let is_selected = is_focused && i == state.region_selected;
+            let value_style = if is_selected {
+                Style::default()
+                    .fg(theme.palette.bg_primary)
+                    .bg(theme.palette.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.palette.fg_primary)
+            };
+            Line::from(vec![
+                Span::styled(if is_selected { "▸ " } else { "  " }, Style::default().fg(theme.palette.accent)),
+                Span::styled(label, Style::default().fg(theme.palette.fg_secondary)),
+                Span::styled(value, value_style),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn render_playback_section(frame: &mut Frame, cfg: &Config, state: &AppState, theme: &crate::tui::theme::Theme, area: Rect) {
+    let is_focused = state.settings_focus == SettingsFocus::Playback;
+    let border_color = if is_focused { theme.palette.accent } else { theme.palette.border };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(theme.border_set())
+        .border_style(Style::default().fg(border_color))
+        .title(format!(" {} Playback ", theme.icons.play))
+        .title_style(Style::default().fg(theme.palette.accent));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let value = if cfg.player.autoplay_radio { "On" } else { "Off" };
+    let value_style = if is_focused {
+        Style::default()
+            .fg(theme.palette.bg_primary)
+            .bg(theme.palette.accent)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.palette.fg_primary)
+    };
+    let line = Line::from(vec![
+        Span::styled(if is_focused { "▸ " } else { "  " }, Style::default().fg(theme.palette.accent)),
+        Span::styled("Autoplay radio: ", Style::default().fg(theme.palette.fg_secondary)),
+        Span::styled(value, value_style),
+    ]);
+
+    frame.render_widget(Paragraph::new(line), inner);
+}
+
 fn render_lyrics_section(frame: &mut Frame, state: &AppState, theme: &crate::tui::theme::Theme, area: Rect) {
     let icons = &theme.icons;
 
@@ -255,19 +393,26 @@ fn render_help(frame: &mut Frame, state: &AppState, theme: &crate::tui::theme::T
 
     let focus_hint = match state.settings_focus {
         SettingsFocus::Authentication => "Auth",
+        SettingsFocus::Region => "Region",
+        SettingsFocus::Playback => "Playback",
         SettingsFocus::AudioDevice => "Audio",
         SettingsFocus::Cache => "Cache",
     };
 
-    let msg = Line::from(vec![
+    let mut spans = vec![
         Span::styled("Tab", Style::default().fg(theme.palette.accent_alt)),
         Span::styled(" switch  ", Style::default().fg(theme.palette.fg_secondary)),
         Span::styled("j/k", Style::default().fg(theme.palette.accent_alt)),
         Span::styled(" navigate  ", Style::default().fg(theme.palette.fg_secondary)),
         Span::styled("Enter", Style::default().fg(theme.palette.accent_alt)),
         Span::styled(" apply  ", Style::default().fg(theme.palette.fg_secondary)),
-        Span::styled(format!("[{}]", focus_hint), Style::default().fg(theme.palette.playing)),
-    ]);
+    ];
+    if state.settings_focus == SettingsFocus::Authentication {
+        spans.push(Span::styled("a", Style::default().fg(theme.palette.accent_alt)));
+        spans.push(Span::styled(" account  ", Style::default().fg(theme.palette.fg_secondary)));
+    }
+    spans.push(Span::styled(format!("[{}]", focus_hint), Style::default().fg(theme.palette.playing)));
+    let msg = Line::from(spans);
 
     frame.render_widget(Paragraph::new(msg).block(block), area);
 }
@@ -290,3 +435,11 @@ fn format_size(bytes: u64) -> String {
         format!("{} B", bytes)
     }
 }
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
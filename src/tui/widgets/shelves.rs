@@ -0,0 +1,221 @@
+//! Shelf rendering shared by the Home and Explore screens
+
+use crate::app::state::ShelvesState;
+use crate::tui::theme::{get_theme, LoadingSpinner};
+use crate::ytm::models::{Shelf, ShelfItem, ShelfLayout};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Rows shown for list shelves such as Quick picks
+const LIST_ROWS: usize = 4;
+/// Width of a single card in a card shelf (excluding the gap)
+const CARD_WIDTH: usize = 24;
+const CARD_GAP: usize = 2;
+
+/// Render a non-empty page of shelves, scrolled so the selected shelf is visible
+pub fn render(frame: &mut Frame, page: &ShelvesState, tick: u64, area: Rect) {
+    let theme = get_theme();
+    if page.shelves.is_empty() {
+        return;
+    }
+
+    // Scroll so that the selected shelf is fully visible
+    let available = area.height as usize;
+    let mut first = page.selected_shelf.min(page.shelves.len() - 1);
+    let mut used = shelf_height(&page.shelves[first]);
+    while first > 0 {
+        let h = shelf_height(&page.shelves[first - 1]);
+        if used + h > available {
+            break;
+        }
+        used += h;
+        first -= 1;
+    }
+
+    let width = area.width as usize;
+    let mut lines: Vec<Line> = Vec::new();
+
+    for (i, shelf) in page.shelves.iter().enumerate().skip(first) {
+        if lines.len() >= available {
+            break;
+        }
+        let is_selected_shelf = i == page.selected_shelf;
+        let selected_item = is_selected_shelf.then_some(page.selected_item);
+
+        let title_style = if is_selected_shelf {
+            Style::default()
+                .fg(theme.palette.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.palette.fg_secondary)
+        };
+        lines.push(Line::from(Span::styled(
+            format!("━━ {} ━━", shelf.title),
+            title_style,
+        )));
+
+        match shelf.layout {
+            ShelfLayout::List => lines.extend(list_lines(shelf, selected_item, width, &theme)),
+            ShelfLayout::Cards => lines.extend(card_lines(shelf, selected_item, width, &theme)),
+        }
+        lines.push(Line::default());
+    }
+
+    if page.loading_more {
+        let spinner = LoadingSpinner::frame(tick);
+        lines.push(Line::from(Span::styled(
+            format!("  {} Loading more...", spinner),
+            Style::default().fg(theme.palette.fg_secondary),
+        )));
+    }
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+fn shelf_height(shelf: &Shelf) -> usize {
+    let body = match shelf.layout {
+        ShelfLayout::List => shelf.items.len().min(LIST_ROWS),
+        ShelfLayout::Cards => 2,
+    };
+    // title + body + spacer
+    body + 2
+}
+
+/// Window start so that `selected` stays visible in a view of `visible` items
+fn window_start(selected: Option<usize>, visible: usize, len: usize) -> usize {
+    let Some(selected) = selected else {
+        return 0;
+    };
+    if visible == 0 || len <= visible {
+        return 0;
+    }
+    selected.saturating_sub(visible - 1).min(len - visible)
+}
+
+fn list_lines(
+    shelf: &Shelf,
+    selected: Option<usize>,
+    width: usize,
+    theme: &crate::tui::theme::Theme,
+) -> Vec<Line<'static>> {
+    let icons = &theme.icons;
+    let start = window_start(selected, LIST_ROWS, shelf.items.len());
+    let max_width = width.saturating_sub(4);
+
+    shelf
+        .items
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(LIST_ROWS)
+        .map(|(i, item)| {
+            let is_selected = selected == Some(i);
+            let subtitle = item.subtitle();
+            let display = if subtitle.is_empty() {
+                item.title().to_string()
+            } else {
+                format!("{} - {}", item.title(), subtitle)
+            };
+
+            let style = if is_selected {
+                Style::default()
+                    .fg(theme.palette.bg_primary)
+                    .bg(theme.palette.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.palette.fg_primary)
+            };
+
+            Line::from(vec![
+                Span::styled(
+                    if is_selected { "\u{f054} " } else { "  " },
+                    Style::default().fg(theme.palette.accent),
+                ),
+                Span::styled(
+                    format!("{} {}", item_icon(item, icons), truncate_str(&display, max_width)),
+                    style,
+                ),
+            ])
+        })
+        .collect()
+}
+
+fn card_lines(
+    shelf: &Shelf,
+    selected: Option<usize>,
+    width: usize,
+    theme: &crate::tui::theme::Theme,
+) -> Vec<Line<'static>> {
+    let icons = &theme.icons;
+    let visible = (width / (CARD_WIDTH + CARD_GAP)).max(1);
+    let start = window_start(selected, visible, shelf.items.len());
+
+    let mut titles: Vec<Span> = Vec::new();
+    let mut subtitles: Vec<Span> = Vec::new();
+
+    for (i, item) in shelf.items.iter().enumerate().skip(start).take(visible) {
+        let is_selected = selected == Some(i);
+
+        let title_style = if is_selected {
+            Style::default()
+                .fg(theme.palette.bg_primary)
+                .bg(theme.palette.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.palette.fg_primary)
+        };
+
+        let title = format!("{} {}", item_icon(item, icons), item.title());
+        titles.push(Span::styled(pad_str(&title, CARD_WIDTH), title_style));
+        titles.push(Span::raw(" ".repeat(CARD_GAP)));
+
+        subtitles.push(Span::styled(
+            pad_str(&item.subtitle(), CARD_WIDTH),
+            Style::default().fg(theme.palette.fg_secondary),
+        ));
+        subtitles.push(Span::raw(" ".repeat(CARD_GAP)));
+    }
+
+    // Hint that the shelf scrolls horizontally
+    if start + visible < shelf.items.len() {
+        titles.push(Span::styled("›", Style::default().fg(theme.palette.fg_secondary)));
+    }
+
+    vec![Line::from(titles), Line::from(subtitles)]
+}
+
+fn item_icon(item: &ShelfItem, icons: &crate::tui::theme::Icons) -> &'static str {
+    match item {
+        ShelfItem::Track(_) => icons.music,
+        ShelfItem::Playlist(_) => icons.playlist,
+        ShelfItem::Album(_) => icons.album,
+        ShelfItem::Artist(_) => icons.artist,
+    }
+}
+
+/// Truncate or pad to exactly `width` characters
+fn pad_str(s: &str, width: usize) -> String {
+    let truncated = truncate_str(s, width);
+    let len = truncated.chars().count();
+    format!("{}{}", truncated, " ".repeat(width.saturating_sub(len)))
+}
+
+fn truncate_str(s: &str, max_len: usize) -> String {
+    if max_len == 0 {
+        return String::new();
+    }
+    let char_count: usize = s.chars().count();
+    if char_count <= max_len {
+        s.to_string()
+    } else if max_len > 3 {
+        let truncated: String = s.chars().take(max_len - 3).collect();
+        format!("{}...", truncated)
+    } else {
+        s.chars().take(max_len).collect()
+    }
+}
@@ -38,21 +38,24 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
 
     // Menu items with icons
     let menu_items = [
+        MenuItem::item(icons.home, "Home"),
+        MenuItem::item(icons.explore, "Explore"),
         MenuItem::item(icons.history, "History"),
         MenuItem::item(icons.search, "Search"),
         MenuItem::item(icons.queue, "Queue"),
         MenuItem::item(icons.library, "Library"),
+        MenuItem::item(icons.lyrics, "Lyrics"),
         MenuItem::separator(),
         MenuItem::item(icons.settings, "Settings"),
         MenuItem::item(icons.help, "Help"),
     ];
 
     // Map menu index to actual selection index (skipping separator)
-    // Menu indices: 0=History, 1=Search, 2=Queue, 3=Library, 4=separator, 5=Settings, 6=Help
-    // Selection indices: 0=History, 1=Search, 2=Queue, 3=Library, 4=Settings, 5=Help
-    let selection_to_menu: [usize; 6] = [0, 1, 2, 3, 5, 6];
-    let menu_to_selection: [Option<usize>; 7] = [
-        Some(0), Some(1), Some(2), Some(3), None, Some(4), Some(5)
+    // Menu indices: 0=Home, 1=Explore, 2=History, 3=Search, 4=Queue, 5=Library, 6=Lyrics, 7=separator, 8=Settings, 9=Help
+    // Selection indices: 0=Home, 1=Explore, 2=History, 3=Search, 4=Queue, 5=Library, 6=Lyrics, 7=Settings, 8=Help
+    let selection_to_menu: [usize; 9] = [0, 1, 2, 3, 4, 5, 6, 8, 9];
+    let menu_to_selection: [Option<usize>; 10] = [
+        Some(0), Some(1), Some(2), Some(3), Some(4), Some(5), Some(6), None, Some(7), Some(8)
     ];
 
     let items: Vec<ListItem> = menu_items
@@ -93,7 +96,7 @@ pub fn render(frame: &mut Frame, state: &AppState, area: Rect) {
         .collect();
 
     // Map selection to list position (account for separator)
-    let list_idx = selection_to_menu[state.sidebar_selected.min(5)];
+    let list_idx = selection_to_menu[state.sidebar_selected.min(8)];
 
     let mut list_state = ListState::default();
     list_state.select(Some(list_idx));
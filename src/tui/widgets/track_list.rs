@@ -98,6 +98,10 @@ pub fn render(frame: &mut Frame, _cfg: &Config, state: &AppState, area: Rect) {
                 Style::default()
                     .fg(theme.palette.accent)
                     .add_modifier(Modifier::BOLD)
+            } else if list_state.selection.contains(i) {
+                Style::default()
+                    .fg(theme.palette.accent_alt)
+                    .bg(theme.palette.bg_highlight)
             } else {
                 Style::default().fg(theme.palette.fg_primary)
             };
@@ -1,13 +1,19 @@
-use crate::ytm::auth::AuthState;
-use crate::ytm::models::{Playlist, SearchItem, Track};
+use crate::ytm::auth::{AuthState, Identity};
+use crate::ytm::http::{HttpLayer, RetryPolicy};
+use crate::ytm::models::{
+    AccountInfo, Channel, LikeStatus, MoodCategory, Playlist, SearchItem, Shelf, ShelfItem,
+    ShelfLayout, Thumbnail, Track, VideoType,
+};
 use anyhow::Context;
 use reqwest::header::{
     HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, COOKIE, ORIGIN, REFERER, USER_AGENT,
 };
 use serde_json::json;
 use sha1::{Digest, Sha1};
+use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::OnceCell;
+use std::time::Duration;
+use tokio::sync::Mutex;
 
 /// Search results with optional continuation token for pagination
 #[derive(Debug, Clone)]
@@ -23,11 +29,67 @@ pub struct SearchAllResult {
     pub continuation: Option<String>,
 }
 
+/// A batch of radio tracks with the token for the next batch
+#[derive(Debug, Clone)]
+pub struct RadioPage {
+    /// Radio playlist being followed (e.g. `RDAMVM{videoId}`)
+    pub playlist_id: String,
+    pub tracks: Vec<Track>,
+    pub continuation: Option<String>,
+}
+
+/// A page of shelves (Home, Explore) with a continuation token for loading more
+#[derive(Debug, Clone)]
+pub struct ShelfPage {
+    pub shelves: Vec<Shelf>,
+    pub continuation: Option<String>,
+}
+
+pub const DEFAULT_BASE_URL: &str = "https://music.youtube.com";
+
+/// Per-client settings that shape every innertube request
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    pub identity: Identity,
+    /// Interface language, e.g. "en" or "ja"; YouTube guesses when unset
+    pub hl: Option<String>,
+    /// Content region, e.g. "US"; YouTube uses IP geolocation when unset
+    pub gl: Option<String>,
+    /// Per-request timeout, covering connect through reading the body
+    pub timeout: Duration,
+    pub retry: RetryPolicy,
+    /// Global request pacing; 0 disables it
+    pub requests_per_second: f64,
+    /// Where the web app and innertube API live; a local stand-in server for tests and demos
+    pub base_url: String,
+    /// SQLite cache to persist the bootstrap in, so cold starts skip fetching the web app
+    pub bootstrap_cache: Option<PathBuf>,
+    pub bootstrap_ttl: Duration,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            identity: Identity::default(),
+            hl: None,
+            gl: None,
+            timeout: Duration::from_secs(15),
+            retry: RetryPolicy::default(),
+            requests_per_second: 5.0,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            bootstrap_cache: None,
+            bootstrap_ttl: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Inner {
     http: reqwest::Client,
+    layer: HttpLayer,
     auth: Option<AuthState>,
-    bootstrap: OnceCell<Bootstrap>,
+    opts: ClientOptions,
+    bootstrap: Mutex<Option<Bootstrap>>,
 }
 
 #[derive(Debug, Clone)]
@@ -35,22 +97,24 @@ pub struct YtmClient {
     inner: Arc<Inner>,
 }
 
-#[derive(Debug, Clone)]
-struct Bootstrap {
-    api_key: String,
-    client_version: String,
-    visitor_data: Option<String>,
+/// Values scraped from the web app that every innertube request needs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bootstrap {
+    pub api_key: String,
+    pub client_version: String,
+    pub visitor_data: Option<String>,
 }
 
 impl YtmClient {
-    pub fn new(auth: Option<AuthState>) -> anyhow::Result<Self> {
+    pub fn new(auth: Option<AuthState>, opts: ClientOptions) -> anyhow::Result<Self> {
         let mut headers = HeaderMap::new();
         headers.insert(
             USER_AGENT,
             HeaderValue::from_static("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/121.0.0.0 Safari/537.36"),
         );
-        headers.insert(ORIGIN, HeaderValue::from_static("https://music.youtube.com"));
-        headers.insert(REFERER, HeaderValue::from_static("https://music.youtube.com/"));
+        let origin = opts.base_url.trim_end_matches('/').to_string();
+        headers.insert(ORIGIN, HeaderValue::from_str(&origin).context("invalid base url")?);
+        headers.insert(REFERER, HeaderValue::from_str(&format!("{origin}/"))?);
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
         if let Some(a) = &auth {
@@ -58,21 +122,25 @@ impl YtmClient {
                 headers.insert(COOKIE, HeaderValue::from_str(&a.cookie_header)?);
             }
             if let Some(sapisid) = &a.sapisid {
-                let authz = make_sapisid_hash_auth("https://music.youtube.com", sapisid);
+                let authz = make_sapisid_hash_auth(&origin, sapisid);
                 headers.insert(AUTHORIZATION, HeaderValue::from_str(&authz)?);
             }
         }
 
         let http = reqwest::Client::builder()
             .default_headers(headers)
+            .timeout(opts.timeout)
             .build()
             .context("build reqwest client")?;
+        let layer = HttpLayer::new(opts.retry.clone(), opts.requests_per_second);
 
         Ok(Self {
             inner: Arc::new(Inner {
                 http,
+                layer,
                 auth,
-                bootstrap: OnceCell::new(),
+                opts,
+                bootstrap: Mutex::new(None),
             }),
         })
     }
@@ -92,29 +160,14 @@ impl YtmClient {
 
     /// Continue search using a continuation token
     pub async fn search_continue(&self, continuation: &str) -> anyhow::Result<SearchResult> {
-        let b = self.bootstrap().await?;
-
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
             "continuation": continuation
         });
 
         let v: serde_json::Value = self
-            .innertube_post("search", &b)
-            .json(&body)
-            .send()
-            .await
-            .context("send search continuation request")?
-            .error_for_status()
-            .context("search continuation http status")?
-            .json()
+            .innertube("search", body)
             .await
-            .context("parse search continuation json")?;
+            .context("search continuation")?;
 
         let tracks = extract_tracks_from_continuation(&v);
         let next_continuation = extract_continuation_token(&v);
@@ -122,15 +175,7 @@ impl YtmClient {
     }
 
     pub async fn search_raw(&self, query: &str) -> anyhow::Result<serde_json::Value> {
-        let b = self.bootstrap().await?;
-
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
             "query": query,
             // This params value is commonly used to bias towards songs in YTM.
             // We'll keep it optional if YouTube changes behavior; search still returns items.
@@ -138,46 +183,24 @@ impl YtmClient {
         });
 
         let v: serde_json::Value = self
-            .innertube_post("search", &b)
-            .json(&body)
-            .send()
-            .await
-            .context("send search request")?
-            .error_for_status()
-            .context("search http status")?
-            .json()
+            .innertube("search", body)
             .await
-            .context("parse search json")?;
+            .context("search")?;
         Ok(v)
     }
 
     /// Search for playlists only
     pub async fn search_playlists_raw(&self, query: &str) -> anyhow::Result<serde_json::Value> {
-        let b = self.bootstrap().await?;
-
         // Params for playlists filter: EgeKAQQoAEABagoQAxAEEAoQCRAF
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
             "query": query,
             "params": "EgeKAQQoAEABagoQAxAEEAoQCRAF"
         });
 
         let v: serde_json::Value = self
-            .innertube_post("search", &b)
-            .json(&body)
-            .send()
-            .await
-            .context("send search playlists request")?
-            .error_for_status()
-            .context("search playlists http status")?
-            .json()
+            .innertube("search", body)
             .await
-            .context("parse search playlists json")?;
+            .context("search playlists")?;
         Ok(v)
     }
 
@@ -197,7 +220,7 @@ impl YtmClient {
         // Extract playlists from playlist search
         let playlists: Vec<SearchItem> = playlists_json
             .as_ref()
-            .map(|v| extract_playlists_from_search(v))
+            .map(extract_playlists_from_search)
             .unwrap_or_default()
             .into_iter()
             .take(5) // Limit to top 5 playlists to not overwhelm results
@@ -217,95 +240,141 @@ impl YtmClient {
         })
     }
 
-    pub async fn browse_home_tracks(&self) -> anyhow::Result<Vec<Track>> {
+    pub async fn browse_home_raw(&self) -> anyhow::Result<serde_json::Value> {
+        let body = json!({
+            "browseId": "FEmusic_home"
+        });
+
+        let v: serde_json::Value = self
+            .innertube("browse", body)
+            .await
+            .context("browse home")?;
+        Ok(v)
+    }
+
+    /// Browse the Home feed as titled shelves
+    pub async fn browse_home(&self) -> anyhow::Result<ShelfPage> {
         let v = self.browse_home_raw().await?;
-        Ok(extract_tracks_generic(&v))
+        Ok(ShelfPage {
+            shelves: extract_shelves(&v),
+            continuation: extract_section_list_continuation(&v),
+        })
     }
 
-    pub async fn browse_home_raw(&self) -> anyhow::Result<serde_json::Value> {
-        let b = self.bootstrap().await?;
+    /// Load more shelves (Home or an Explore page) using a continuation token
+    pub async fn browse_shelves_continue(&self, continuation: &str) -> anyhow::Result<ShelfPage> {
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
-            "browseId": "FEmusic_home"
+            "continuation": continuation
         });
 
         let v: serde_json::Value = self
-            .innertube_post("browse", &b)
-            .json(&body)
-            .send()
+            .innertube("browse", body)
             .await
-            .context("send browse home request")?
-            .error_for_status()
-            .context("browse home http status")?
-            .json()
+            .context("browse continuation")?;
+
+        Ok(ShelfPage {
+            shelves: extract_shelves(&v),
+            continuation: extract_section_list_continuation(&v),
+        })
+    }
+
+    /// Explore landing page (new albums, trending, new videos)
+    pub async fn browse_explore(&self) -> anyhow::Result<ShelfPage> {
+        self.browse_shelves("FEmusic_explore", None, None).await
+    }
+
+    /// New album and single releases
+    pub async fn browse_new_releases(&self) -> anyhow::Result<ShelfPage> {
+        self.browse_shelves("FEmusic_new_releases", None, None).await
+    }
+
+    /// Charts for a country code (e.g. "US"); `ZZ` is the global chart
+    pub async fn browse_charts(&self, country: &str) -> anyhow::Result<ShelfPage> {
+        let form_data = json!({ "selectedValues": [country] });
+        self.browse_shelves("FEmusic_charts", None, Some(form_data)).await
+    }
+
+    /// Categories listed on the Moods & genres page
+    pub async fn get_mood_categories(&self) -> anyhow::Result<Vec<MoodCategory>> {
+        let v = self.browse_raw("FEmusic_moods_and_genres", None, None).await?;
+        Ok(extract_mood_categories(&v))
+    }
+
+    /// Playlists for a single mood or genre
+    pub async fn browse_mood_category(&self, params: &str) -> anyhow::Result<ShelfPage> {
+        self.browse_shelves("FEmusic_moods_and_genres_category", Some(params), None)
+            .await
+    }
+
+    async fn browse_shelves(
+        &self,
+        browse_id: &str,
+        params: Option<&str>,
+        form_data: Option<serde_json::Value>,
+    ) -> anyhow::Result<ShelfPage> {
+        let v = self.browse_raw(browse_id, params, form_data).await?;
+        Ok(ShelfPage {
+            shelves: extract_shelves(&v),
+            continuation: extract_section_list_continuation(&v),
+        })
+    }
+
+    async fn browse_raw(
+        &self,
+        browse_id: &str,
+        params: Option<&str>,
+        form_data: Option<serde_json::Value>,
+    ) -> anyhow::Result<serde_json::Value> {
+        let mut body = json!({
+            "browseId": browse_id
+        });
+        if let Some(p) = params {
+            body["params"] = json!(p);
+        }
+        if let Some(f) = form_data {
+            body["formData"] = f;
+        }
+
+        let v: serde_json::Value = self
+            .innertube("browse", body)
             .await
-            .context("parse browse home json")?;
+            .with_context(|| format!("browse {browse_id}"))?;
         Ok(v)
     }
 
     pub async fn browse_playlist_tracks(&self, playlist_id: &str) -> anyhow::Result<Vec<Track>> {
-        let b = self.bootstrap().await?;
-        let browse_id = if playlist_id.starts_with("VL") {
+        // Albums (MPRE...) and artists (UC...) are browsed directly; playlists need the VL prefix.
+        let browse_id = if playlist_id.starts_with("VL")
+            || playlist_id.starts_with("MPRE")
+            || playlist_id.starts_with("UC")
+        {
             playlist_id.to_string()
         } else {
             format!("VL{}", playlist_id)
         };
 
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
             "browseId": browse_id
         });
 
         let v: serde_json::Value = self
-            .innertube_post("browse", &b)
-            .json(&body)
-            .send()
+            .innertube("browse", body)
             .await
-            .context("send browse playlist request")?
-            .error_for_status()
-            .context("browse playlist http status")?
-            .json()
-            .await
-            .context("parse browse playlist json")?;
+            .context("browse playlist")?;
 
         Ok(extract_tracks_generic(&v))
     }
 
     /// Get user's liked music playlist (requires authentication)
     pub async fn get_liked_music(&self) -> anyhow::Result<Vec<Track>> {
-        let b = self.bootstrap().await?;
-
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
             "browseId": "FEmusic_liked_videos"
         });
 
         let v: serde_json::Value = self
-            .innertube_post("browse", &b)
-            .json(&body)
-            .send()
-            .await
-            .context("send browse liked music request")?
-            .error_for_status()
-            .context("browse liked music http status")?
-            .json()
+            .innertube("browse", body)
             .await
-            .context("parse browse liked music json")?;
+            .context("browse liked music")?;
 
         Ok(extract_tracks_generic(&v))
     }
@@ -313,29 +382,14 @@ impl YtmClient {
     /// Get user's playlists (requires authentication)
     #[allow(dead_code)]
     pub async fn get_user_playlists(&self) -> anyhow::Result<Vec<Playlist>> {
-        let b = self.bootstrap().await?;
-
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
             "browseId": "FEmusic_library_privately_owned_playlists"
         });
 
         let v: serde_json::Value = self
-            .innertube_post("browse", &b)
-            .json(&body)
-            .send()
-            .await
-            .context("send browse playlists request")?
-            .error_for_status()
-            .context("browse playlists http status")?
-            .json()
+            .innertube("browse", body)
             .await
-            .context("parse browse playlists json")?;
+            .context("browse playlists")?;
 
         Ok(extract_playlists(&v))
     }
@@ -343,112 +397,339 @@ impl YtmClient {
     /// Get user's saved albums (requires authentication)
     #[allow(dead_code)]
     pub async fn get_user_albums(&self) -> anyhow::Result<Vec<Playlist>> {
-        let b = self.bootstrap().await?;
-
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
             "browseId": "FEmusic_library_albums"
         });
 
         let v: serde_json::Value = self
-            .innertube_post("browse", &b)
-            .json(&body)
-            .send()
-            .await
-            .context("send browse albums request")?
-            .error_for_status()
-            .context("browse albums http status")?
-            .json()
+            .innertube("browse", body)
             .await
-            .context("parse browse albums json")?;
+            .context("browse albums")?;
 
         Ok(extract_playlists(&v))
     }
 
+    /// Like, dislike or clear the rating of a track (requires authentication)
+    pub async fn rate_track(&self, video_id: &str, status: LikeStatus) -> anyhow::Result<()> {
+        let endpoint = match status {
+            LikeStatus::Like => "like/like",
+            LikeStatus::Dislike => "like/dislike",
+            LikeStatus::Indifferent => "like/removelike",
+        };
+        let body = json!({
+            "target": { "videoId": video_id }
+        });
+
+        self.innertube(endpoint, body)
+            .await
+            .with_context(|| format!("rate {video_id}"))?;
+        Ok(())
+    }
+
+    /// Append tracks to one of the user's playlists (requires authentication)
+    pub async fn add_playlist_items(&self, playlist_id: &str, video_ids: &[String]) -> anyhow::Result<()> {
+        let actions: Vec<serde_json::Value> = video_ids
+            .iter()
+            .map(|id| {
+                json!({
+                    "action": "ACTION_ADD_VIDEO",
+                    "addedVideoId": id,
+                    "dedupeOption": "DEDUPE_OPTION_SKIP"
+                })
+            })
+            .collect();
+        let body = json!({
+            "playlistId": playlist_id.strip_prefix("VL").unwrap_or(playlist_id),
+            "actions": actions
+        });
+
+        let v: serde_json::Value = self
+            .innertube("browse/edit_playlist", body)
+            .await
+            .context("edit playlist")?;
+        if v.get("status").and_then(|s| s.as_str()) == Some("STATUS_FAILED") {
+            anyhow::bail!("YouTube Music refused the playlist edit");
+        }
+        Ok(())
+    }
+
+    /// The signed-in account, as shown in the account menu.
+    /// `None` means YouTube treated the request as logged out.
+    pub async fn get_account_info(&self) -> anyhow::Result<Option<AccountInfo>> {
+        let body = json!({});
+
+        let v: serde_json::Value = self
+            .innertube("account/account_menu", body)
+            .await
+            .context("account menu")?;
+
+        Ok(extract_account_info(&v))
+    }
+
+    /// Channels the signed-in Google account can act as (its own plus brand accounts),
+    /// from the account menu's "Switch account" page.
+    pub async fn get_channels(&self) -> anyhow::Result<Vec<Channel>> {
+        let body = json!({});
+
+        let v: serde_json::Value = self
+            .innertube("account/accounts_list", body)
+            .await
+            .context("accounts list")?;
+
+        Ok(extract_channels(&v))
+    }
+
+    pub fn auth(&self) -> Option<&AuthState> {
+        self.inner.auth.as_ref()
+    }
+
+    /// Plain-text lyrics from the track's Lyrics tab
+    pub async fn get_lyrics(&self, video_id: &str) -> anyhow::Result<Option<String>> {
+        let next = self
+            .innertube("next", json!({ "videoId": video_id }))
+            .await
+            .context("watch next")?;
+        // Instrumentals and most user uploads have no lyrics tab
+        let Some(browse_id) = extract_lyrics_browse_id(&next) else {
+            return Ok(None);
+        };
+
+        let v = self
+            .innertube("browse", json!({ "browseId": browse_id }))
+            .await
+            .context("browse lyrics")?;
+        Ok(extract_lyrics(&v))
+    }
+
     /// Get radio/automix tracks based on a seed video ID.
     /// Returns tracks similar to the given video for endless playback.
-    #[allow(dead_code)]
     pub async fn get_radio_tracks(&self, video_id: &str) -> anyhow::Result<Vec<Track>> {
-        let v = self.get_radio_raw(video_id).await?;
-        Ok(extract_radio_tracks(&v))
+        let page = self
+            .get_radio(Some(video_id), &format!("RDAMVM{}", video_id))
+            .await?;
+        Ok(page.tracks)
     }
 
-    /// Get raw JSON response from the radio/next endpoint
-    #[allow(dead_code)]
-    pub async fn get_radio_raw(&self, video_id: &str) -> anyhow::Result<serde_json::Value> {
-        let b = self.bootstrap().await?;
+    /// Start a radio from a track, playlist, album or artist
+    pub async fn start_radio(&self, seed: &ShelfItem) -> anyhow::Result<RadioPage> {
+        match seed {
+            // Radio playlist ID format: RDAMVM{videoId}
+            ShelfItem::Track(t) => {
+                self.get_radio(Some(&t.video_id), &format!("RDAMVM{}", t.video_id))
+                    .await
+            }
+            // Playlist radio: RDAMPL{playlistId}
+            ShelfItem::Playlist(p) => {
+                let id = p.id.strip_prefix("VL").unwrap_or(&p.id);
+                self.get_radio(None, &format!("RDAMPL{}", id)).await
+            }
+            // Artist pages carry their own radio playlist (RDEM...)
+            ShelfItem::Artist(p) => match self.get_artist_radio_id(&p.id).await? {
+                Some(radio_id) => self.get_radio(None, &radio_id).await,
+                None => self.start_radio_from_first_track(&p.id).await,
+            },
+            ShelfItem::Album(p) => self.start_radio_from_first_track(&p.id).await,
+        }
+    }
 
-        // Radio playlist ID format: RDAMVM{videoId}
-        let playlist_id = format!("RDAMVM{}", video_id);
+    /// Fetch the next batch of a radio started with `start_radio`
+    pub async fn get_radio_continue(
+        &self,
+        playlist_id: &str,
+        continuation: &str,
+    ) -> anyhow::Result<RadioPage> {
+        let v = self.get_radio_raw(None, playlist_id, Some(continuation)).await?;
+        Ok(RadioPage {
+            playlist_id: playlist_id.to_string(),
+            tracks: extract_radio_tracks(&v),
+            continuation: extract_radio_continuation(&v),
+        })
+    }
+
+    async fn get_radio(&self, video_id: Option<&str>, playlist_id: &str) -> anyhow::Result<RadioPage> {
+        let v = self.get_radio_raw(video_id, playlist_id, None).await?;
+        Ok(RadioPage {
+            playlist_id: playlist_id.to_string(),
+            tracks: extract_radio_tracks(&v),
+            continuation: extract_radio_continuation(&v),
+        })
+    }
 
+    async fn start_radio_from_first_track(&self, browse_id: &str) -> anyhow::Result<RadioPage> {
+        let tracks = self.browse_playlist_tracks(browse_id).await?;
+        let first = tracks.into_iter().next().context("nothing to start a radio from")?;
+        self.get_radio(Some(&first.video_id), &format!("RDAMVM{}", first.video_id))
+            .await
+    }
+
+    async fn get_artist_radio_id(&self, channel_id: &str) -> anyhow::Result<Option<String>> {
         let body = json!({
-            "context": {
-                "client": {
-                    "clientName": "WEB_REMIX",
-                    "clientVersion": b.client_version,
-                }
-            },
-            "videoId": video_id,
+            "browseId": channel_id
+        });
+
+        let v: serde_json::Value = self
+            .innertube("browse", body)
+            .await
+            .context("browse artist")?;
+
+        Ok(find_key(&v, "startRadioButton")
+            .and_then(|x| x.pointer("/buttonRenderer/navigationEndpoint/watchPlaylistEndpoint/playlistId"))
+            .and_then(|x| x.as_str())
+            .map(|s| s.to_string()))
+    }
+
+    /// Get raw JSON response from the radio/next endpoint
+    async fn get_radio_raw(
+        &self,
+        video_id: Option<&str>,
+        playlist_id: &str,
+        continuation: Option<&str>,
+    ) -> anyhow::Result<serde_json::Value> {
+        let mut body = json!({
             "playlistId": playlist_id,
             "isAudioOnly": true
         });
+        if let Some(id) = video_id {
+            body["videoId"] = json!(id);
+        }
+        if let Some(c) = continuation {
+            body["continuation"] = json!(c);
+        }
 
         let v: serde_json::Value = self
-            .innertube_post("next", &b)
-            .json(&body)
-            .send()
-            .await
-            .context("send radio/next request")?
-            .error_for_status()
-            .context("radio/next http status")?
-            .json()
+            .innertube("next", body)
             .await
-            .context("parse radio/next json")?;
+            .context("radio/next")?;
 
         Ok(v)
     }
 
     async fn bootstrap(&self) -> anyhow::Result<Bootstrap> {
-        self.inner
-            .bootstrap
-            .get_or_try_init(|| async {
-                let html = self
-                    .inner
-                    .http
-                    .get("https://music.youtube.com/")
-                    .send()
-                    .await
-                    .context("fetch music.youtube.com for bootstrap")?
-                    .error_for_status()
-                    .context("bootstrap http status")?
-                    .text()
-                    .await
-                    .context("read bootstrap html")?;
-
-                let api_key = parse_ytcfg_value(&html, "INNERTUBE_API_KEY")
-                    .context("parse INNERTUBE_API_KEY")?;
-                let client_version = parse_ytcfg_value(&html, "INNERTUBE_CLIENT_VERSION")
-                    .context("parse INNERTUBE_CLIENT_VERSION")?;
-                let visitor_data = parse_ytcfg_value(&html, "VISITOR_DATA");
-
-                Ok(Bootstrap {
-                    api_key,
-                    client_version,
-                    visitor_data,
-                })
-            })
+        // Held across the fetch so concurrent callers share one bootstrap
+        let mut cached = self.inner.bootstrap.lock().await;
+        if let Some(b) = cached.as_ref() {
+            return Ok(b.clone());
+        }
+
+        if let Some(b) = self.load_persisted_bootstrap().await {
+            *cached = Some(b.clone());
+            return Ok(b);
+        }
+
+        let url = format!("{}/", self.base_url());
+        let html = self
+            .inner
+            .layer
+            .send(|| self.inner.http.get(&url))
+            .await
+            .with_context(|| format!("fetch {url} for bootstrap"))?
+            .error_for_status()
+            .context("bootstrap http status")?
+            .text()
             .await
-            .cloned()
+            .context("read bootstrap html")?;
+
+        let api_key = parse_ytcfg_value(&html, "INNERTUBE_API_KEY")
+            .context("parse INNERTUBE_API_KEY")?;
+        let client_version = parse_ytcfg_value(&html, "INNERTUBE_CLIENT_VERSION")
+            .context("parse INNERTUBE_CLIENT_VERSION")?;
+        let visitor_data = parse_ytcfg_value(&html, "VISITOR_DATA");
+
+        let b = Bootstrap {
+            api_key,
+            client_version,
+            visitor_data,
+        };
+        self.persist_bootstrap(Some(b.clone())).await;
+        *cached = Some(b.clone());
+        Ok(b)
+    }
+
+    /// Drop the bootstrap everywhere so the next request fetches a fresh one
+    async fn forget_bootstrap(&self) {
+        *self.inner.bootstrap.lock().await = None;
+        self.persist_bootstrap(None).await;
+    }
+
+    async fn load_persisted_bootstrap(&self) -> Option<Bootstrap> {
+        let path = self.inner.opts.bootstrap_cache.clone()?;
+        let base_url = self.base_url().to_string();
+        let max_age = self.inner.opts.bootstrap_ttl.as_secs() as i64;
+        tokio::task::spawn_blocking(move || {
+            let now = crate::ytm::auth::unix_now();
+            crate::storage::Storage::open(&path)?.get_bootstrap(&base_url, now - max_age)
+        })
+        .await
+        .ok()?
+        .unwrap_or_else(|e| {
+            tracing::debug!(error = %e, "read persisted bootstrap");
+            None
+        })
     }
 
-    fn innertube_post(&self, path: &str, b: &Bootstrap) -> reqwest::RequestBuilder {
+    /// Store `b`, or clear the stored bootstrap when `None`. Best effort.
+    async fn persist_bootstrap(&self, b: Option<Bootstrap>) {
+        let Some(path) = self.inner.opts.bootstrap_cache.clone() else {
+            return;
+        };
+        let base_url = self.base_url().to_string();
+        let res = tokio::task::spawn_blocking(move || {
+            let storage = crate::storage::Storage::open(&path)?;
+            match b {
+                Some(b) => storage.cache_bootstrap(&base_url, &b, crate::ytm::auth::unix_now()),
+                None => storage.clear_bootstrap(&base_url),
+            }
+        })
+        .await;
+        if let Ok(Err(e)) = res {
+            tracing::debug!(error = %e, "persist bootstrap");
+        }
+    }
+
+    fn base_url(&self) -> &str {
+        self.inner.opts.base_url.trim_end_matches('/')
+    }
+
+    /// POST an innertube endpoint and parse the JSON response. The request
+    /// `context` is filled in here; if YouTube rejects our client version the
+    /// bootstrap is refreshed and the request sent once more.
+    async fn innertube(&self, path: &str, mut body: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+        let mut rebootstrapped = false;
+        loop {
+            let b = self.bootstrap().await?;
+            body["context"] = self.context(&b);
+
+            let resp = self
+                .inner
+                .layer
+                .send(|| self.innertube_post(path, &b, &body))
+                .await?;
+
+            let status = resp.status();
+            if status.is_success() {
+                return resp.json().await.context("parse json");
+            }
+
+            let text = resp.text().await.unwrap_or_default();
+            if !rebootstrapped && is_client_version_rejection(status, &text) {
+                tracing::debug!(path, "client version rejected, bootstrapping again");
+                self.forget_bootstrap().await;
+                rebootstrapped = true;
+                continue;
+            }
+            anyhow::bail!("http status {status}: {}", text.chars().take(200).collect::<String>());
+        }
+    }
+
+    fn innertube_post(
+        &self,
+        path: &str,
+        b: &Bootstrap,
+        body: &serde_json::Value,
+    ) -> reqwest::RequestBuilder {
         let url = format!(
-            "https://music.youtube.com/youtubei/v1/{path}?key={}&prettyPrint=false",
+            "{}/youtubei/v1/{path}?key={}&prettyPrint=false",
+            self.base_url(),
             b.api_key
         );
 
@@ -471,8 +752,43 @@ impl YtmClient {
             rb = rb.header("X-Goog-Visitor-Id", v);
         }
 
-        rb
+        // Pick the account within the signed-in session
+        if let Some(n) = self.inner.opts.identity.auth_user {
+            rb = rb.header("X-Goog-AuthUser", n.to_string());
+        }
+
+        rb.json(body)
     }
+
+    /// The `context` object sent with every innertube request
+    fn context(&self, b: &Bootstrap) -> serde_json::Value {
+        let opts = &self.inner.opts;
+        let mut client = json!({
+            "clientName": "WEB_REMIX",
+            "clientVersion": b.client_version,
+        });
+        if let Some(hl) = &opts.hl {
+            client["hl"] = json!(hl);
+        }
+        if let Some(gl) = &opts.gl {
+            client["gl"] = json!(gl);
+        }
+
+        let mut context = json!({ "client": client });
+        if let Some(page_id) = &opts.identity.on_behalf_of_user {
+            context["user"] = json!({ "onBehalfOfUser": page_id });
+        }
+        context
+    }
+}
+
+/// YouTube answers 400 when it no longer accepts the client version we bootstrapped
+fn is_client_version_rejection(status: reqwest::StatusCode, body: &str) -> bool {
+    if status != reqwest::StatusCode::BAD_REQUEST {
+        return false;
+    }
+    let body = body.to_ascii_lowercase();
+    body.contains("precondition check failed") || body.contains("client version")
 }
 
 fn make_sapisid_hash_auth(origin: &str, sapisid: &str) -> String {
@@ -491,76 +807,159 @@ fn extract_tracks_from_search(v: &serde_json::Value) -> Vec<Track> {
     // Best-effort extraction; YouTube's structure changes often.
     // We scan for `musicResponsiveListItemRenderer` nodes that contain a `watchEndpoint.videoId`.
     let mut out = Vec::new();
-    scan_value(v, &mut |node| {
-        let r = node.get("musicResponsiveListItemRenderer")?;
-        let video_id = extract_video_id_from_item(r)?;
-
-        let title = r
-            .pointer("/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
-            .and_then(|x| x.as_str())
-            .unwrap_or("Unknown title")
-            .to_string();
-
-        let artists = r
-            .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs")
-            .and_then(|x| x.as_array())
-            .map(|runs| {
-                runs.iter()
-                    .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
-                    .filter(|t| *t != " • ")
-                    .map(|t| t.to_string())
-                    .collect::<Vec<_>>()
-            })
-            .unwrap_or_default();
-
-        Some(Track {
-            video_id,
-            title,
-            artists,
-            album: None,
-            duration_seconds: None,
-        })
-    }, &mut out);
+    scan_value(
+        v,
+        &mut |node| extract_list_item_track(node.get("musicResponsiveListItemRenderer")?),
+        &mut out,
+    );
     out
 }
 
 fn extract_tracks_generic(v: &serde_json::Value) -> Vec<Track> {
     // Generic extraction used by browse/home/playlist responses.
     let mut out = Vec::new();
-    scan_value(v, &mut |node| {
-        let r = node.get("musicResponsiveListItemRenderer")?;
-        let video_id = extract_video_id_from_item(r)?;
+    scan_value(
+        v,
+        &mut |node| extract_list_item_track(node.get("musicResponsiveListItemRenderer")?),
+        &mut out,
+    );
+    out
+}
 
-        let title = r
-            .pointer("/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
+/// A song row (`musicResponsiveListItemRenderer`) with a video to play
+fn extract_list_item_track(r: &serde_json::Value) -> Option<Track> {
+    let video_id = extract_video_id_from_item(r)?;
+
+    let title = r
+        .pointer("/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
+        .and_then(|x| x.as_str())
+        .unwrap_or("Unknown title")
+        .to_string();
+
+    let mut track = Track {
+        video_id,
+        title,
+        ..Track::default()
+    };
+
+    // Columns after the title hold artists, album and sometimes duration,
+    // either as one "Artist • Album • 3:45" byline or one column each
+    let runs: Vec<&serde_json::Value> = r
+        .get("flexColumns")
+        .and_then(|x| x.as_array())
+        .into_iter()
+        .flatten()
+        .skip(1)
+        .filter_map(|c| c.pointer("/musicResponsiveListItemFlexColumnRenderer/text/runs"))
+        .filter_map(|x| x.as_array())
+        .flatten()
+        .collect();
+    apply_byline(&runs, &mut track);
+
+    // Playlist pages put the duration in a fixed column
+    if track.duration_seconds.is_none() {
+        track.duration_seconds = r
+            .pointer("/fixedColumns/0/musicResponsiveListItemFixedColumnRenderer/text/runs/0/text")
             .and_then(|x| x.as_str())
-            .unwrap_or("Unknown title")
-            .to_string();
+            .and_then(parse_duration_text);
+    }
 
-        let artists = r
-            .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs")
-            .and_then(|x| x.as_array())
-            .map(|runs| {
-                runs.iter()
-                    .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
-                    .filter(|t| *t != " • ")
-                    .map(|t| t.to_string())
-                    .collect::<Vec<_>>()
-            })
-            .unwrap_or_default();
+    apply_renderer_details(r, &mut track);
+    Some(track)
+}
 
-        Some(Track {
-            video_id,
-            title,
-            artists,
-            album: None,
-            duration_seconds: None,
+/// Sort byline runs into artists, album, year and duration.
+/// Linked runs are classified by page type; plain text by its shape.
+fn apply_byline(runs: &[&serde_json::Value], track: &mut Track) {
+    for run in runs {
+        let Some(text) = run.get("text").and_then(|t| t.as_str()) else {
+            continue;
+        };
+        let trimmed = text.trim();
+        if matches!(trimmed, "" | "•" | "&" | ",") {
+            continue;
+        }
+
+        if let Some(endpoint) = run.pointer("/navigationEndpoint/browseEndpoint") {
+            let browse_id = endpoint.get("browseId").and_then(|x| x.as_str()).unwrap_or("");
+            let page_type = endpoint
+                .pointer("/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType")
+                .and_then(|x| x.as_str())
+                .unwrap_or("");
+            if page_type == "MUSIC_PAGE_TYPE_ALBUM" || browse_id.starts_with("MPRE") {
+                track.album = Some(trimmed.to_string());
+                track.album_id = Some(browse_id.to_string());
+            } else if page_type == "MUSIC_PAGE_TYPE_ARTIST"
+                || page_type == "MUSIC_PAGE_TYPE_USER_CHANNEL"
+                || browse_id.starts_with("UC")
+            {
+                track.artists.push(trimmed.to_string());
+                track.artist_ids.push(Some(browse_id.to_string()));
+            }
+            continue;
+        }
+
+        if trimmed.contains(':')
+            && let Some(secs) = parse_duration_text(trimmed)
+        {
+            track.duration_seconds = Some(secs);
+        } else if trimmed.len() == 4
+            && let Ok(year) = trimmed.parse::<u16>()
+        {
+            track.year = Some(year);
+        } else if !is_byline_label(trimmed) {
+            // Unlinked artist names, e.g. "Various Artists"
+            track.artists.push(trimmed.to_string());
+            track.artist_ids.push(None);
+        }
+    }
+}
+
+/// Type labels and counters that appear in bylines but are not names
+fn is_byline_label(text: &str) -> bool {
+    matches!(text, "Song" | "Video" | "Single" | "EP" | "Album" | "Episode" | "Podcast")
+        || [" views", " plays", " likes"].iter().any(|s| text.ends_with(s))
+}
+
+/// Thumbnails, explicit badge, like status and video type from any track renderer
+fn apply_renderer_details(r: &serde_json::Value, track: &mut Track) {
+    track.thumbnails = extract_thumbnails(r);
+
+    let mut badges = Vec::new();
+    collect_key(r, "musicInlineBadgeRenderer", &mut badges);
+    track.explicit = badges.iter().any(|b| {
+        b.pointer("/icon/iconType").and_then(|x| x.as_str()) == Some("MUSIC_EXPLICIT_BADGE")
+    });
+
+    track.like_status = find_key(r, "likeButtonRenderer")
+        .and_then(|x| x.get("likeStatus"))
+        .and_then(|x| x.as_str())
+        .and_then(LikeStatus::from_api);
+    track.video_type = find_key(r, "musicVideoType")
+        .and_then(|x| x.as_str())
+        .and_then(VideoType::from_api);
+}
+
+/// Thumbnail sizes of a renderer, smallest first
+fn extract_thumbnails(r: &serde_json::Value) -> Vec<Thumbnail> {
+    let mut thumbnails: Vec<Thumbnail> = find_key(r, "thumbnails")
+        .and_then(|x| x.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|t| {
+                    Some(Thumbnail {
+                        url: t.get("url")?.as_str()?.to_string(),
+                        width: t.get("width").and_then(|x| x.as_u64()).unwrap_or(0) as u32,
+                        height: t.get("height").and_then(|x| x.as_u64()).unwrap_or(0) as u32,
+                    })
+                })
+                .collect()
         })
-    }, &mut out);
-    out
+        .unwrap_or_default();
+    thumbnails.sort_by_key(|t| t.width);
+    thumbnails
 }
 
-#[allow(dead_code)]
 fn extract_radio_tracks(v: &serde_json::Value) -> Vec<Track> {
     // Radio/next response has a different structure with playlistPanelVideoRenderer
     let mut out = Vec::new();
@@ -579,31 +978,30 @@ fn extract_radio_tracks(v: &serde_json::Value) -> Vec<Track> {
                 .unwrap_or("Unknown title")
                 .to_string();
 
-            let artists = r
-                .pointer("/shortBylineText/runs")
+            let mut track = Track {
+                video_id,
+                title,
+                ..Track::default()
+            };
+
+            // The long byline ("Artist • Album • 2021") links artists and album
+            let runs: Vec<&serde_json::Value> = r
+                .pointer("/longBylineText/runs")
+                .or_else(|| r.pointer("/shortBylineText/runs"))
                 .and_then(|x| x.as_array())
-                .map(|runs| {
-                    runs.iter()
-                        .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
-                        .filter(|t| *t != " • " && *t != " & ")
-                        .map(|t| t.to_string())
-                        .collect::<Vec<_>>()
-                })
-                .unwrap_or_default();
+                .into_iter()
+                .flatten()
+                .collect();
+            apply_byline(&runs, &mut track);
 
             // Extract duration from lengthText
-            let duration_seconds = r
+            track.duration_seconds = r
                 .pointer("/lengthText/runs/0/text")
                 .and_then(|x| x.as_str())
                 .and_then(parse_duration_text);
 
-            return Some(Track {
-                video_id,
-                title,
-                artists,
-                album: None,
-                duration_seconds,
-            });
+            apply_renderer_details(r, &mut track);
+            return Some(track);
         }
 
         // Also try automixPreviewVideoRenderer
@@ -622,9 +1020,7 @@ fn extract_radio_tracks(v: &serde_json::Value) -> Vec<Track> {
             return Some(Track {
                 video_id,
                 title,
-                artists: vec![],
-                album: None,
-                duration_seconds: None,
+                ..Track::default()
             });
         }
 
@@ -634,8 +1030,122 @@ fn extract_radio_tracks(v: &serde_json::Value) -> Vec<Track> {
     out
 }
 
+/// Continuation token for the next batch of a radio (`nextRadioContinuationData`)
+fn extract_radio_continuation(v: &serde_json::Value) -> Option<String> {
+    find_key(v, "nextRadioContinuationData")
+        .and_then(|x| x.get("continuation"))
+        .and_then(|x| x.as_str())
+        .map(|s| s.to_string())
+}
+
+/// Browse id (`MPLY...`) of the Lyrics tab in a `next` response
+fn extract_lyrics_browse_id(v: &serde_json::Value) -> Option<String> {
+    let mut tabs = Vec::new();
+    collect_key(v, "tabRenderer", &mut tabs);
+    tabs.into_iter().find_map(|tab| {
+        let id = tab.pointer("/endpoint/browseEndpoint/browseId")?.as_str()?;
+        id.starts_with("MPLY").then(|| id.to_string())
+    })
+}
+
+/// Lyrics text from a lyrics browse response
+fn extract_lyrics(v: &serde_json::Value) -> Option<String> {
+    find_key(v, "musicDescriptionShelfRenderer")?
+        .get("description")
+        .and_then(text_of)
+}
+
+/// Name and handle of the signed-in account from the account menu
+fn extract_account_info(v: &serde_json::Value) -> Option<AccountInfo> {
+    let header = find_key(v, "activeAccountHeaderRenderer")?;
+    let name = header
+        .pointer("/accountName/runs/0/text")
+        .and_then(|x| x.as_str())?
+        .to_string();
+    let handle = header
+        .pointer("/channelHandle/runs/0/text")
+        .and_then(|x| x.as_str())
+        .map(|s| s.to_string());
+    Some(AccountInfo { name, handle })
+}
+
+fn extract_channels(v: &serde_json::Value) -> Vec<Channel> {
+    let mut items = Vec::new();
+    collect_key(v, "accountItem", &mut items);
+
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let name = text_of(item.get("accountName")?)?;
+            let handle = item.get("channelHandle").and_then(text_of);
+            // Brand accounts carry a page id; the primary channel does not
+            let page_id = item
+                .pointer("/serviceEndpoint/selectActiveIdentityEndpoint/supportedTokens")
+                .and_then(|x| x.as_array())
+                .and_then(|tokens| {
+                    tokens.iter().find_map(|t| {
+                        t.pointer("/pageIdToken/pageId").and_then(|x| x.as_str())
+                    })
+                })
+                .map(|s| s.to_string());
+            let selected = item
+                .get("isSelected")
+                .and_then(|x| x.as_bool())
+                .unwrap_or(false);
+            Some(Channel {
+                name,
+                handle,
+                page_id,
+                selected,
+            })
+        })
+        .collect()
+}
+
+/// Text of a `simpleText` or `runs` node
+fn text_of(v: &serde_json::Value) -> Option<String> {
+    if let Some(t) = v.get("simpleText").and_then(|x| x.as_str()) {
+        return Some(t.to_string());
+    }
+    let runs = v.get("runs")?.as_array()?;
+    Some(
+        runs.iter()
+            .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
+            .collect(),
+    )
+}
+
+fn collect_key<'a>(v: &'a serde_json::Value, key: &str, out: &mut Vec<&'a serde_json::Value>) {
+    match v {
+        serde_json::Value::Object(o) => {
+            for (k, x) in o {
+                if k == key {
+                    out.push(x);
+                } else {
+                    collect_key(x, key, out);
+                }
+            }
+        }
+        serde_json::Value::Array(a) => a.iter().for_each(|x| collect_key(x, key, out)),
+        _ => {}
+    }
+}
+
+/// Depth-first search for the first value stored under `key`
+fn find_key<'a>(v: &'a serde_json::Value, key: &str) -> Option<&'a serde_json::Value> {
+    match v {
+        serde_json::Value::Object(o) => {
+            if let Some(x) = o.get(key) {
+                return Some(x);
+            }
+            o.values().find_map(|x| find_key(x, key))
+        }
+        serde_json::Value::Array(a) => a.iter().find_map(|x| find_key(x, key)),
+        _ => None,
+    }
+}
+
 /// Parse duration text like "3:45" or "1:23:45" into seconds
-#[allow(dead_code)]
 fn parse_duration_text(text: &str) -> Option<u32> {
     let parts: Vec<&str> = text.split(':').collect();
     match parts.len() {
@@ -739,36 +1249,11 @@ fn extract_continuation_token(v: &serde_json::Value) -> Option<String> {
 fn extract_tracks_from_continuation(v: &serde_json::Value) -> Vec<Track> {
     // Continuation responses have tracks in continuationContents.musicShelfContinuation.contents
     let mut out = Vec::new();
-    scan_value(v, &mut |node| {
-        let r = node.get("musicResponsiveListItemRenderer")?;
-        let video_id = extract_video_id_from_item(r)?;
-
-        let title = r
-            .pointer("/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
-            .and_then(|x| x.as_str())
-            .unwrap_or("Unknown title")
-            .to_string();
-
-        let artists = r
-            .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs")
-            .and_then(|x| x.as_array())
-            .map(|runs| {
-                runs.iter()
-                    .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
-                    .filter(|t| *t != " • ")
-                    .map(|t| t.to_string())
-                    .collect::<Vec<_>>()
-            })
-            .unwrap_or_default();
-
-        Some(Track {
-            video_id,
-            title,
-            artists,
-            album: None,
-            duration_seconds: None,
-        })
-    }, &mut out);
+    scan_value(
+        v,
+        &mut |node| extract_list_item_track(node.get("musicResponsiveListItemRenderer")?),
+        &mut out,
+    );
     out
 }
 
@@ -810,81 +1295,78 @@ fn extract_playlists_from_search(v: &serde_json::Value) -> Vec<Playlist> {
             if let Some(browse_id) = r
                 .pointer("/navigationEndpoint/browseEndpoint/browseId")
                 .and_then(|x| x.as_str())
+                && (browse_id.starts_with("VL") || browse_id.starts_with("PL"))
             {
-                if browse_id.starts_with("VL") || browse_id.starts_with("PL") {
-                    let playlist_id = browse_id.strip_prefix("VL").unwrap_or(browse_id).to_string();
+                let playlist_id = browse_id.strip_prefix("VL").unwrap_or(browse_id).to_string();
 
-                    let title = r
-                        .pointer("/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
-                        .and_then(|x| x.as_str())
-                        .unwrap_or("Unknown playlist")
-                        .to_string();
+                let title = r
+                    .pointer("/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
+                    .and_then(|x| x.as_str())
+                    .unwrap_or("Unknown playlist")
+                    .to_string();
 
-                    // Get author from second flex column
-                    let author = r
-                        .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
-                        .and_then(|x| x.as_str())
-                        .map(|s| s.to_string());
+                // Get author from second flex column
+                let author = r
+                    .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
+                    .and_then(|x| x.as_str())
+                    .map(|s| s.to_string());
 
-                    // Try to get track count from subtitle runs
-                    let track_count = r
-                        .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs")
-                        .and_then(|x| x.as_array())
-                        .and_then(|runs| {
-                            runs.iter()
-                                .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
-                                .find_map(|text| {
-                                    // Look for patterns like "45 songs" or "45 tracks"
-                                    if text.contains("song") || text.contains("track") {
-                                        text.split_whitespace()
-                                            .next()
-                                            .and_then(|n| n.parse::<u32>().ok())
-                                    } else {
-                                        None
-                                    }
-                                })
-                        });
-
-                    return Some(Playlist {
-                        id: playlist_id,
-                        title,
-                        author,
-                        track_count,
-                        thumbnail_url: None,
+                // Try to get track count from subtitle runs
+                let track_count = r
+                    .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs")
+                    .and_then(|x| x.as_array())
+                    .and_then(|runs| {
+                        runs.iter()
+                            .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
+                            .find_map(|text| {
+                                // Look for patterns like "45 songs" or "45 tracks"
+                                if text.contains("song") || text.contains("track") {
+                                    text.split_whitespace()
+                                        .next()
+                                        .and_then(|n| n.parse::<u32>().ok())
+                                } else {
+                                    None
+                                }
+                            })
                     });
-                }
+
+                return Some(Playlist {
+                    id: playlist_id,
+                    title,
+                    author,
+                    track_count,
+                    thumbnail_url: None,
+                });
             }
         }
 
         // Also check musicTwoRowItemRenderer
-        if let Some(r) = node.get("musicTwoRowItemRenderer") {
-            if let Some(browse_id) = r
+        if let Some(r) = node.get("musicTwoRowItemRenderer")
+            && let Some(browse_id) = r
                 .pointer("/navigationEndpoint/browseEndpoint/browseId")
                 .and_then(|x| x.as_str())
-            {
-                if browse_id.starts_with("VL") || browse_id.starts_with("PL") {
-                    let playlist_id = browse_id.strip_prefix("VL").unwrap_or(browse_id).to_string();
+            && (browse_id.starts_with("VL") || browse_id.starts_with("PL"))
+        {
+            let playlist_id = browse_id.strip_prefix("VL").unwrap_or(browse_id).to_string();
 
-                    let title = r
-                        .pointer("/title/runs/0/text")
-                        .and_then(|x| x.as_str())
-                        .unwrap_or("Unknown playlist")
-                        .to_string();
+            let title = r
+                .pointer("/title/runs/0/text")
+                .and_then(|x| x.as_str())
+                .unwrap_or("Unknown playlist")
+                .to_string();
 
-                    let author = r
-                        .pointer("/subtitle/runs/0/text")
-                        .and_then(|x| x.as_str())
-                        .map(|s| s.to_string());
+            let author = r
+                .pointer("/subtitle/runs/0/text")
+                .and_then(|x| x.as_str())
+                .map(|s| s.to_string());
 
-                    return Some(Playlist {
-                        id: playlist_id,
-                        title,
-                        author,
-                        track_count: None,
-                        thumbnail_url: None,
-                    });
-                }
-            }
+            return Some(Playlist {
+                id: playlist_id,
+                title,
+                author,
+                track_count: None,
+                thumbnail_url: None,
+            });
         }
 
         None
@@ -1003,6 +1485,231 @@ where
     }
 }
 
+/// Extract shelves from a browse response (Home, Explore pages) or a section list continuation
+fn extract_shelves(v: &serde_json::Value) -> Vec<Shelf> {
+    let sections = v
+        .pointer("/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents")
+        .or_else(|| v.pointer("/continuationContents/sectionListContinuation/contents"))
+        .and_then(|x| x.as_array());
+
+    let Some(sections) = sections else {
+        return Vec::new();
+    };
+
+    sections
+        .iter()
+        .filter_map(|section| {
+            // Carousels (Home, Explore, Charts), grids (New releases, mood categories)
+            // and plain shelves (Charts lists) all hold the same item renderers.
+            let (title, contents) = if let Some(shelf) = section
+                .get("musicCarouselShelfRenderer")
+                .or_else(|| section.get("musicImmersiveCarouselShelfRenderer"))
+            {
+                let title = shelf
+                    .pointer("/header/musicCarouselShelfBasicHeaderRenderer/title/runs/0/text")
+                    .or_else(|| shelf.pointer("/header/musicImmersiveCarouselShelfBasicHeaderRenderer/title/runs/0/text"));
+                (title, shelf.get("contents"))
+            } else if let Some(grid) = section.get("gridRenderer") {
+                let title = grid.pointer("/header/gridHeaderRenderer/title/runs/0/text");
+                (title, grid.get("items"))
+            } else if let Some(shelf) = section.get("musicShelfRenderer") {
+                (shelf.pointer("/title/runs/0/text"), shelf.get("contents"))
+            } else {
+                return None;
+            };
+
+            let title = title
+                .and_then(|x| x.as_str())
+                .unwrap_or("Untitled")
+                .to_string();
+
+            let contents = contents?.as_array()?;
+            let layout = if contents
+                .iter()
+                .any(|c| c.get("musicResponsiveListItemRenderer").is_some())
+            {
+                ShelfLayout::List
+            } else {
+                ShelfLayout::Cards
+            };
+
+            let items: Vec<ShelfItem> = contents.iter().filter_map(extract_shelf_item).collect();
+            if items.is_empty() {
+                return None;
+            }
+
+            Some(Shelf { title, layout, items })
+        })
+        .collect()
+}
+
+fn extract_shelf_item(node: &serde_json::Value) -> Option<ShelfItem> {
+    if let Some(r) = node.get("musicResponsiveListItemRenderer") {
+        let title = r
+            .pointer("/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
+            .and_then(|x| x.as_str())
+            .unwrap_or("Unknown title")
+            .to_string();
+
+        let subtitle_runs = r
+            .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs")
+            .and_then(|x| x.as_array())
+            .map(|runs| {
+                runs.iter()
+                    .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
+                    .filter(|t| *t != " • " && *t != " & " && *t != ", ")
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // Rows without a video (e.g. Top artists in Charts) link to a browse page instead
+        return match extract_list_item_track(r) {
+            Some(track) => Some(ShelfItem::Track(track)),
+            None => classify_browse_item(r, title, subtitle_runs, None),
+        };
+    }
+
+    let r = node.get("musicTwoRowItemRenderer")?;
+
+    let title = r
+        .pointer("/title/runs/0/text")
+        .and_then(|x| x.as_str())
+        .unwrap_or("Untitled")
+        .to_string();
+
+    let subtitle_runs: Vec<String> = r
+        .pointer("/subtitle/runs")
+        .and_then(|x| x.as_array())
+        .map(|runs| {
+            runs.iter()
+                .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
+                .filter(|t| *t != " • " && *t != " & " && *t != ", ")
+                .map(|t| t.to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Songs and videos shown as cards play directly
+    if let Some(video_id) = r
+        .pointer("/navigationEndpoint/watchEndpoint/videoId")
+        .and_then(|x| x.as_str())
+    {
+        let mut track = Track {
+            video_id: video_id.to_string(),
+            title,
+            ..Track::default()
+        };
+        // Subtitle is typically "Song • Artist" or "Artist • 1.2M views"
+        let runs: Vec<&serde_json::Value> = r
+            .pointer("/subtitle/runs")
+            .and_then(|x| x.as_array())
+            .into_iter()
+            .flatten()
+            .collect();
+        apply_byline(&runs, &mut track);
+        apply_renderer_details(r, &mut track);
+        return Some(ShelfItem::Track(track));
+    }
+
+    let thumbnail_url = r
+        .pointer("/thumbnailRenderer/musicThumbnailRenderer/thumbnail/thumbnails/0/url")
+        .and_then(|x| x.as_str())
+        .map(|s| s.to_string());
+
+    classify_browse_item(r, title, subtitle_runs, thumbnail_url)
+}
+
+/// Turn a renderer with a `browseEndpoint` into an album, artist or playlist item
+fn classify_browse_item(
+    r: &serde_json::Value,
+    title: String,
+    subtitle_runs: Vec<String>,
+    thumbnail_url: Option<String>,
+) -> Option<ShelfItem> {
+    let browse_id = r
+        .pointer("/navigationEndpoint/browseEndpoint/browseId")
+        .and_then(|x| x.as_str())?;
+    let page_type = r
+        .pointer("/navigationEndpoint/browseEndpoint/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType")
+        .and_then(|x| x.as_str())
+        .unwrap_or("");
+
+    let playlist = Playlist {
+        id: browse_id.strip_prefix("VL").unwrap_or(browse_id).to_string(),
+        title,
+        author: (!subtitle_runs.is_empty()).then(|| subtitle_runs.join(" • ")),
+        track_count: None,
+        thumbnail_url,
+    };
+
+    if page_type == "MUSIC_PAGE_TYPE_ALBUM" || browse_id.starts_with("MPRE") {
+        Some(ShelfItem::Album(playlist))
+    } else if page_type == "MUSIC_PAGE_TYPE_ARTIST" || browse_id.starts_with("UC") {
+        Some(ShelfItem::Artist(playlist))
+    } else if page_type == "MUSIC_PAGE_TYPE_PLAYLIST"
+        || browse_id.starts_with("VL")
+        || browse_id.starts_with("PL")
+        || browse_id.starts_with("RD")
+    {
+        Some(ShelfItem::Playlist(playlist))
+    } else {
+        None
+    }
+}
+
+/// Extract the category buttons from the Moods & genres page
+fn extract_mood_categories(v: &serde_json::Value) -> Vec<MoodCategory> {
+    let Some(sections) = v
+        .pointer("/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents")
+        .and_then(|x| x.as_array())
+    else {
+        return Vec::new();
+    };
+
+    let mut out = Vec::new();
+    for grid in sections.iter().filter_map(|s| s.get("gridRenderer")) {
+        let section = grid
+            .pointer("/header/gridHeaderRenderer/title/runs/0/text")
+            .and_then(|x| x.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        let Some(items) = grid.get("items").and_then(|x| x.as_array()) else {
+            continue;
+        };
+
+        for button in items.iter().filter_map(|i| i.get("musicNavigationButtonRenderer")) {
+            let Some(title) = button
+                .pointer("/buttonText/runs/0/text")
+                .and_then(|x| x.as_str())
+            else {
+                continue;
+            };
+            let Some(params) = button
+                .pointer("/clickCommand/browseEndpoint/params")
+                .and_then(|x| x.as_str())
+            else {
+                continue;
+            };
+            out.push(MoodCategory {
+                section: section.clone(),
+                title: title.to_string(),
+                params: params.to_string(),
+            });
+        }
+    }
+    out
+}
+
+/// Continuation token for the next page of a section list (Home, Explore pages)
+fn extract_section_list_continuation(v: &serde_json::Value) -> Option<String> {
+    v.pointer("/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/continuations/0/nextContinuationData/continuation")
+        .or_else(|| v.pointer("/continuationContents/sectionListContinuation/continuations/0/nextContinuationData/continuation"))
+        .and_then(|x| x.as_str())
+        .map(|s| s.to_string())
+}
+
 /// Extract search items (tracks and playlists) from search response
 #[allow(dead_code)]
 fn extract_search_items(v: &serde_json::Value) -> Vec<SearchItem> {
@@ -1011,32 +1718,8 @@ fn extract_search_items(v: &serde_json::Value) -> Vec<SearchItem> {
         // Try to extract as a track (musicResponsiveListItemRenderer with video_id)
         if let Some(r) = node.get("musicResponsiveListItemRenderer") {
             // Check if this is a track (has videoId in overlay or playlistItemData)
-            if let Some(video_id) = extract_video_id_from_item(r) {
-                let title = r
-                    .pointer("/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text")
-                    .and_then(|x| x.as_str())
-                    .unwrap_or("Unknown title")
-                    .to_string();
-
-                let artists = r
-                    .pointer("/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs")
-                    .and_then(|x| x.as_array())
-                    .map(|runs| {
-                        runs.iter()
-                            .filter_map(|r| r.get("text").and_then(|t| t.as_str()))
-                            .filter(|t| *t != " • " && *t != " & ")
-                            .map(|t| t.to_string())
-                            .collect::<Vec<_>>()
-                    })
-                    .unwrap_or_default();
-
-                return Some(SearchItem::Track(Track {
-                    video_id,
-                    title,
-                    artists,
-                    album: None,
-                    duration_seconds: None,
-                }));
+            if let Some(track) = extract_list_item_track(r) {
+                return Some(SearchItem::Track(track));
             }
 
             // Check if this is a playlist (has browseId starting with VL or is a playlist type)
@@ -1071,34 +1754,32 @@ fn extract_search_items(v: &serde_json::Value) -> Vec<SearchItem> {
         }
 
         // Try musicTwoRowItemRenderer (used for some playlist/album results)
-        if let Some(r) = node.get("musicTwoRowItemRenderer") {
-            if let Some(browse_id) = r
+        if let Some(r) = node.get("musicTwoRowItemRenderer")
+            && let Some(browse_id) = r
                 .pointer("/navigationEndpoint/browseEndpoint/browseId")
                 .and_then(|x| x.as_str())
-            {
-                if browse_id.starts_with("VL") || browse_id.starts_with("PL") {
-                    let playlist_id = browse_id.strip_prefix("VL").unwrap_or(browse_id).to_string();
+            && (browse_id.starts_with("VL") || browse_id.starts_with("PL"))
+        {
+            let playlist_id = browse_id.strip_prefix("VL").unwrap_or(browse_id).to_string();
 
-                    let title = r
-                        .pointer("/title/runs/0/text")
-                        .and_then(|x| x.as_str())
-                        .unwrap_or("Unknown playlist")
-                        .to_string();
+            let title = r
+                .pointer("/title/runs/0/text")
+                .and_then(|x| x.as_str())
+                .unwrap_or("Unknown playlist")
+                .to_string();
 
-                    let author = r
-                        .pointer("/subtitle/runs/0/text")
-                        .and_then(|x| x.as_str())
-                        .map(|s| s.to_string());
+            let author = r
+                .pointer("/subtitle/runs/0/text")
+                .and_then(|x| x.as_str())
+                .map(|s| s.to_string());
 
-                    return Some(SearchItem::Playlist(Playlist {
-                        id: playlist_id,
-                        title,
-                        author,
-                        track_count: None,
-                        thumbnail_url: None,
-                    }));
-                }
-            }
+            return Some(SearchItem::Playlist(Playlist {
+                id: playlist_id,
+                title,
+                author,
+                track_count: None,
+                thumbnail_url: None,
+            }));
         }
 
         None
@@ -1131,3 +1812,371 @@ where
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_shelves() {
+        let v = json!({
+            "contents": {"singleColumnBrowseResultsRenderer": {"tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {
+                "contents": [
+                    {"musicCarouselShelfRenderer": {
+                        "header": {"musicCarouselShelfBasicHeaderRenderer": {"title": {"runs": [{"text": "Quick picks"}]}}},
+                        "contents": [{"musicResponsiveListItemRenderer": {
+                            "flexColumns": [
+                                {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [
+                                    {"text": "Song", "navigationEndpoint": {"watchEndpoint": {"videoId": "vid1"}}}
+                                ]}}},
+                                {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Artist"}]}}}
+                            ]
+                        }}]
+                    }},
+                    {"musicCarouselShelfRenderer": {
+                        "header": {"musicCarouselShelfBasicHeaderRenderer": {"title": {"runs": [{"text": "Albums for you"}]}}},
+                        "contents": [{"musicTwoRowItemRenderer": {
+                            "title": {"runs": [{"text": "An Album"}]},
+                            "subtitle": {"runs": [{"text": "Album"}, {"text": " • "}, {"text": "Artist"}]},
+                            "navigationEndpoint": {"browseEndpoint": {"browseId": "MPREb_abc"}}
+                        }}]
+                    }}
+                ],
+                "continuations": [{"nextContinuationData": {"continuation": "tok"}}]
+            }}}}]}}
+        });
+
+        let shelves = extract_shelves(&v);
+        assert_eq!(shelves.len(), 2);
+        assert_eq!(shelves[0].title, "Quick picks");
+        assert_eq!(shelves[0].layout, ShelfLayout::List);
+        assert!(matches!(&shelves[0].items[0], ShelfItem::Track(t) if t.video_id == "vid1"));
+        assert_eq!(shelves[1].layout, ShelfLayout::Cards);
+        assert!(matches!(&shelves[1].items[0], ShelfItem::Album(p) if p.id == "MPREb_abc"));
+        assert_eq!(extract_section_list_continuation(&v).as_deref(), Some("tok"));
+    }
+
+    #[test]
+    fn test_extract_mood_categories() {
+        let v = json!({
+            "contents": {"singleColumnBrowseResultsRenderer": {"tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {
+                "contents": [
+                    {"gridRenderer": {
+                        "header": {"gridHeaderRenderer": {"title": {"runs": [{"text": "Moods & moments"}]}}},
+                        "items": [
+                            {"musicNavigationButtonRenderer": {
+                                "buttonText": {"runs": [{"text": "Chill"}]},
+                                "clickCommand": {"browseEndpoint": {"browseId": "FEmusic_moods_and_genres_category", "params": "p_chill"}}
+                            }},
+                            {"musicNavigationButtonRenderer": {"buttonText": {"runs": [{"text": "No params"}]}}}
+                        ]
+                    }},
+                    {"gridRenderer": {
+                        "header": {"gridHeaderRenderer": {"title": {"runs": [{"text": "Genres"}]}}},
+                        "items": [{"musicNavigationButtonRenderer": {
+                            "buttonText": {"runs": [{"text": "Jazz"}]},
+                            "clickCommand": {"browseEndpoint": {"browseId": "FEmusic_moods_and_genres_category", "params": "p_jazz"}}
+                        }}]
+                    }}
+                ]
+            }}}}]}}
+        });
+
+        let categories = extract_mood_categories(&v);
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories[0].section, "Moods & moments");
+        assert_eq!(categories[0].title, "Chill");
+        assert_eq!(categories[0].params, "p_chill");
+        assert_eq!(categories[1].section, "Genres");
+        assert_eq!(categories[1].title, "Jazz");
+    }
+
+    #[test]
+    fn test_extract_shelves_grid_and_chart_artists() {
+        let v = json!({
+            "contents": {"singleColumnBrowseResultsRenderer": {"tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {
+                "contents": [
+                    {"gridRenderer": {
+                        "header": {"gridHeaderRenderer": {"title": {"runs": [{"text": "New albums & singles"}]}}},
+                        "items": [{"musicTwoRowItemRenderer": {
+                            "title": {"runs": [{"text": "Fresh"}]},
+                            "navigationEndpoint": {"browseEndpoint": {"browseId": "MPREb_new"}}
+                        }}]
+                    }},
+                    {"musicShelfRenderer": {
+                        "title": {"runs": [{"text": "Top artists"}]},
+                        "contents": [{"musicResponsiveListItemRenderer": {
+                            "flexColumns": [
+                                {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Someone"}]}}}
+                            ],
+                            "navigationEndpoint": {"browseEndpoint": {
+                                "browseId": "UCartist",
+                                "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": "MUSIC_PAGE_TYPE_ARTIST"}}
+                            }}
+                        }}]
+                    }}
+                ]
+            }}}}]}}
+        });
+
+        let shelves = extract_shelves(&v);
+        assert_eq!(shelves.len(), 2);
+        assert_eq!(shelves[0].title, "New albums & singles");
+        assert!(matches!(&shelves[0].items[0], ShelfItem::Album(p) if p.id == "MPREb_new"));
+        assert_eq!(shelves[1].title, "Top artists");
+        assert!(matches!(&shelves[1].items[0], ShelfItem::Artist(p) if p.id == "UCartist"));
+    }
+
+    #[test]
+    fn test_extract_radio_tracks_and_continuation() {
+        let v = json!({
+            "contents": {"singleColumnMusicWatchNextResultsRenderer": {"tabbedRenderer": {"watchNextTabbedResultsRenderer": {"tabs": [
+                {"tabRenderer": {"content": {"musicQueueRenderer": {"content": {"playlistPanelRenderer": {
+                    "contents": [
+                        {"playlistPanelVideoRenderer": {
+                            "title": {"runs": [{"text": "Seed"}]},
+                            "shortBylineText": {"runs": [{"text": "Artist"}]},
+                            "lengthText": {"runs": [{"text": "3:05"}]},
+                            "navigationEndpoint": {"watchEndpoint": {"videoId": "seed1"}}
+                        }},
+                        {"playlistPanelVideoRenderer": {
+                            "title": {"runs": [{"text": "Next"}]},
+                            "navigationEndpoint": {"watchEndpoint": {"videoId": "next1"}}
+                        }}
+                    ],
+                    "continuations": [{"nextRadioContinuationData": {"continuation": "radio_tok"}}]
+                }}}}}}
+            ]}}}}
+        });
+
+        let tracks = extract_radio_tracks(&v);
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(tracks[0].video_id, "seed1");
+        assert_eq!(tracks[0].duration_seconds, Some(185));
+        assert_eq!(extract_radio_continuation(&v).as_deref(), Some("radio_tok"));
+    }
+
+    #[test]
+    fn test_extract_track_metadata() {
+        let artist = |name: &str, id: &str| json!({"text": name, "navigationEndpoint": {"browseEndpoint": {
+            "browseId": id,
+            "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": "MUSIC_PAGE_TYPE_ARTIST"}}
+        }}});
+        let v = json!({"musicShelfRenderer": {"contents": [{"musicResponsiveListItemRenderer": {
+            "thumbnail": {"musicThumbnailRenderer": {"thumbnail": {"thumbnails": [
+                {"url": "https://t/120", "width": 120, "height": 120},
+                {"url": "https://t/60", "width": 60, "height": 60}
+            ]}}},
+            "flexColumns": [
+                {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Tune", "navigationEndpoint": {
+                    "watchEndpoint": {"videoId": "vid1", "watchEndpointMusicSupportedConfigs": {
+                        "watchEndpointMusicConfig": {"musicVideoType": "MUSIC_VIDEO_TYPE_ATV"}
+                    }}
+                }}]}}},
+                {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [
+                    {"text": "Song"}, {"text": " • "},
+                    artist("One", "UC1"), {"text": " & "}, {"text": "Guest"}, {"text": " • "},
+                    {"text": "Record", "navigationEndpoint": {"browseEndpoint": {"browseId": "MPREb_rec"}}},
+                    {"text": " • "}, {"text": "2019"}, {"text": " • "}, {"text": "3:05"}
+                ]}}}
+            ],
+            "badges": [{"musicInlineBadgeRenderer": {"icon": {"iconType": "MUSIC_EXPLICIT_BADGE"}}}],
+            "menu": {"menuRenderer": {"topLevelButtons": [{"likeButtonRenderer": {"likeStatus": "LIKE"}}]}}
+        }}]}});
+
+        let tracks = extract_tracks_generic(&v);
+        assert_eq!(tracks.len(), 1);
+        let t = &tracks[0];
+        assert_eq!(t.artists, vec!["One", "Guest"]);
+        assert_eq!(t.artist_ids, vec![Some("UC1".to_string()), None]);
+        assert_eq!(t.album.as_deref(), Some("Record"));
+        assert_eq!(t.album_id.as_deref(), Some("MPREb_rec"));
+        assert_eq!(t.year, Some(2019));
+        assert_eq!(t.duration_seconds, Some(185));
+        assert!(t.explicit);
+        assert_eq!(t.like_status, Some(LikeStatus::Like));
+        assert_eq!(t.video_type, Some(VideoType::Song));
+        assert_eq!(t.thumbnail_url(100), Some("https://t/120"));
+        assert_eq!(t.thumbnail_url(0), Some("https://t/60"));
+    }
+
+    #[test]
+    fn test_extract_lyrics() {
+        let next = json!({"contents": {"singleColumnMusicWatchNextResultsRenderer": {"tabbedRenderer": {
+            "watchNextTabbedResultsRenderer": {"tabs": [
+                {"tabRenderer": {"title": "Up next"}},
+                {"tabRenderer": {"title": "Lyrics", "endpoint": {"browseEndpoint": {"browseId": "MPLYt_abc"}}}},
+                {"tabRenderer": {"title": "Related", "endpoint": {"browseEndpoint": {"browseId": "MPTRt_abc"}}}}
+            ]}
+        }}}});
+        assert_eq!(extract_lyrics_browse_id(&next).as_deref(), Some("MPLYt_abc"));
+
+        let browse = json!({"contents": {"sectionListRenderer": {"contents": [{"musicDescriptionShelfRenderer": {
+            "description": {"runs": [{"text": "First line\nSecond line"}]},
+            "footer": {"runs": [{"text": "Source: Musixmatch"}]}
+        }}]}}});
+        assert_eq!(extract_lyrics(&browse).as_deref(), Some("First line\nSecond line"));
+    }
+
+    #[test]
+    fn test_extract_account_info() {
+        let v = json!({
+            "actions": [{"openPopupAction": {"popup": {"multiPageMenuRenderer": {
+                "header": {"activeAccountHeaderRenderer": {
+                    "accountName": {"runs": [{"text": "Jane Doe"}]},
+                    "channelHandle": {"runs": [{"text": "@janedoe"}]}
+                }}
+            }}}}]
+        });
+        let info = extract_account_info(&v).unwrap();
+        assert_eq!(info.name, "Jane Doe");
+        assert_eq!(info.handle.as_deref(), Some("@janedoe"));
+
+        assert!(extract_account_info(&json!({"actions": []})).is_none());
+    }
+
+    #[test]
+    fn test_extract_channels() {
+        let v = json!({
+            "actions": [{"getMultiPageMenuAction": {"menu": {"multiPageMenuRenderer": {"sections": [
+                {"accountSectionListRenderer": {"contents": [{"accountItemSectionRenderer": {"contents": [
+                    {"accountItem": {
+                        "accountName": {"simpleText": "Jane Doe"},
+                        "channelHandle": {"simpleText": "@janedoe"},
+                        "isSelected": true,
+                        "serviceEndpoint": {"selectActiveIdentityEndpoint": {"supportedTokens": [
+                            {"accountStateToken": {"hasChannel": true}}
+                        ]}}
+                    }},
+                    {"accountItem": {
+                        "accountName": {"runs": [{"text": "Jane's Band"}]},
+                        "isSelected": false,
+                        "serviceEndpoint": {"selectActiveIdentityEndpoint": {"supportedTokens": [
+                            {"pageIdToken": {"pageId": "1122334455"}}
+                        ]}}
+                    }}
+                ]}}]}}
+            ]}}}}]
+        });
+        let channels = extract_channels(&v);
+        assert_eq!(channels.len(), 2);
+        assert_eq!(channels[0].name, "Jane Doe");
+        assert_eq!(channels[0].handle.as_deref(), Some("@janedoe"));
+        assert!(channels[0].selected);
+        assert_eq!(channels[0].page_id, None);
+        assert_eq!(channels[1].name, "Jane's Band");
+        assert_eq!(channels[1].page_id.as_deref(), Some("1122334455"));
+    }
+
+    #[test]
+    fn test_context_carries_locale_and_identity() {
+        let b = Bootstrap {
+            api_key: "key".to_string(),
+            client_version: "1.20250101.01.00".to_string(),
+            visitor_data: None,
+        };
+
+        let plain = YtmClient::new(None, ClientOptions::default()).unwrap();
+        let ctx = plain.context(&b);
+        assert_eq!(ctx["client"]["clientVersion"], "1.20250101.01.00");
+        assert!(ctx["client"].get("hl").is_none());
+        assert!(ctx.get("user").is_none());
+
+        let opts = ClientOptions {
+            identity: Identity {
+                auth_user: Some(1),
+                on_behalf_of_user: Some("1122334455".to_string()),
+            },
+            hl: Some("ja".to_string()),
+            gl: Some("JP".to_string()),
+            ..ClientOptions::default()
+        };
+        let ctx = YtmClient::new(None, opts).unwrap().context(&b);
+        assert_eq!(ctx["client"]["hl"], "ja");
+        assert_eq!(ctx["client"]["gl"], "JP");
+        assert_eq!(ctx["user"]["onBehalfOfUser"], "1122334455");
+    }
+
+    /// Minimal stand-in for music.youtube.com: serves the bootstrap page and
+    /// rejects innertube calls made with client version "1.0".
+    async fn stand_in_server() -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let page_hits = Arc::new(AtomicUsize::new(0));
+        let hits = page_hits.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut sock, _)) = listener.accept().await {
+                let mut req = Vec::new();
+                let mut buf = [0u8; 4096];
+                // Read headers and the body announced by content-length
+                loop {
+                    let n = sock.read(&mut buf).await.unwrap_or(0);
+                    if n == 0 {
+                        break;
+                    }
+                    req.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&req);
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let len = text[..end]
+                            .lines()
+                            .filter_map(|l| l.to_ascii_lowercase().strip_prefix("content-length:").map(str::to_string))
+                            .find_map(|v| v.trim().parse::<usize>().ok())
+                            .unwrap_or(0);
+                        if req.len() >= end + 4 + len {
+                            break;
+                        }
+                    }
+                }
+                let req = String::from_utf8_lossy(&req).to_string();
+
+                let (status, body) = if req.starts_with("GET / ") {
+                    let n = hits.fetch_add(1, Ordering::SeqCst);
+                    let version = if n == 0 { "1.0" } else { "2.0" };
+                    (200, format!(r#"<script>ytcfg.set({{"INNERTUBE_API_KEY":"k","INNERTUBE_CLIENT_VERSION":"{version}"}})</script>"#))
+                } else if req.contains(r#""clientVersion":"1.0""#) {
+                    (400, r#"{"error":{"message":"Precondition check failed."}}"#.to_string())
+                } else {
+                    (200, "{}".to_string())
+                };
+                let resp = format!(
+                    "HTTP/1.1 {status} X\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = sock.write_all(resp.as_bytes()).await;
+            }
+        });
+
+        (format!("http://{addr}"), page_hits)
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_refresh_and_persistence() {
+        use std::sync::atomic::Ordering;
+
+        let (base_url, page_hits) = stand_in_server().await;
+        let cache = std::env::temp_dir().join(format!("void-bootstrap-test-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&cache);
+        let opts = ClientOptions {
+            base_url,
+            bootstrap_cache: Some(cache.clone()),
+            requests_per_second: 0.0,
+            ..ClientOptions::default()
+        };
+
+        // Stale client version is rejected once, then bootstrapped again
+        let client = YtmClient::new(None, opts.clone()).unwrap();
+        client.browse_home().await.unwrap();
+        assert_eq!(page_hits.load(Ordering::SeqCst), 2);
+
+        // A new client reuses the persisted bootstrap
+        let client = YtmClient::new(None, opts).unwrap();
+        client.browse_home().await.unwrap();
+        assert_eq!(page_hits.load(Ordering::SeqCst), 2);
+        assert_eq!(client.bootstrap().await.unwrap().client_version, "2.0");
+
+        let _ = std::fs::remove_file(&cache);
+    }
+}
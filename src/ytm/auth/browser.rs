@@ -0,0 +1,424 @@
+//! Read cookies directly from Firefox- and Chromium-family browser profiles,
+//! so API calls are authenticated without exporting a Netscape cookie file.
+
+use super::Cookie;
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+
+/// Only cookies for these domains are imported
+const COOKIE_DOMAINS: [&str; 1] = ["youtube.com"];
+
+/// Seconds between 1601-01-01 (Chromium's epoch) and 1970-01-01
+const CHROMIUM_EPOCH_OFFSET: i64 = 11_644_473_600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Family {
+    Firefox,
+    Chromium,
+}
+
+fn family(browser: &str) -> Option<Family> {
+    match browser {
+        "firefox" | "zen" | "librewolf" | "waterfox" | "floorp" => Some(Family::Firefox),
+        "chrome" | "chromium" | "brave" | "edge" | "opera" | "vivaldi" => Some(Family::Chromium),
+        _ => None,
+    }
+}
+
+/// Split a yt-dlp style `BROWSER[+KEYRING][:PROFILE][::CONTAINER]` spec
+fn parse_spec(spec: &str) -> (String, Option<String>) {
+    let spec = spec.split("::").next().unwrap_or(spec);
+    let (browser, profile) = match spec.split_once(':') {
+        Some((b, p)) if !p.is_empty() => (b, Some(p.to_string())),
+        Some((b, _)) => (b, None),
+        None => (spec, None),
+    };
+    let browser = browser.split('+').next().unwrap_or(browser);
+    (browser.trim().to_lowercase(), profile)
+}
+
+/// Read YouTube cookies for a browser spec such as `firefox` or `chrome:Profile 1`
+pub fn read_cookies(spec: &str) -> anyhow::Result<Vec<Cookie>> {
+    let (browser, profile) = parse_spec(spec);
+    let Some(fam) = family(&browser) else {
+        anyhow::bail!("reading cookies from {browser} is not supported");
+    };
+
+    let db = match profile {
+        Some(p) => profile_cookie_db(&browser, fam, &p)?,
+        None => find_cookie_db(&browser, fam)?.1,
+    };
+
+    match fam {
+        Family::Firefox => read_firefox_cookies(&db),
+        Family::Chromium => read_chromium_cookies(&db),
+    }
+}
+
+/// Profile directory holding the most recently used cookie database for a browser
+pub fn find_profile(browser: &str) -> anyhow::Result<PathBuf> {
+    let (browser, _) = parse_spec(browser);
+    let Some(fam) = family(&browser) else {
+        anyhow::bail!("reading cookies from {browser} is not supported");
+    };
+    Ok(find_cookie_db(&browser, fam)?.0)
+}
+
+/// Directories containing a browser's profiles, for Linux (native, snap, flatpak) and macOS
+fn browser_roots(browser: &str) -> Vec<PathBuf> {
+    let Some(dirs) = directories::BaseDirs::new() else {
+        return Vec::new();
+    };
+    let home = dirs.home_dir();
+    let config = dirs.config_dir();
+    let mac = home.join("Library").join("Application Support");
+
+    match browser {
+        "firefox" => vec![
+            home.join(".mozilla/firefox"),
+            home.join("snap/firefox/common/.mozilla/firefox"),
+            home.join(".var/app/org.mozilla.firefox/.mozilla/firefox"),
+            mac.join("Firefox/Profiles"),
+        ],
+        "zen" => vec![
+            home.join(".zen"),
+            home.join(".var/app/app.zen_browser.zen/.zen"),
+            mac.join("zen/Profiles"),
+        ],
+        "librewolf" => vec![
+            home.join(".librewolf"),
+            home.join(".var/app/io.gitlab.librewolf-community/.librewolf"),
+            mac.join("librewolf/Profiles"),
+        ],
+        "waterfox" => vec![home.join(".waterfox"), mac.join("Waterfox/Profiles")],
+        "floorp" => vec![home.join(".floorp"), mac.join("Floorp/Profiles")],
+        "chrome" => vec![
+            config.join("google-chrome"),
+            home.join(".var/app/com.google.Chrome/config/google-chrome"),
+            mac.join("Google/Chrome"),
+        ],
+        "chromium" => vec![
+            config.join("chromium"),
+            home.join("snap/chromium/common/chromium"),
+            home.join(".var/app/org.chromium.Chromium/config/chromium"),
+            mac.join("Chromium"),
+        ],
+        "brave" => vec![
+            config.join("BraveSoftware/Brave-Browser"),
+            home.join(".var/app/com.brave.Browser/config/BraveSoftware/Brave-Browser"),
+            mac.join("BraveSoftware/Brave-Browser"),
+        ],
+        "edge" => vec![config.join("microsoft-edge"), mac.join("Microsoft Edge")],
+        "opera" => vec![config.join("opera"), mac.join("com.operasoftware.Opera")],
+        "vivaldi" => vec![config.join("vivaldi"), mac.join("Vivaldi")],
+        _ => Vec::new(),
+    }
+}
+
+/// Cookie database inside a profile directory, if present
+fn cookie_db_in(dir: &Path, fam: Family) -> Option<PathBuf> {
+    let candidates = match fam {
+        Family::Firefox => vec![dir.join("cookies.sqlite")],
+        Family::Chromium => vec![dir.join("Network").join("Cookies"), dir.join("Cookies")],
+    };
+    candidates.into_iter().find(|p| p.is_file())
+}
+
+/// Find the most recently modified cookie database across all profiles.
+/// Returns `(profile_dir, db_path)`.
+fn find_cookie_db(browser: &str, fam: Family) -> anyhow::Result<(PathBuf, PathBuf)> {
+    let roots = browser_roots(browser);
+    let mut best: Option<(std::time::SystemTime, PathBuf, PathBuf)> = None;
+
+    for root in roots.iter().filter(|r| r.is_dir()) {
+        // Opera keeps its cookies in the root rather than a profile subdirectory
+        let mut dirs = vec![root.clone()];
+        if let Ok(entries) = std::fs::read_dir(root) {
+            dirs.extend(entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()));
+        }
+
+        for dir in dirs {
+            let Some(db) = cookie_db_in(&dir, fam) else {
+                continue;
+            };
+            let modified = std::fs::metadata(&db)
+                .and_then(|m| m.modified())
+                .unwrap_or(std::time::UNIX_EPOCH);
+            if best.as_ref().is_none_or(|(t, _, _)| modified > *t) {
+                best = Some((modified, dir, db));
+            }
+        }
+    }
+
+    best.map(|(_, dir, db)| (dir, db)).with_context(|| {
+        let looked: Vec<String> = roots.iter().map(|r| r.display().to_string()).collect();
+        format!("no {browser} cookie database found (looked in {})", looked.join(", "))
+    })
+}
+
+/// Resolve an explicit profile: a cookie file, a profile directory, or a profile name
+fn profile_cookie_db(browser: &str, fam: Family, profile: &str) -> anyhow::Result<PathBuf> {
+    let path = PathBuf::from(profile);
+    if path.is_file() {
+        return Ok(path);
+    }
+    if path.is_dir() {
+        return cookie_db_in(&path, fam)
+            .with_context(|| format!("no cookie database in {}", path.display()));
+    }
+    browser_roots(browser)
+        .iter()
+        .find_map(|root| cookie_db_in(&root.join(profile), fam))
+        .with_context(|| format!("{browser} profile not found: {profile}"))
+}
+
+/// Browsers lock their cookie database while running, so read from a copy
+fn open_copy(db: &Path) -> anyhow::Result<(rusqlite::Connection, TempDir)> {
+    let dir = TempDir::new()?;
+
+    let copy = dir.0.join("cookies.sqlite");
+    std::fs::copy(db, &copy).with_context(|| format!("copy {}", db.display()))?;
+    // Recent writes may still live in the write-ahead log
+    for suffix in ["-wal", "-shm"] {
+        let side = PathBuf::from(format!("{}{}", db.display(), suffix));
+        if side.exists() {
+            let _ = std::fs::copy(&side, PathBuf::from(format!("{}{}", copy.display(), suffix)));
+        }
+    }
+
+    let conn = rusqlite::Connection::open(&copy)
+        .with_context(|| format!("open sqlite {}", copy.display()))?;
+    Ok((conn, dir))
+}
+
+/// A private scratch directory, removed when dropped
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new() -> anyhow::Result<Self> {
+        let dir = std::env::temp_dir().join(format!(
+            "void-cookies-{}-{:016x}",
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+        std::fs::create_dir(&dir).with_context(|| format!("create dir {}", dir.display()))?;
+        Ok(Self(dir))
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Matches each domain and its subdomains, but not hosts that merely end in it
+fn domain_filter(column: &str) -> String {
+    COOKIE_DOMAINS
+        .iter()
+        .map(|d| format!("{column} = '{d}' OR {column} LIKE '%.{d}'"))
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
+fn read_firefox_cookies(db: &Path) -> anyhow::Result<Vec<Cookie>> {
+    let (conn, dir) = open_copy(db)?;
+    let result = (|| {
+        let sql = format!(
+            "SELECT host, path, name, value, isSecure, expiry FROM moz_cookies WHERE {}",
+            domain_filter("host")
+        );
+        let mut stmt = conn.prepare(&sql).context("prepare moz_cookies query")?;
+        let rows = stmt
+            .query_map([], |row| {
+                let expiry: i64 = row.get(5)?;
+                Ok(Cookie {
+                    domain: row.get(0)?,
+                    path: row.get(1)?,
+                    name: row.get(2)?,
+                    value: row.get(3)?,
+                    secure: row.get::<_, i64>(4)? != 0,
+                    // Newer Firefox versions store milliseconds
+                    expires_utc: (expiry > 0)
+                        .then_some(if expiry > 100_000_000_000 { expiry / 1000 } else { expiry }),
+                })
+            })
+            .context("query moz_cookies")?;
+        let cookies = rows.collect::<Result<Vec<_>, _>>().context("read moz_cookies row")?;
+        Ok(unexpired(cookies))
+    })();
+    // Close the copy before its directory goes
+    drop(conn);
+    drop(dir);
+    result
+}
+
+fn read_chromium_cookies(db: &Path) -> anyhow::Result<Vec<Cookie>> {
+    let (conn, dir) = open_copy(db)?;
+    let result = (|| {
+        // Since schema version 24 the plaintext is prefixed with SHA256(host_key)
+        let version: i64 = conn
+            .query_row("SELECT value FROM meta WHERE key = 'version'", [], |row| {
+                row.get::<_, String>(0)
+            })
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+        let keys = [derive_key(b"peanuts"), derive_key(b"")];
+
+        let sql = format!(
+            "SELECT host_key, path, name, value, encrypted_value, is_secure, expires_utc FROM cookies WHERE {}",
+            domain_filter("host_key")
+        );
+        let mut stmt = conn.prepare(&sql).context("prepare cookies query")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, Vec<u8>>(4)?,
+                    row.get::<_, i64>(5)? != 0,
+                    row.get::<_, i64>(6)?,
+                ))
+            })
+            .context("query cookies")?;
+
+        let mut cookies = Vec::new();
+        for row in rows {
+            let (domain, path, name, value, encrypted, secure, expires) =
+                row.context("read cookies row")?;
+            let value = if !value.is_empty() || encrypted.is_empty() {
+                value
+            } else {
+                // Skip cookies we cannot decrypt (e.g. a keyring-protected v11 key)
+                let Some(mut plain) = decrypt_linux(&encrypted, &keys) else {
+                    continue;
+                };
+                if version >= 24 && plain.len() >= 32 {
+                    plain.drain(..32);
+                }
+                match String::from_utf8(plain) {
+                    Ok(v) => v,
+                    Err(_) => continue,
+                }
+            };
+            cookies.push(Cookie {
+                domain,
+                path,
+                name,
+                value,
+                secure,
+                expires_utc: (expires > 0).then(|| expires / 1_000_000 - CHROMIUM_EPOCH_OFFSET),
+            });
+        }
+        Ok(unexpired(cookies))
+    })();
+    drop(conn);
+    drop(dir);
+    result
+}
+
+/// Drop cookies past their expiry; session cookies (no expiry) are kept
+fn unexpired(mut cookies: Vec<Cookie>) -> Vec<Cookie> {
+    let now = super::unix_now();
+    cookies.retain(|c| c.expires_utc.is_none_or(|e| e > now));
+    cookies
+}
+
+/// Chromium's Linux cookie key: PBKDF2-SHA1 over the keyring password
+/// ("peanuts" when no keyring is used) with a fixed salt and a single iteration
+fn derive_key(password: &[u8]) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    pbkdf2::pbkdf2_hmac::<sha1::Sha1>(password, b"saltysalt", 1, &mut key);
+    key
+}
+
+/// Decrypt a `v10`/`v11` value (AES-128-CBC, IV of 16 spaces), trying each key in turn
+fn decrypt_linux(encrypted: &[u8], keys: &[[u8; 16]]) -> Option<Vec<u8>> {
+    let data = match encrypted.get(..3) {
+        Some(b"v10") | Some(b"v11") => &encrypted[3..],
+        _ => return None,
+    };
+    keys.iter().find_map(|key| {
+        cbc::Decryptor::<aes::Aes128>::new(key.into(), &[b' '; 16].into())
+            .decrypt_padded_vec_mut::<Pkcs7>(data)
+            .ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::cipher::BlockEncryptMut;
+
+    fn encrypt_v10(plain: &[u8]) -> Vec<u8> {
+        let key = derive_key(b"peanuts");
+        let mut out = b"v10".to_vec();
+        out.extend(
+            cbc::Encryptor::<aes::Aes128>::new(&key.into(), &[b' '; 16].into())
+                .encrypt_padded_vec_mut::<Pkcs7>(plain),
+        );
+        out
+    }
+
+    #[test]
+    fn test_parse_spec() {
+        assert_eq!(parse_spec("firefox"), ("firefox".to_string(), None));
+        assert_eq!(
+            parse_spec("Chrome+gnomekeyring:Profile 1::container"),
+            ("chrome".to_string(), Some("Profile 1".to_string()))
+        );
+        assert_eq!(
+            parse_spec("firefox:/home/me/.zen/abc.default"),
+            ("firefox".to_string(), Some("/home/me/.zen/abc.default".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_decrypt_linux() {
+        let keys = [derive_key(b"peanuts"), derive_key(b"")];
+        let encrypted = encrypt_v10(b"secret-value");
+        assert_eq!(decrypt_linux(&encrypted, &keys).as_deref(), Some(&b"secret-value"[..]));
+        assert!(decrypt_linux(b"plain", &keys).is_none());
+    }
+
+    #[test]
+    fn test_read_chromium_cookies() {
+        let dir = std::env::temp_dir().join(format!("void-chromium-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db = dir.join("Cookies");
+        let conn = rusqlite::Connection::open(&db).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE meta (key TEXT, value TEXT);
+             INSERT INTO meta VALUES ('version', '24');
+             CREATE TABLE cookies (host_key TEXT, path TEXT, name TEXT, value TEXT,
+                 encrypted_value BLOB, is_secure INTEGER, expires_utc INTEGER);",
+        )
+        .unwrap();
+        let mut plain = vec![0u8; 32];
+        plain.extend_from_slice(b"sapisid-value");
+        conn.execute(
+            "INSERT INTO cookies VALUES ('.youtube.com', '/', 'SAPISID', '', ?1, 1, 14000000000000000)",
+            [encrypt_v10(&plain)],
+        )
+        .unwrap();
+        conn.execute_batch(
+            "INSERT INTO cookies VALUES ('.example.com', '/', 'OTHER', 'x', x'', 0, 0);
+             INSERT INTO cookies VALUES ('.notyoutube.com', '/', 'SID', 'x', x'', 0, 0);
+             INSERT INTO cookies VALUES ('youtube.com', '/', 'OLD', 'x', x'', 0, 13000000000000000);
+             INSERT INTO cookies VALUES ('www.youtube.com', '/', 'PREF', 'f6=1', x'', 0, 0);",
+        )
+        .unwrap();
+        drop(conn);
+
+        let cookies = read_chromium_cookies(&db).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+        let names: Vec<_> = cookies.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["SAPISID", "PREF"]);
+        assert_eq!(cookies[0].value, "sapisid-value");
+        assert_eq!(cookies[0].expires_utc, Some(14_000_000_000 - CHROMIUM_EPOCH_OFFSET));
+    }
+}
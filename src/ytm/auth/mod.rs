@@ -0,0 +1,289 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub mod browser;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cookie {
+    pub domain: String,
+    pub path: String,
+    pub name: String,
+    pub value: String,
+    pub secure: bool,
+    pub expires_utc: Option<i64>,
+}
+
+/// Cookies that together make up a signed-in YouTube session
+const LOGIN_COOKIES: &[&str] = &[
+    "SAPISID",
+    "__Secure-3PAPISID",
+    "APISID",
+    "HSID",
+    "SSID",
+    "SID",
+    "__Secure-3PSID",
+    "LOGIN_INFO",
+];
+
+/// Start warning this long before the login cookies expire
+pub const EXPIRY_WARNING_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Where the cookies of an `AuthState` were loaded from
+#[derive(Debug, Clone)]
+pub enum AuthSource {
+    File(PathBuf),
+    Browser(String),
+}
+
+impl std::fmt::Display for AuthSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthSource::File(p) => write!(f, "cookie file {}", p.display()),
+            AuthSource::Browser(spec) => write!(f, "browser {spec}"),
+        }
+    }
+}
+
+/// Which account of a signed-in session requests act as.
+/// The default is the first Google account's own channel.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Identity {
+    /// `X-Goog-AuthUser`: index of the Google account in a multi-login session
+    pub auth_user: Option<u32>,
+    /// `onBehalfOfUser`: brand account page id
+    pub on_behalf_of_user: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthState {
+    cookies: Vec<Cookie>,
+    pub cookie_header: String,
+    pub sapisid: Option<String>,
+    pub source: AuthSource,
+}
+
+pub fn load_netscape_cookies(path: &Path) -> anyhow::Result<AuthState> {
+    let raw = std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+    let mut cookies = Vec::new();
+
+    for line in raw.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // Netscape format: domain \t flag \t path \t secure \t expiration \t name \t value
+        let parts: Vec<&str> = line.split('\t').collect();
+        if parts.len() < 7 {
+            continue;
+        }
+
+        let domain = parts[0].to_string();
+        let path = parts[2].to_string();
+        let secure = parts[3].eq_ignore_ascii_case("TRUE");
+        let expires_utc = parts[4].parse::<i64>().ok();
+        let name = parts[5].to_string();
+        let value = parts[6].to_string();
+
+        cookies.push(Cookie {
+            domain,
+            path,
+            name,
+            value,
+            secure,
+            expires_utc,
+        });
+    }
+
+    Ok(AuthState::from_cookies(cookies, AuthSource::File(path.to_path_buf())))
+}
+
+/// Read YouTube cookies straight from a browser profile.
+/// `spec` uses yt-dlp's `--cookies-from-browser` syntax, e.g. `firefox` or `chrome:Profile 1`.
+pub fn load_browser_cookies(spec: &str) -> anyhow::Result<AuthState> {
+    let cookies = browser::read_cookies(spec)?;
+    if !cookies.iter().any(|c| c.name == "SAPISID" || c.name == "__Secure-3PAPISID") {
+        anyhow::bail!("no YouTube login cookies in {spec}; sign in to music.youtube.com there first");
+    }
+    Ok(AuthState::from_cookies(cookies, AuthSource::Browser(spec.to_string())))
+}
+
+/// Build auth from the configured sources; a cookie file takes precedence over a browser.
+pub fn load_auth(cookies: Option<&Path>, cookies_from_browser: Option<&str>) -> anyhow::Result<Option<AuthState>> {
+    if let Some(p) = cookies
+        && p.exists()
+    {
+        return load_netscape_cookies(p).map(Some);
+    }
+    match cookies_from_browser {
+        Some(spec) => load_browser_cookies(spec).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Whether a cookie is sent to music.youtube.com: youtube.com itself or a subdomain
+fn is_youtube_cookie(cookie: &Cookie) -> bool {
+    let host = cookie.domain.trim_start_matches('.');
+    host == "youtube.com" || host.ends_with(".youtube.com")
+}
+
+impl AuthState {
+    /// Keeps only YouTube's cookies. google.com sets SID, SAPISID etc. under the same
+    /// names, and mixing them in would send duplicates and sign with the wrong SAPISID.
+    pub fn from_cookies(cookies: Vec<Cookie>, source: AuthSource) -> Self {
+        let cookies: Vec<Cookie> = cookies.into_iter().filter(is_youtube_cookie).collect();
+        let cookie_header = cookies
+            .iter()
+            .map(|c| format!("{}={}", c.name, c.value))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        // For signed requests, YouTube uses SAPISID (sometimes __Secure-3PAPISID works too).
+        let sapisid = cookies
+            .iter()
+            .find(|c| c.name == "SAPISID")
+            .map(|c| c.value.clone())
+            .or_else(|| {
+                cookies
+                    .iter()
+                    .find(|c| c.name == "__Secure-3PAPISID")
+                    .map(|c| c.value.clone())
+            });
+
+        AuthState {
+            cookies,
+            cookie_header,
+            sapisid,
+            source,
+        }
+    }
+
+    pub fn has_sapisid(&self) -> bool {
+        self.sapisid.is_some()
+    }
+
+    /// Earliest expiry (unix seconds) among the login cookies.
+    /// `None` when they are all session cookies or missing.
+    pub fn login_expiry(&self) -> Option<i64> {
+        self.cookies
+            .iter()
+            .filter(|c| LOGIN_COOKIES.contains(&c.name.as_str()))
+            .filter_map(|c| c.expires_utc)
+            // Netscape files use 0 for session cookies
+            .filter(|&t| t > 0)
+            .min()
+    }
+}
+
+/// Human readable distance to a cookie expiry, e.g. "in 3 days" or "2 hours ago"
+pub fn describe_expiry(expires_utc: i64, now: i64) -> String {
+    let delta = expires_utc - now;
+    let secs = delta.abs();
+    let amount = if secs >= 86_400 {
+        plural(secs / 86_400, "day")
+    } else if secs >= 3_600 {
+        plural(secs / 3_600, "hour")
+    } else {
+        plural((secs / 60).max(1), "minute")
+    };
+    if delta >= 0 {
+        format!("in {amount}")
+    } else {
+        format!("{amount} ago")
+    }
+}
+
+fn plural(n: i64, unit: &str) -> String {
+    if n == 1 {
+        format!("1 {unit}")
+    } else {
+        format!("{n} {unit}s")
+    }
+}
+
+pub fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cookie(name: &str, expires_utc: Option<i64>) -> Cookie {
+        Cookie {
+            domain: ".youtube.com".to_string(),
+            path: "/".to_string(),
+            name: name.to_string(),
+            value: "v".to_string(),
+            secure: true,
+            expires_utc,
+        }
+    }
+
+    #[test]
+    fn test_google_cookies_are_left_out() {
+        let google = |name: &str| Cookie {
+            domain: ".google.com".to_string(),
+            value: "google".to_string(),
+            ..cookie(name, Some(100))
+        };
+        let youtube = |name: &str| Cookie {
+            value: "yt".to_string(),
+            ..cookie(name, Some(5_000))
+        };
+        let auth = AuthState::from_cookies(
+            vec![
+                google("SAPISID"),
+                google("SID"),
+                youtube("SAPISID"),
+                youtube("SID"),
+                Cookie {
+                    domain: "music.youtube.com".to_string(),
+                    ..youtube("PREF")
+                },
+                Cookie {
+                    domain: ".notyoutube.com".to_string(),
+                    ..youtube("HSID")
+                },
+            ],
+            AuthSource::Browser("firefox".to_string()),
+        );
+        assert_eq!(auth.sapisid.as_deref(), Some("yt"));
+        assert_eq!(auth.cookie_header, "SAPISID=yt; SID=yt; PREF=yt");
+        assert_eq!(auth.login_expiry(), Some(5_000));
+    }
+
+    #[test]
+    fn test_login_expiry() {
+        let auth = AuthState::from_cookies(
+            vec![
+                cookie("PREF", Some(100)),
+                cookie("SAPISID", Some(5_000)),
+                cookie("SID", Some(3_000)),
+                cookie("LOGIN_INFO", Some(0)),
+            ],
+            AuthSource::Browser("firefox".to_string()),
+        );
+        assert!(auth.has_sapisid());
+        assert_eq!(auth.login_expiry(), Some(3_000));
+
+        let session = AuthState::from_cookies(
+            vec![cookie("SID", None)],
+            AuthSource::Browser("firefox".to_string()),
+        );
+        assert!(!session.has_sapisid());
+        assert_eq!(session.login_expiry(), None);
+    }
+
+    #[test]
+    fn test_describe_expiry() {
+        assert_eq!(describe_expiry(3 * 86_400 + 10, 0), "in 3 days");
+        assert_eq!(describe_expiry(3_600, 0), "in 1 hour");
+        assert_eq!(describe_expiry(0, 7_200), "2 hours ago");
+        assert_eq!(describe_expiry(10, 0), "in 1 minute");
+    }
+}
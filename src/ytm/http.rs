@@ -0,0 +1,262 @@
+//! Request layer shared by all YTM calls: pacing, retries with backoff, timeouts.
+
+use anyhow::Context;
+use rand::Rng;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Never wait longer than this for a `Retry-After` hint
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff with jitter: a random delay in the upper half of
+    /// `base * 2^attempt`, capped at `max_delay`.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let half = exp / 2;
+        let jitter = rand::rng().random_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter)
+    }
+}
+
+/// Spaces requests out so that at most `per_second` start each second
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(per_second: f64) -> Self {
+        Self {
+            interval: Duration::from_secs_f64(1.0 / per_second),
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Wait for the next free slot
+    pub async fn acquire(&self) {
+        let slot = {
+            let mut next = self.next.lock().await;
+            let now = Instant::now();
+            let slot = (*next).max(now);
+            *next = slot + self.interval;
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Sends requests with pacing and retries on transient failures
+#[derive(Debug)]
+pub struct HttpLayer {
+    retry: RetryPolicy,
+    limiter: Option<RateLimiter>,
+}
+
+impl HttpLayer {
+    /// `requests_per_second` of 0 disables the rate limiter
+    pub fn new(retry: RetryPolicy, requests_per_second: f64) -> Self {
+        Self {
+            retry,
+            limiter: (requests_per_second > 0.0).then(|| RateLimiter::new(requests_per_second)),
+        }
+    }
+
+    /// Send the request built by `build`, retrying connection errors, timeouts,
+    /// 429 and 5xx. Other statuses are returned for the caller to handle.
+    pub async fn send<F>(&self, build: F) -> anyhow::Result<Response>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let mut attempt = 0;
+        loop {
+            if let Some(limiter) = &self.limiter {
+                limiter.acquire().await;
+            }
+
+            let wait = match build().send().await {
+                Ok(resp) if is_retryable_status(resp.status()) && attempt < self.retry.max_retries => {
+                    tracing::debug!(status = %resp.status(), attempt, "retrying request");
+                    retry_after(&resp).unwrap_or_else(|| self.retry.delay(attempt))
+                }
+                Ok(resp) => return Ok(resp),
+                Err(e) if is_retryable_error(&e) && attempt < self.retry.max_retries => {
+                    tracing::debug!(error = %e, attempt, "retrying request");
+                    self.retry.delay(attempt)
+                }
+                Err(e) => return Err(e).context("send request"),
+            };
+
+            tokio::time::sleep(wait).await;
+            attempt += 1;
+        }
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn is_retryable_error(e: &reqwest::Error) -> bool {
+    e.is_connect() || e.is_timeout() || e.is_request()
+}
+
+/// `Retry-After` in seconds, if the server sent one
+fn retry_after(resp: &Response) -> Option<Duration> {
+    let secs: u64 = resp
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(secs).min(MAX_RETRY_AFTER))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve the scripted status codes in order, then 200s. Returns the base URL
+    /// and a counter of requests received.
+    async fn mock_server(statuses: Vec<u16>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut sock, _)) = listener.accept().await else { break };
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let status = statuses.get(n).copied().unwrap_or(200);
+                let mut buf = [0u8; 4096];
+                let _ = sock.read(&mut buf).await;
+                let body = format!("{{\"n\":{n}}}");
+                let resp = format!(
+                    "HTTP/1.1 {status} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = sock.write_all(resp.as_bytes()).await;
+            }
+        });
+
+        (format!("http://{addr}"), hits)
+    }
+
+    fn fast_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_statuses() {
+        let (url, hits) = mock_server(vec![503, 429, 200]).await;
+        let http = reqwest::Client::new();
+        let layer = HttpLayer::new(fast_policy(3), 0.0);
+
+        let resp = layer.send(|| http.get(&url)).await.unwrap();
+        assert_eq!(resp.status(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_retries() {
+        let (url, hits) = mock_server(vec![500, 500, 500, 500]).await;
+        let http = reqwest::Client::new();
+        let layer = HttpLayer::new(fast_policy(2), 0.0);
+
+        let resp = layer.send(|| http.get(&url)).await.unwrap();
+        assert_eq!(resp.status(), 500);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        let (url, hits) = mock_server(vec![404]).await;
+        let http = reqwest::Client::new();
+        let layer = HttpLayer::new(fast_policy(3), 0.0);
+
+        let resp = layer.send(|| http.get(&url)).await.unwrap();
+        assert_eq!(resp.status(), 404);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_connection_errors_are_retried() {
+        // Bind and drop to get a port nobody listens on
+        let addr = TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap();
+        let http = reqwest::Client::new();
+        let layer = HttpLayer::new(fast_policy(2), 0.0);
+
+        let attempts = AtomicUsize::new(0);
+        let err = layer
+            .send(|| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                http.get(format!("http://{addr}"))
+            })
+            .await;
+        assert!(err.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_spaces_requests() {
+        let limiter = RateLimiter::new(50.0);
+        let started = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire().await;
+        }
+        // First slot is immediate, the next three are 20ms apart
+        assert!(started.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+        };
+        for _ in 0..20 {
+            let d0 = policy.delay(0);
+            assert!(d0 >= Duration::from_millis(50) && d0 <= Duration::from_millis(100));
+            let d3 = policy.delay(3);
+            assert!(d3 >= Duration::from_millis(400) && d3 <= Duration::from_millis(800));
+            let d10 = policy.delay(10);
+            assert!(d10 >= Duration::from_millis(500) && d10 <= Duration::from_millis(1000));
+        }
+    }
+}
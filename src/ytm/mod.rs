@@ -1,5 +1,6 @@
 pub mod api;
 pub mod auth;
+pub mod http;
 pub mod models;
 pub mod resolve;
 
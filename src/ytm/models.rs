@@ -1,12 +1,127 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Track {
     pub video_id: String,
     pub title: String,
     pub artists: Vec<String>,
     pub album: Option<String>,
     pub duration_seconds: Option<u32>,
+    /// Browse ids (`UC...`) of `artists`, index for index; `None` for unlinked names
+    #[serde(default)]
+    pub artist_ids: Vec<Option<String>>,
+    /// Browse id (`MPRE...`) of `album`
+    #[serde(default)]
+    pub album_id: Option<String>,
+    /// Available sizes, smallest first
+    #[serde(default)]
+    pub thumbnails: Vec<Thumbnail>,
+    #[serde(default)]
+    pub explicit: bool,
+    #[serde(default)]
+    pub year: Option<u16>,
+    /// Like status of the signed-in account; `None` when logged out or unknown
+    #[serde(default)]
+    pub like_status: Option<LikeStatus>,
+    #[serde(default)]
+    pub video_type: Option<VideoType>,
+}
+
+impl Track {
+    /// "Title - Artist, Artist" with an explicit marker, as shown in lists
+    pub fn label(&self) -> String {
+        let mut label = self.title.clone();
+        if !self.artists.is_empty() {
+            label.push_str(" - ");
+            label.push_str(&self.artists.join(", "));
+        }
+        if self.explicit {
+            label.push_str(" [E]");
+        }
+        label
+    }
+
+    /// Smallest thumbnail at least `min_width` wide, else the largest one
+    pub fn thumbnail_url(&self, min_width: u32) -> Option<&str> {
+        self.thumbnails
+            .iter()
+            .find(|t| t.width >= min_width)
+            .or_else(|| self.thumbnails.last())
+            .map(|t| t.url.as_str())
+    }
+
+    /// Browse id of the artist at `index`, if linked
+    #[allow(dead_code)]
+    pub fn artist_id(&self, index: usize) -> Option<&str> {
+        self.artist_ids.get(index)?.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Thumbnail {
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LikeStatus {
+    Like,
+    Dislike,
+    Indifferent,
+}
+
+impl LikeStatus {
+    pub fn from_api(s: &str) -> Option<Self> {
+        match s {
+            "LIKE" => Some(Self::Like),
+            "DISLIKE" => Some(Self::Dislike),
+            "INDIFFERENT" => Some(Self::Indifferent),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Like => "LIKE",
+            Self::Dislike => "DISLIKE",
+            Self::Indifferent => "INDIFFERENT",
+        }
+    }
+}
+
+/// What kind of video backs a track (`musicVideoType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VideoType {
+    /// Art track: the audio-only song upload (`MUSIC_VIDEO_TYPE_ATV`)
+    Song,
+    /// Official music video (`MUSIC_VIDEO_TYPE_OMV`)
+    OfficialVideo,
+    /// User-generated video (`MUSIC_VIDEO_TYPE_UGC`)
+    UserVideo,
+    /// Podcast episode (`MUSIC_VIDEO_TYPE_PODCAST_EPISODE`)
+    Episode,
+}
+
+impl VideoType {
+    pub fn from_api(s: &str) -> Option<Self> {
+        match s {
+            "MUSIC_VIDEO_TYPE_ATV" => Some(Self::Song),
+            "MUSIC_VIDEO_TYPE_OMV" => Some(Self::OfficialVideo),
+            "MUSIC_VIDEO_TYPE_UGC" => Some(Self::UserVideo),
+            "MUSIC_VIDEO_TYPE_PODCAST_EPISODE" => Some(Self::Episode),
+            _ => None,
+        }
+    }
+
+    pub fn as_api(self) -> &'static str {
+        match self {
+            Self::Song => "MUSIC_VIDEO_TYPE_ATV",
+            Self::OfficialVideo => "MUSIC_VIDEO_TYPE_OMV",
+            Self::UserVideo => "MUSIC_VIDEO_TYPE_UGC",
+            Self::Episode => "MUSIC_VIDEO_TYPE_PODCAST_EPISODE",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +133,88 @@ pub struct Playlist {
     pub thumbnail_url: Option<String>,
 }
 
+/// A titled section of a browse page (e.g. "Quick picks", "New albums & singles")
+#[derive(Debug, Clone)]
+pub struct Shelf {
+    pub title: String,
+    pub layout: ShelfLayout,
+    pub items: Vec<ShelfItem>,
+}
+
+/// How a shelf is presented by YouTube Music
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShelfLayout {
+    /// Rows of songs (`musicResponsiveListItemRenderer`), e.g. Quick picks
+    List,
+    /// Horizontally scrolling cards (`musicTwoRowItemRenderer`)
+    Cards,
+}
+
+/// Anything that can appear on a shelf.
+/// Albums and artists reuse `Playlist` since they are opened by browse id the same way.
+#[derive(Debug, Clone)]
+pub enum ShelfItem {
+    Track(Track),
+    Playlist(Playlist),
+    Album(Playlist),
+    Artist(Playlist),
+}
+
+impl ShelfItem {
+    pub fn title(&self) -> &str {
+        match self {
+            ShelfItem::Track(t) => &t.title,
+            ShelfItem::Playlist(p) | ShelfItem::Album(p) | ShelfItem::Artist(p) => &p.title,
+        }
+    }
+
+    pub fn subtitle(&self) -> String {
+        match self {
+            ShelfItem::Track(t) => t.artists.join(", "),
+            ShelfItem::Playlist(p) | ShelfItem::Album(p) | ShelfItem::Artist(p) => {
+                p.author.clone().unwrap_or_default()
+            }
+        }
+    }
+
+    /// The collection behind this item, if it opens into a track list
+    pub fn as_collection(&self) -> Option<&Playlist> {
+        match self {
+            ShelfItem::Track(_) => None,
+            ShelfItem::Playlist(p) | ShelfItem::Album(p) | ShelfItem::Artist(p) => Some(p),
+        }
+    }
+}
+
+/// A mood or genre button on the Moods & genres page
+#[derive(Debug, Clone)]
+pub struct MoodCategory {
+    /// Grouping heading, e.g. "Moods & moments" or "Genres"
+    pub section: String,
+    pub title: String,
+    /// Browse params for `FEmusic_moods_and_genres_category`
+    pub params: String,
+}
+
+/// The signed-in YouTube account
+#[derive(Debug, Clone)]
+pub struct AccountInfo {
+    pub name: String,
+    /// Channel handle, e.g. "@someone"
+    pub handle: Option<String>,
+}
+
+/// A channel the signed-in account can act as
+#[derive(Debug, Clone)]
+pub struct Channel {
+    pub name: String,
+    pub handle: Option<String>,
+    /// Brand account page id (`onBehalfOfUser`); `None` for the primary channel
+    pub page_id: Option<String>,
+    /// The channel requests currently act as
+    pub selected: bool,
+}
+
 /// Unified search result item that can be either a track or a playlist
 #[derive(Debug, Clone)]
 pub enum SearchItem {